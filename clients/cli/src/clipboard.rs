@@ -0,0 +1,41 @@
+//! System clipboard access
+//!
+//! Copying is only available with the `clipboard` feature, and even then needs
+//! a clipboard to talk to (there is none over a headless SSH session). Callers
+//! get an error in either case and can show the text some other way.
+
+/// Lazily connected handle to the system clipboard.
+///
+/// Kept alive for the session: on X11 and Wayland the copied text is served by
+/// this process, and disappears once the handle is dropped.
+#[derive(Default)]
+pub struct Clipboard {
+    #[cfg(feature = "clipboard")]
+    inner: Option<arboard::Clipboard>,
+}
+
+impl std::fmt::Debug for Clipboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Clipboard").finish_non_exhaustive()
+    }
+}
+
+impl Clipboard {
+    /// Put `text` on the clipboard
+    #[cfg(feature = "clipboard")]
+    pub fn copy(&mut self, text: &str) -> Result<(), String> {
+        let clipboard = match &mut self.inner {
+            Some(clipboard) => clipboard,
+            None => self
+                .inner
+                .insert(arboard::Clipboard::new().map_err(|e| e.to_string())?),
+        };
+        clipboard.set_text(text).map_err(|e| e.to_string())
+    }
+
+    /// Put `text` on the clipboard
+    #[cfg(not(feature = "clipboard"))]
+    pub fn copy(&mut self, _text: &str) -> Result<(), String> {
+        Err("built without the clipboard feature".to_string())
+    }
+}
@@ -10,8 +10,18 @@ pub mod cli_consts {
     // All queue sizes are chosen to be larger than the API page size (currently 50)
     // to provide adequate buffering while preventing excessive memory usage.
 
-    /// The maximum number of events to keep in the activity logs.
-    pub const MAX_ACTIVITY_LOGS: usize = 100;
+    /// How long the first-proof confirmation stays on the dashboard (seconds)
+    pub const FIRST_PROOF_BANNER_SECS: u64 = 15;
+
+    /// How long a confirmation message stays in the dashboard footer (seconds)
+    pub const TOAST_SECS: u64 = 3;
+
+    /// How long the header's failure count stands out after it goes up (seconds)
+    pub const FAILURE_BADGE_FLASH_SECS: u64 = 5;
+
+    /// Default maximum number of events to keep in the activity logs.
+    /// Older events are dropped; cumulative metrics are unaffected.
+    pub const MAX_ACTIVITY_LOGS: usize = 1000;
 
     /// Maximum number of event buffer size for worker threads
     pub const EVENT_QUEUE_SIZE: usize = 100;
@@ -29,6 +39,125 @@ pub mod cli_consts {
     /// "Reasonable" generic projection task memory requirement.
     pub const PROJECTED_MEMORY_REQUIREMENT: u64 = 4294967296; // 4gb
 
+    /// Default points credited for each successfully submitted proof
+    pub const DEFAULT_POINTS_PER_TASK: u64 = 300;
+
+    /// Default time a task fetch may take before the dashboard shows a timeout (seconds)
+    pub const DEFAULT_FETCH_TIMEOUT_SECS: u64 = 5;
+
+    /// Number of CPU and RAM samples shown as sparklines
+    pub const SYSTEM_HISTORY_SAMPLES: usize = 60;
+
+    /// Default interval between dashboard redraws (milliseconds)
+    pub const DEFAULT_REFRESH_MS: u64 = 100;
+
+    /// Shortest redraw interval accepted; faster only burns CPU without visible benefit (milliseconds)
+    pub const MIN_REFRESH_MS: u64 = 50;
+
+    /// Interval between CPU and RAM samples, however often the dashboard redraws (milliseconds)
+    pub const SYSTEM_SAMPLE_INTERVAL_MS: u64 = 1000;
+
+    /// Number of recent proof durations kept for min/max proving time
+    pub const PROVING_TIMES_CAPACITY: usize = 100;
+
+    /// Number of recent submissions the rolling submission latency covers
+    pub const SUBMISSION_LATENCY_SAMPLES: usize = 20;
+
+    /// How long a generated proof may wait for its submission before it stops
+    /// being tracked for submission latency (seconds)
+    pub const SUBMISSION_PENDING_TIMEOUT_SECS: u64 = 3600;
+
+    /// Number of in-flight tasks, and of recent submissions, tracked for retry counts
+    pub const RETRY_STATS_CAPACITY: usize = 20;
+
+    /// Number of recent tasks whose lifecycle stage is tracked
+    pub const TASK_LIFECYCLE_CAPACITY: usize = 1000;
+
+    /// Time a task may stay in flight with no event before it is counted as dropped (seconds)
+    pub const TASK_DROP_TIMEOUT_SECS: u64 = 30 * 60;
+
+    /// Default time a prover may spend on one proof before it is flagged as stuck (seconds)
+    pub const DEFAULT_STUCK_PROVER_SECS: u64 = 10 * 60;
+
+    /// Default slowdown (percent over the baseline proving time) flagged as a regression
+    pub const DEFAULT_REGRESSION_PCT: f64 = 25.0;
+
+    /// Number of first proofs whose average is the regression baseline, unless one is configured
+    pub const REGRESSION_BASELINE_PROOFS: usize = 5;
+
+    /// Number of latest proofs averaged when checking for a regression
+    pub const REGRESSION_ROLLING_PROOFS: usize = 5;
+
+    /// Minimum time between repeated regression alerts (seconds)
+    pub const REGRESSION_ALERT_COOLDOWN_SECS: u64 = 30 * 60;
+
+    /// Default time without task fetcher activity before the orchestrator is
+    /// shown as unreachable (seconds)
+    pub const DEFAULT_STALE_FETCHER_SECS: u64 = 2 * 60;
+
+    /// Default CPU temperature (°C) from which it is shown in red
+    pub const DEFAULT_CPU_TEMP_WARN_CELSIUS: f32 = 85.0;
+
+    /// Default RAM usage (percent of total memory) at which the RAM gauge turns yellow
+    pub const DEFAULT_RAM_WARN_PCT: f32 = 60.0;
+
+    /// Default RAM usage (percent of total memory) at which the RAM gauge turns red
+    pub const DEFAULT_RAM_CRITICAL_PCT: f32 = 80.0;
+
+    /// Default weight of each new CPU and RAM sample in the displayed moving average
+    pub const DEFAULT_METRICS_SMOOTHING: f32 = 0.3;
+
+    /// Sliding window for the recent throughput (tasks per hour) metric (seconds)
+    pub const THROUGHPUT_WINDOW_SECS: u64 = 3600;
+
+    /// Size at which the event log file is rolled over (bytes)
+    pub const EVENT_LOG_MAX_BYTES: u64 = 10 * 1024 * 1024; // 10 MB
+
+    /// Rotated event log files kept besides the live one
+    pub const EVENT_LOG_KEEP_FILES: usize = 5;
+
+    /// Events that may wait for the event log writer before new ones are dropped
+    pub const EVENT_LOG_QUEUE_SIZE: usize = 1000;
+
+    /// Completed tasks that may wait for the CSV writer before new ones are dropped
+    pub const TASK_CSV_QUEUE_SIZE: usize = 1000;
+
+    /// Number of recent activity log events included in a state dump
+    pub const STATE_DUMP_EVENTS: usize = 200;
+
+    /// Default interval between metrics snapshots in headless JSON mode (seconds)
+    pub const JSON_METRICS_INTERVAL_SECS: u64 = 30;
+
+    /// How often cumulative dashboard metrics are saved to the stats file (seconds)
+    pub const STATS_SAVE_INTERVAL_SECS: u64 = 60;
+
+    /// Failures older than this don't count towards a failure notification (seconds)
+    pub const FAILURE_NOTIFY_WINDOW_SECS: u64 = 15 * 60;
+
+    /// Default minimum time between failure notifications (seconds)
+    pub const DEFAULT_NOTIFY_COOLDOWN_SECS: u64 = 30 * 60;
+
+    /// Default address the `status` command queries; matches the usual `--status-addr`
+    pub const DEFAULT_STATUS_ADDR: &str = "127.0.0.1:9091";
+
+    /// How long the `status` command waits for a running instance to answer (seconds)
+    pub const STATUS_REQUEST_TIMEOUT_SECS: u64 = 5;
+
+    /// How long the metrics and status servers wait before accepting again
+    /// after a failed accept, e.g. when out of file descriptors (milliseconds)
+    pub const ACCEPT_RETRY_DELAY_MS: u64 = 100;
+
+    /// How long the metrics server waits for a scraper to send its request
+    /// before dropping the connection (seconds)
+    pub const METRICS_REQUEST_TIMEOUT_SECS: u64 = 5;
+
+    /// Default longest time without a successful submission that `healthcheck`
+    /// still reports as healthy (seconds)
+    pub const DEFAULT_HEALTH_MAX_SINCE_SUCCESS_SECS: u64 = 60 * 60;
+
+    /// How often a worker paused by the proving schedule re-checks it (seconds)
+    pub const SCHEDULE_CHECK_INTERVAL_SECS: u64 = 30;
+
     // =============================================================================
     // DIFFICULTY CONFIGURATION
     // =============================================================================
@@ -96,6 +225,63 @@ pub mod cli_consts {
         }
     }
 
+    /// Task history database batching
+    pub mod history {
+        use std::time::Duration;
+
+        /// Records that may wait behind a slow disk before new ones are dropped
+        pub const QUEUE_SIZE: usize = 1000;
+
+        /// Most records written in one transaction
+        pub const BATCH_SIZE: usize = 100;
+
+        /// Longest a record waits for others to share its transaction (seconds)
+        pub const FLUSH_INTERVAL_SECS: u64 = 5;
+
+        /// Interval between system metrics rows (seconds)
+        pub const SYSTEM_SAMPLE_INTERVAL_SECS: u64 = 60;
+
+        /// Tasks awaiting submission whose fetch and proof times are kept
+        pub const PENDING_TASKS: usize = 1000;
+
+        /// Helper function to get the flush interval
+        pub const fn flush_interval() -> Duration {
+            Duration::from_secs(FLUSH_INTERVAL_SECS)
+        }
+
+        /// Helper function to get the system sample interval
+        pub const fn system_sample_interval() -> Duration {
+            Duration::from_secs(SYSTEM_SAMPLE_INTERVAL_SECS)
+        }
+    }
+
+    /// Submission webhook delivery configuration
+    pub mod webhook {
+        use std::time::Duration;
+
+        /// Initial delay before retrying a failed delivery (milliseconds)
+        pub const INITIAL_BACKOFF_MS: u64 = 1000; // 1 second
+
+        /// Maximum number of retry attempts per delivery
+        pub const MAX_RETRIES: u32 = 3;
+
+        /// Time a single delivery attempt may take (seconds)
+        pub const TIMEOUT_SECS: u64 = 10;
+
+        /// Deliveries that may wait behind a slow endpoint before new ones are dropped
+        pub const QUEUE_SIZE: usize = 100;
+
+        /// Helper function to get initial backoff duration
+        pub const fn initial_backoff() -> Duration {
+            Duration::from_millis(INITIAL_BACKOFF_MS)
+        }
+
+        /// Helper function to get the per-attempt timeout
+        pub const fn timeout() -> Duration {
+            Duration::from_secs(TIMEOUT_SECS)
+        }
+    }
+
     /// Advanced rate limiting configuration
     pub mod rate_limiting {
         use std::time::Duration;
@@ -130,4 +316,16 @@ pub mod cli_consts {
             Duration::from_secs(EXTRA_RETRY_DELAY_SECS)
         }
     }
+
+    /// Dashboard breakpoints (terminal columns and rows)
+    pub mod layout {
+        /// Narrowest terminal that gets side-by-side panels; narrower ones stack
+        pub const COLUMNS_MIN_WIDTH: u16 = 100;
+
+        /// Shortest terminal that shows the comparison, prover and error panels
+        pub const SIDE_PANELS_MIN_HEIGHT: u16 = 30;
+
+        /// Shortest terminal that shows the CPU and RAM history sparklines
+        pub const SPARKLINES_MIN_HEIGHT: u16 = 40;
+    }
 }
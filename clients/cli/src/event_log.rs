@@ -0,0 +1,318 @@
+//! Event log file
+//!
+//! Appends every activity log event to a file, one line per event, rolling the
+//! file over when it grows past a size limit. Writes happen on a background
+//! thread so a slow disk never stalls the dashboard; if the file can't be
+//! written, logging stops and the failure is reported once. JSON logs can be
+//! read back for replay.
+
+use crate::consts::cli_consts::{EVENT_LOG_KEEP_FILES, EVENT_LOG_MAX_BYTES, EVENT_LOG_QUEUE_SIZE};
+use crate::events::Event as WorkerEvent;
+use chrono::NaiveDateTime;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// How each event is written
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// `timestamp worker event_type msg`
+    #[default]
+    Text,
+    /// One JSON object per line, with every event field (replayable)
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            other => Err(format!(
+                "invalid log format '{}', expected 'text' or 'json'",
+                other
+            )),
+        }
+    }
+}
+
+/// Where and how to write the event log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventLogConfig {
+    /// The live log file; rotated files get a `.1`, `.2`, ... suffix
+    pub path: PathBuf,
+    /// Line format
+    pub format: LogFormat,
+    /// Size at which the live file is rolled over
+    pub max_bytes: u64,
+    /// Rotated files kept besides the live one
+    pub keep_files: usize,
+}
+
+impl EventLogConfig {
+    /// Log to `path` with the default rotation limits
+    pub fn new(path: PathBuf, format: LogFormat) -> Self {
+        Self {
+            path,
+            format,
+            max_bytes: EVENT_LOG_MAX_BYTES,
+            keep_files: EVENT_LOG_KEEP_FILES,
+        }
+    }
+}
+
+/// Format an event as a single log line, including the trailing newline
+pub fn format_line(event: &WorkerEvent, format: LogFormat) -> String {
+    let msg = event.msg.trim_end().replace('\n', " ");
+    match format {
+        LogFormat::Text => format!(
+            "{} {:?} {} {}\n",
+            event.timestamp, event.worker, event.event_type, msg
+        ),
+        LogFormat::Json => {
+            let event = WorkerEvent {
+                msg,
+                ..event.clone()
+            };
+            // Serializing plain strings and enums can't fail
+            serde_json::to_string(&event).unwrap_or_default() + "\n"
+        }
+    }
+}
+
+/// Handle to the background writer
+#[derive(Debug)]
+pub struct EventLog {
+    /// Taken on drop, closing the queue so the writer finishes
+    events: Option<SyncSender<WorkerEvent>>,
+    failure: Receiver<String>,
+    writer: Option<JoinHandle<()>>,
+}
+
+impl EventLog {
+    /// Open the log file and start the writer. Path errors surface here,
+    /// before anything is queued.
+    pub fn open(config: EventLogConfig) -> io::Result<Self> {
+        let writer = RotatingWriter::open(config)?;
+        let (events, queue) = mpsc::sync_channel(EVENT_LOG_QUEUE_SIZE);
+        let (failed, failure) = mpsc::channel();
+        let writer = std::thread::spawn(move || writer.run(queue, failed));
+        Ok(Self {
+            events: Some(events),
+            failure,
+            writer: Some(writer),
+        })
+    }
+
+    /// Queue an event for writing without waiting on the disk. Events are
+    /// dropped if the writer falls a full queue behind: keeping the dashboard
+    /// responsive matters more than a complete log.
+    pub fn record(&self, event: &WorkerEvent) {
+        if let Some(events) = &self.events {
+            let _ = events.try_send(event.clone());
+        }
+    }
+
+    /// Why the writer stopped, if it has
+    pub fn failure(&self) -> Option<String> {
+        self.failure.try_recv().ok()
+    }
+}
+
+impl Drop for EventLog {
+    /// Close the queue and wait for the writer to finish the events in it
+    fn drop(&mut self) {
+        self.events = None;
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+        }
+    }
+}
+
+/// Appends lines to the live file, rotating it by size
+struct RotatingWriter {
+    config: EventLogConfig,
+    file: File,
+    written: u64,
+}
+
+impl RotatingWriter {
+    fn open(config: EventLogConfig) -> io::Result<Self> {
+        if let Some(parent) = config.path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent)?;
+            }
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&config.path)?;
+        let written = file.metadata()?.len();
+        Ok(Self {
+            config,
+            file,
+            written,
+        })
+    }
+
+    /// Write queued events until the dashboard goes away or a write fails
+    fn run(mut self, queue: Receiver<WorkerEvent>, failed: mpsc::Sender<String>) {
+        for event in queue {
+            if let Err(e) = self.write(&event) {
+                let _ = failed.send(format!(
+                    "Event log {} disabled: {}",
+                    self.config.path.display(),
+                    e
+                ));
+                return;
+            }
+        }
+    }
+
+    fn write(&mut self, event: &WorkerEvent) -> io::Result<()> {
+        let line = format_line(event, self.config.format);
+        if self.written > 0 && self.written + line.len() as u64 > self.config.max_bytes {
+            self.rotate()?;
+        }
+        self.file.write_all(line.as_bytes())?;
+        self.written += line.len() as u64;
+        Ok(())
+    }
+
+    /// Shift `log.N` to `log.N+1` (dropping the oldest) and start a fresh live file
+    fn rotate(&mut self) -> io::Result<()> {
+        let path = &self.config.path;
+        let keep = self.config.keep_files;
+        if keep > 0 {
+            for n in (1..keep).rev() {
+                let from = rotated_path(path, n);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(path, n + 1))?;
+                }
+            }
+            fs::rename(path, rotated_path(path, 1))?;
+        }
+        self.file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(path)?;
+        self.written = 0;
+        Ok(())
+    }
+}
+
+/// Read back the events of a JSON event log, oldest first. Lines that aren't
+/// events (e.g. cut off by a crash) are skipped.
+pub fn read_events(path: &Path) -> io::Result<Vec<WorkerEvent>> {
+    let mut events = Vec::new();
+    for line in BufReader::new(File::open(path)?).lines() {
+        if let Ok(event) = serde_json::from_str(&line?) {
+            events.push(event);
+        }
+    }
+    Ok(events)
+}
+
+/// How long to wait between replaying two events recorded at these
+/// timestamps, `speed` times faster than recorded. A speed of 0 (or an
+/// unreadable timestamp) doesn't wait at all.
+pub fn replay_delay(previous: &str, next: &str, speed: f64) -> Duration {
+    const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+    if speed <= 0.0 {
+        return Duration::ZERO;
+    }
+    let (Ok(previous), Ok(next)) = (
+        NaiveDateTime::parse_from_str(previous, FORMAT),
+        NaiveDateTime::parse_from_str(next, FORMAT),
+    ) else {
+        return Duration::ZERO;
+    };
+    let recorded = next
+        .signed_duration_since(previous)
+        .to_std()
+        .unwrap_or_default();
+    recorded.div_f64(speed)
+}
+
+/// `nexus.log` -> `nexus.log.2`
+fn rotated_path(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", n));
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventType;
+    use crate::logging::LogLevel;
+    use tempfile::tempdir;
+
+    fn event(msg: &str) -> WorkerEvent {
+        WorkerEvent::prover_with_level(0, msg.to_string(), EventType::Error, LogLevel::Error)
+    }
+
+    #[test]
+    fn test_text_line_is_single_line() {
+        let event = event("Proof failed\nfor task 7\n");
+        let line = format_line(&event, LogFormat::Text);
+        assert_eq!(
+            line,
+            format!(
+                "{} Prover(0) Error Proof failed for task 7\n",
+                event.timestamp
+            )
+        );
+    }
+
+    #[test]
+    fn test_writer_rotates_and_keeps_limited_files() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("events.log");
+        let line_len = format_line(&event("x"), LogFormat::Text).len() as u64;
+        let config = EventLogConfig {
+            path: path.clone(),
+            format: LogFormat::Text,
+            max_bytes: line_len * 2,
+            keep_files: 2,
+        };
+
+        let mut writer = RotatingWriter::open(config).unwrap();
+        for _ in 0..7 {
+            writer.write(&event("x")).unwrap();
+        }
+
+        // 7 lines at 2 per file: live (1), .1 (2), .2 (2); the oldest pair is gone
+        let lines = |p: &Path| fs::read_to_string(p).unwrap().lines().count();
+        assert_eq!(lines(&path), 1);
+        assert_eq!(lines(&rotated_path(&path, 1)), 2);
+        assert_eq!(lines(&rotated_path(&path, 2)), 2);
+        assert!(!rotated_path(&path, 3).exists());
+    }
+
+    #[test]
+    fn test_replay_delay_follows_recorded_cadence() {
+        let (a, b) = ("2025-01-01 12:00:00", "2025-01-01 12:00:30");
+        assert_eq!(replay_delay(a, b, 1.0), Duration::from_secs(30));
+        assert_eq!(replay_delay(a, b, 10.0), Duration::from_secs(3));
+        assert_eq!(replay_delay(a, b, 0.0), Duration::ZERO);
+        // Out-of-order or unreadable timestamps don't wait
+        assert_eq!(replay_delay(b, a, 1.0), Duration::ZERO);
+        assert_eq!(replay_delay("garbage", b, 1.0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_unwritable_path_fails_to_open() {
+        let dir = tempdir().unwrap();
+        // A directory can't be opened as the log file
+        let config = EventLogConfig::new(dir.path().to_path_buf(), LogFormat::Text);
+        assert!(EventLog::open(config).is_err());
+    }
+}
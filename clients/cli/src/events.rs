@@ -4,9 +4,11 @@
 
 use crate::logging::{LogLevel, should_log_with_env};
 use chrono::Local;
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
+use std::time::Instant;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Worker {
     /// Worker that fetches tasks from the orchestrator and processes them.
     TaskFetcher,
@@ -14,9 +16,12 @@ pub enum Worker {
     Prover(usize),
     /// Worker that submits proofs to the orchestrator.
     ProofSubmitter,
+    /// The dashboard itself, for problems with its own outputs (log files,
+    /// history, forwarders) rather than with the pipeline.
+    Dashboard,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, strum::Display)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, strum::Display, Serialize, Deserialize)]
 pub enum EventType {
     Success,
     Error,
@@ -26,23 +31,85 @@ pub enum EventType {
 }
 
 /// Represents the current state in the proof pipeline
-#[derive(Debug, Copy, Clone, Eq, PartialEq, strum::Display)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, strum::Display, Serialize, Deserialize)]
 pub enum ProverState {
     /// Computing the proof
     Proving,
     /// Waiting before fetching next task (idle state)
+    #[default]
     Waiting,
 }
 
-#[derive(Debug, Clone)]
+/// Structured progress of a task through the proof pipeline.
+///
+/// Carried alongside the human-readable message so consumers never have to
+/// parse `msg` to track progress.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ProofPhase {
+    /// Requesting a task from the orchestrator
+    Requesting,
+    /// Waiting out a backoff before the next request
+    Backoff { wait_secs: u64 },
+    /// A task was obtained from the orchestrator
+    Fetched { task_id: String },
+    /// Proving of the task has started
+    Proving { task_id: String },
+    /// A proof was generated for the task, with its serialized size in bytes
+    /// when known
+    ProofGenerated {
+        task_id: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        proof_bytes: Option<u64>,
+    },
+    /// The proof was accepted by the orchestrator, with the number of
+    /// submission attempts it took when known
+    Submitted {
+        task_id: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        attempts: Option<u32>,
+    },
+    /// Proof generation failed for the task
+    ProofFailed { task_id: String },
+    /// The orchestrator didn't accept the task's proof
+    SubmitFailed { task_id: String },
+}
+
+impl ProofPhase {
+    /// The task this phase is about, if any
+    pub fn task_id(&self) -> Option<&str> {
+        match self {
+            ProofPhase::Requesting | ProofPhase::Backoff { .. } => None,
+            ProofPhase::Fetched { task_id }
+            | ProofPhase::Proving { task_id }
+            | ProofPhase::ProofGenerated { task_id, .. }
+            | ProofPhase::Submitted { task_id, .. }
+            | ProofPhase::ProofFailed { task_id }
+            | ProofPhase::SubmitFailed { task_id } => Some(task_id),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Event {
     pub worker: Worker,
     pub msg: String,
+    /// Wall-clock time, for display and logs only: it jumps when the system
+    /// clock is changed
     pub timestamp: String,
+    /// Monotonic time the event was created, which all timing is measured
+    /// against. Not serialized: deserialized events are stamped when read.
+    #[serde(skip, default = "Instant::now")]
+    pub emitted_at: Instant,
     pub event_type: EventType,
     pub log_level: LogLevel,
     /// Optional state information for state change events
     pub prover_state: Option<ProverState>,
+    /// Optional pipeline progress for metrics tracking
+    pub phase: Option<ProofPhase>,
+    /// Node the event was produced for, when the sender knows it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<u64>,
 }
 
 impl PartialEq for Event {
@@ -53,21 +120,26 @@ impl PartialEq for Event {
             && self.event_type == other.event_type
             && self.log_level == other.log_level
             && self.prover_state == other.prover_state
-        // Note: We don't compare state_start_time since Instant doesn't implement Eq
+            && self.phase == other.phase
+            && self.node_id == other.node_id
+        // emitted_at isn't compared: equal events may be created at different instants
     }
 }
 
 impl Eq for Event {}
 
 impl Event {
-    fn new(worker: Worker, msg: String, event_type: EventType, log_level: LogLevel) -> Self {
+    pub fn new(worker: Worker, msg: String, event_type: EventType, log_level: LogLevel) -> Self {
         Self {
             worker,
             msg,
             timestamp: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            emitted_at: Instant::now(),
             event_type,
             log_level,
             prover_state: None,
+            phase: None,
+            node_id: None,
         }
     }
 
@@ -76,12 +148,33 @@ impl Event {
             worker: Worker::TaskFetcher,
             msg,
             timestamp: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            emitted_at: Instant::now(),
             event_type: EventType::StateChange,
             log_level: LogLevel::Info,
             prover_state: Some(state),
+            phase: None,
+            node_id: None,
         }
     }
 
+    /// Attach pipeline progress to this event
+    pub fn with_phase(mut self, phase: ProofPhase) -> Self {
+        self.phase = Some(phase);
+        self
+    }
+
+    /// Attribute this event to a specific node
+    pub fn with_node_id(mut self, node_id: u64) -> Self {
+        self.node_id = Some(node_id);
+        self
+    }
+
+    /// Attribute this event to a specific worker
+    pub fn with_worker(mut self, worker: Worker) -> Self {
+        self.worker = worker;
+        self
+    }
+
     pub fn task_fetcher_with_level(
         msg: String,
         event_type: EventType,
@@ -98,6 +191,10 @@ impl Event {
         Self::new(Worker::ProofSubmitter, msg, event_type, log_level)
     }
 
+    pub fn dashboard_with_level(msg: String, event_type: EventType, log_level: LogLevel) -> Self {
+        Self::new(Worker::Dashboard, msg, event_type, log_level)
+    }
+
     pub fn prover_with_level(
         thread_id: usize,
         msg: String,
@@ -107,6 +204,16 @@ impl Event {
         Self::new(Worker::Prover(thread_id), msg, event_type, log_level)
     }
 
+    /// The task this event is about. Read from the structured phase when there
+    /// is one, otherwise parsed from a "... task <id> ..." message (events
+    /// from older builds, and errors raised without a phase).
+    pub fn task_id(&self) -> Option<&str> {
+        match &self.phase {
+            Some(phase) => phase.task_id(),
+            None => parse_task_id(&self.msg),
+        }
+    }
+
     pub fn should_display(&self) -> bool {
         // Always show success events and info level events
         if self.event_type == EventType::Success || self.log_level >= LogLevel::Info {
@@ -125,3 +232,67 @@ impl Display for Event {
         write!(f, "{} [{}] {}", self.event_type, self.timestamp, self.msg)
     }
 }
+
+/// Find the ID following the word "task" in a message, e.g.
+/// "Proof generation failed for task [3f2b-9c]: timeout" -> "3f2b-9c".
+/// Surrounding brackets, quotes and trailing punctuation are not part of the ID,
+/// and a word without any digit ("for task here") is not taken for one.
+fn parse_task_id(msg: &str) -> Option<&str> {
+    let mut words = msg.split_whitespace();
+    while let Some(word) = words.next() {
+        if word.eq_ignore_ascii_case("task") {
+            let id = words
+                .next()?
+                .trim_matches(|c: char| "[](){}<>\"'`".contains(c))
+                .trim_end_matches([':', ',', '.', ';', '!'])
+                .trim_matches(|c: char| "[](){}<>\"'`".contains(c));
+            return id.contains(|c: char| c.is_ascii_digit()).then_some(id);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_id_prefers_the_phase() {
+        let event = Event::task_fetcher_with_level(
+            "Step 1 of 4: Got task something-else".to_string(),
+            EventType::Success,
+            LogLevel::Info,
+        )
+        .with_phase(ProofPhase::Fetched {
+            task_id: "3f2b9c1e-8d4a-4e2b-9f1a-6c5d4e3b2a10".to_string(),
+        });
+        assert_eq!(
+            event.task_id(),
+            Some("3f2b9c1e-8d4a-4e2b-9f1a-6c5d4e3b2a10")
+        );
+    }
+
+    #[test]
+    fn test_task_id_falls_back_to_the_message() {
+        let cases = [
+            (
+                "Proof generation failed for task Task-123: oom",
+                Some("Task-123"),
+            ),
+            (
+                "Proof generation failed for task 3f2b9c1e-8d4a-4e2b-9f1a-6c5d4e3b2a10: oom",
+                Some("3f2b9c1e-8d4a-4e2b-9f1a-6c5d4e3b2a10"),
+            ),
+            ("Submitting task [abc-42], attempt 2", Some("abc-42")),
+            ("Retrying task (\"abc-43\").", Some("abc-43")),
+            ("Task <xyz_7> done", Some("xyz_7")),
+            ("No task here", None),
+            ("Fetching task", None),
+        ];
+        for (msg, expected) in cases {
+            let event =
+                Event::prover_with_level(0, msg.to_string(), EventType::Error, LogLevel::Error);
+            assert_eq!(event.task_id(), expected, "{}", msg);
+        }
+    }
+}
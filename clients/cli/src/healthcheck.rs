@@ -0,0 +1,223 @@
+//! Health check
+//!
+//! `nexus-network healthcheck` reads a running instance's snapshot from the
+//! status API and turns it into an exit code for liveness probes: 0 when a
+//! proof was submitted recently enough (or the instance started less than that
+//! long ago, or the schedule has paused it), 1 when it has gone idle or a
+//! prover is stuck, and 2 when no instance answers.
+
+use crate::status_server::fetch_snapshot;
+use crate::ui::metrics::format_duration;
+use serde::Deserialize;
+use std::fmt;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+/// Longest gaps that still count as healthy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthThresholds {
+    /// Since the last successful submission, or since startup before the first
+    pub max_since_success: Duration,
+    /// That a single proof may run before its prover counts as stuck
+    pub max_proving: Duration,
+}
+
+/// Outcome of a health check, with a one-line reason
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Health {
+    Healthy(String),
+    /// Idle for too long, or a prover is stuck
+    Unhealthy(String),
+    /// No instance answered, or not with a snapshot
+    Unreachable(String),
+}
+
+impl Health {
+    /// Exit code documented for the `healthcheck` command
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Health::Healthy(_) => 0,
+            Health::Unhealthy(_) => 1,
+            Health::Unreachable(_) => 2,
+        }
+    }
+}
+
+impl fmt::Display for Health {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Health::Healthy(reason) => write!(f, "healthy: {}", reason),
+            Health::Unhealthy(reason) => write!(f, "unhealthy: {}", reason),
+            Health::Unreachable(reason) => write!(f, "unreachable: {}", reason),
+        }
+    }
+}
+
+/// The parts of a dashboard snapshot the check reads. `uptime_secs` and
+/// `provers` are required, so JSON from anything but a dashboard (even `{}`)
+/// is rejected instead of passing as a healthy instance.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct HealthView {
+    pub uptime_secs: u64,
+    pub secs_since_last_success: Option<u64>,
+    /// Paused by the schedule; missing from snapshots of older builds
+    #[serde(default)]
+    pub paused: bool,
+    pub provers: Vec<ProverHealth>,
+}
+
+/// One prover's entry in the snapshot
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ProverHealth {
+    pub id: usize,
+    pub proving_secs: Option<u64>,
+}
+
+impl HealthView {
+    /// A stuck prover is unhealthy even if others are still submitting;
+    /// otherwise the time since the last success decides, unless the schedule
+    /// has paused proving
+    pub fn assess(&self, thresholds: HealthThresholds) -> Health {
+        let max_proving = thresholds.max_proving.as_secs();
+        let max_since_success = thresholds.max_since_success.as_secs();
+
+        let stuck = self.provers.iter().find_map(|prover| {
+            prover
+                .proving_secs
+                .filter(|&secs| secs > max_proving)
+                .map(|secs| (prover.id, secs))
+        });
+        if let Some((id, secs)) = stuck {
+            return Health::Unhealthy(format!(
+                "prover {} has been proving one task for {} (limit {})",
+                id,
+                format_duration(secs),
+                format_duration(max_proving)
+            ));
+        }
+
+        if self.paused {
+            return Health::Healthy("paused by schedule".to_string());
+        }
+
+        match self.secs_since_last_success {
+            Some(secs) if secs <= max_since_success => {
+                Health::Healthy(format!("last submission {} ago", format_duration(secs)))
+            }
+            Some(secs) => Health::Unhealthy(format!(
+                "no submission for {} (limit {})",
+                format_duration(secs),
+                format_duration(max_since_success)
+            )),
+            None if self.uptime_secs <= max_since_success => Health::Healthy(format!(
+                "started {} ago, no submission yet",
+                format_duration(self.uptime_secs)
+            )),
+            None => Health::Unhealthy(format!(
+                "no submission since starting {} ago (limit {})",
+                format_duration(self.uptime_secs),
+                format_duration(max_since_success)
+            )),
+        }
+    }
+}
+
+/// Check the instance whose status API listens on `addr`
+pub async fn check(addr: SocketAddr, thresholds: HealthThresholds) -> Health {
+    let snapshot = match fetch_snapshot(addr).await {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            return Health::Unreachable(format!("no instance answered on {} ({})", addr, e));
+        }
+    };
+    match serde_json::from_value::<HealthView>(snapshot) {
+        Ok(view) => view.assess(thresholds),
+        Err(e) => Health::Unreachable(format!("unexpected snapshot from {} ({})", addr, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLDS: HealthThresholds = HealthThresholds {
+        max_since_success: Duration::from_secs(3600),
+        max_proving: Duration::from_secs(600),
+    };
+
+    fn view(uptime_secs: u64, secs_since_last_success: Option<u64>) -> HealthView {
+        HealthView {
+            uptime_secs,
+            secs_since_last_success,
+            paused: false,
+            provers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_recent_submission_is_healthy_and_a_stale_one_is_not() {
+        let health = view(7200, Some(120)).assess(THRESHOLDS);
+        assert_eq!(health.exit_code(), 0);
+        assert_eq!(health.to_string(), "healthy: last submission 2m 0s ago");
+
+        let health = view(7200, Some(3601)).assess(THRESHOLDS);
+        assert_eq!(health.exit_code(), 1);
+    }
+
+    #[test]
+    fn test_startup_counts_until_the_first_submission() {
+        assert_eq!(view(600, None).assess(THRESHOLDS).exit_code(), 0);
+        assert_eq!(view(3601, None).assess(THRESHOLDS).exit_code(), 1);
+    }
+
+    #[test]
+    fn test_a_schedule_pause_is_healthy_however_long_it_lasts() {
+        let mut view = view(86_400, Some(36_000));
+        view.paused = true;
+        let health = view.assess(THRESHOLDS);
+        assert_eq!(health.exit_code(), 0);
+        assert_eq!(health.to_string(), "healthy: paused by schedule");
+    }
+
+    #[test]
+    fn test_a_stuck_prover_is_unhealthy_despite_recent_submissions() {
+        let mut view = view(7200, Some(60));
+        view.provers = vec![
+            ProverHealth {
+                id: 0,
+                proving_secs: Some(30),
+            },
+            ProverHealth {
+                id: 1,
+                proving_secs: Some(601),
+            },
+        ];
+        let health = view.assess(THRESHOLDS);
+        assert_eq!(health.exit_code(), 1);
+        assert!(health.to_string().contains("prover 1"));
+    }
+
+    #[test]
+    fn test_only_dashboard_snapshots_are_read() {
+        assert!(serde_json::from_str::<HealthView>("{}").is_err());
+        assert!(serde_json::from_str::<HealthView>(r#"{"status": "ok"}"#).is_err());
+
+        let view: HealthView = serde_json::from_str(
+            r#"{"uptime_secs": 60, "secs_since_last_success": null,
+                "provers": [{"id": 0, "proving_secs": 12}]}"#,
+        )
+        .unwrap();
+        assert_eq!(view.assess(THRESHOLDS).exit_code(), 0);
+        assert_eq!(view.provers[0].proving_secs, Some(12));
+    }
+
+    #[tokio::test]
+    async fn test_no_instance_is_unreachable() {
+        // Nothing listens on a freshly released port
+        let closed = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        assert_eq!(check(closed, THRESHOLDS).await.exit_code(), 2);
+    }
+}
@@ -0,0 +1,428 @@
+//! Proving history database
+//!
+//! Keeps a SQLite record of every submitted task and a periodic system metrics
+//! row, for querying throughput and earnings over weeks rather than just the
+//! current session. Rows are queued to a background thread and written in
+//! batched transactions, so the dashboard never waits on the disk. Needs the
+//! `history` feature; without it, opening the database fails with a message
+//! saying so.
+
+use crate::consts::cli_consts::history::{
+    BATCH_SIZE, PENDING_TASKS, QUEUE_SIZE, flush_interval, system_sample_interval,
+};
+use crate::ui::metrics::{SystemMetrics, push_bounded};
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, SyncSender};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Tables created when absent; bump `SCHEMA_VERSION` alongside any change
+#[cfg(feature = "history")]
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS tasks (
+    task_id TEXT PRIMARY KEY,
+    fetched_at TEXT,
+    proved_at TEXT,
+    submitted_at TEXT NOT NULL,
+    duration_secs INTEGER
+);
+CREATE INDEX IF NOT EXISTS tasks_submitted_at ON tasks (submitted_at);
+CREATE TABLE IF NOT EXISTS system_samples (
+    sampled_at TEXT NOT NULL,
+    cpu_percent REAL NOT NULL,
+    ram_bytes INTEGER NOT NULL,
+    ram_usage_pct REAL NOT NULL,
+    network_rx_bytes_per_sec REAL NOT NULL,
+    network_tx_bytes_per_sec REAL NOT NULL
+);
+CREATE INDEX IF NOT EXISTS system_samples_sampled_at ON system_samples (sampled_at);
+";
+
+/// Version recorded in the database's `user_version` once `SCHEMA` is applied
+#[cfg(feature = "history")]
+const SCHEMA_VERSION: i64 = 1;
+
+/// A submitted task. Times are event timestamps (`YYYY-MM-DD HH:MM:SS`,
+/// local time), which SQLite's date functions read as-is.
+#[cfg_attr(not(feature = "history"), allow(dead_code))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskRecord {
+    pub task_id: String,
+    /// When the task was fetched, if that was observed
+    pub fetched_at: Option<String>,
+    /// When its proof was generated, if that was observed
+    pub proved_at: Option<String>,
+    pub submitted_at: String,
+    /// Proving time in seconds, if the proof was timed
+    pub duration_secs: Option<u64>,
+}
+
+/// System metrics at one point in time
+#[cfg_attr(not(feature = "history"), allow(dead_code))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SystemSample {
+    pub sampled_at: String,
+    pub cpu_percent: f32,
+    pub ram_bytes: u64,
+    pub ram_usage_pct: f32,
+    pub network_rx_bytes_per_sec: f64,
+    pub network_tx_bytes_per_sec: f64,
+}
+
+impl SystemSample {
+    pub fn capture(sampled_at: String, metrics: &SystemMetrics) -> Self {
+        Self {
+            sampled_at,
+            cpu_percent: metrics.cpu_percent,
+            ram_bytes: metrics.ram_bytes,
+            ram_usage_pct: metrics.ram_usage_pct,
+            network_rx_bytes_per_sec: metrics.network_rx_bytes_per_sec,
+            network_tx_bytes_per_sec: metrics.network_tx_bytes_per_sec,
+        }
+    }
+}
+
+/// One row to write
+#[derive(Debug, Clone, PartialEq)]
+pub enum HistoryRecord {
+    Task(TaskRecord),
+    System(SystemSample),
+}
+
+/// Why the history database can't be used
+#[derive(Debug, thiserror::Error)]
+pub enum HistoryError {
+    #[cfg(feature = "history")]
+    #[error(transparent)]
+    Sqlite(#[from] rusqlite::Error),
+    #[cfg(not(feature = "history"))]
+    #[error("this build lacks the `history` feature")]
+    Unsupported,
+}
+
+/// Handle to the background writer, plus the fetch and proof times of tasks
+/// that haven't been submitted yet
+#[derive(Debug)]
+pub struct History {
+    /// Taken on drop, closing the queue so the writer finishes
+    records: Option<SyncSender<HistoryRecord>>,
+    failure: Receiver<String>,
+    pending: PendingTasks,
+    last_system_sample: Option<Instant>,
+    writer: Option<JoinHandle<()>>,
+}
+
+impl History {
+    /// Open (creating if needed) the database and start the writer. Path and
+    /// schema errors surface here, before anything is queued.
+    pub fn open(path: &Path) -> Result<Self, HistoryError> {
+        let store = Store::open(path)?;
+        let (records, queue) = mpsc::sync_channel(QUEUE_SIZE);
+        let (failed, failure) = mpsc::channel();
+        let path = path.to_path_buf();
+        let writer = std::thread::spawn(move || run(store, path, queue, failed));
+        Ok(Self {
+            records: Some(records),
+            failure,
+            pending: PendingTasks::new(PENDING_TASKS),
+            last_system_sample: None,
+            writer: Some(writer),
+        })
+    }
+
+    /// Note when a task was fetched
+    pub fn task_fetched(&mut self, task_id: &str, at: &str) {
+        self.pending.entry(task_id).fetched_at = Some(at.to_string());
+    }
+
+    /// Note when a task's proof was generated, and how long it took
+    pub fn task_proved(&mut self, task_id: &str, at: &str, duration: Option<Duration>) {
+        let record = self.pending.entry(task_id);
+        record.proved_at = Some(at.to_string());
+        record.duration_secs = duration.map(|duration| duration.as_secs());
+    }
+
+    /// Queue the row for a submitted task
+    pub fn task_submitted(&mut self, task_id: &str, at: &str) {
+        let record = TaskRecord {
+            submitted_at: at.to_string(),
+            ..self.pending.take(task_id)
+        };
+        self.queue(HistoryRecord::Task(record));
+    }
+
+    /// Queue a system metrics row, if the sample interval has passed
+    pub fn sample_system(&mut self, now: Instant, metrics: &SystemMetrics) {
+        if self
+            .last_system_sample
+            .is_some_and(|at| now.saturating_duration_since(at) < system_sample_interval())
+        {
+            return;
+        }
+        self.last_system_sample = Some(now);
+        let sampled_at = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        self.queue(HistoryRecord::System(SystemSample::capture(
+            sampled_at, metrics,
+        )));
+    }
+
+    /// Rows are dropped if the writer falls a full queue behind: keeping the
+    /// dashboard responsive matters more than a complete history.
+    fn queue(&self, record: HistoryRecord) {
+        if let Some(records) = &self.records {
+            let _ = records.try_send(record);
+        }
+    }
+
+    /// Why the writer stopped, if it has
+    pub fn failure(&self) -> Option<String> {
+        self.failure.try_recv().ok()
+    }
+}
+
+impl Drop for History {
+    /// Close the queue and wait for the writer to commit the rows in it
+    fn drop(&mut self) {
+        self.records = None;
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+        }
+    }
+}
+
+/// Partial rows of tasks awaiting submission.
+///
+/// Bounded so tasks that are never submitted don't pile up; the oldest is
+/// forgotten first.
+#[derive(Debug)]
+pub struct PendingTasks {
+    capacity: usize,
+    records: HashMap<String, TaskRecord>,
+    order: VecDeque<String>,
+}
+
+impl PendingTasks {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            records: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    pub fn entry(&mut self, task_id: &str) -> &mut TaskRecord {
+        if !self.records.contains_key(task_id) {
+            for oldest in push_bounded(&mut self.order, self.capacity, task_id.to_string()) {
+                self.records.remove(&oldest);
+            }
+        }
+        self.records
+            .entry(task_id.to_string())
+            .or_insert_with(|| TaskRecord {
+                task_id: task_id.to_string(),
+                ..TaskRecord::default()
+            })
+    }
+
+    /// Remove the task's partial row (a bare one if nothing was noted)
+    pub fn take(&mut self, task_id: &str) -> TaskRecord {
+        self.order.retain(|id| id != task_id);
+        self.records.remove(task_id).unwrap_or_else(|| TaskRecord {
+            task_id: task_id.to_string(),
+            ..TaskRecord::default()
+        })
+    }
+}
+
+/// Write queued rows in batches until the dashboard goes away or a write fails
+fn run(
+    mut store: Store,
+    path: PathBuf,
+    queue: Receiver<HistoryRecord>,
+    failed: mpsc::Sender<String>,
+) {
+    loop {
+        let (batch, open) = next_batch(&queue, BATCH_SIZE, flush_interval());
+        if !batch.is_empty() {
+            if let Err(e) = store.write(&batch) {
+                let _ = failed.send(format!(
+                    "History database {} disabled: {}",
+                    path.display(),
+                    e
+                ));
+                return;
+            }
+        }
+        if !open {
+            return;
+        }
+    }
+}
+
+/// Wait for a record, then gather more for up to `wait` (or until `max`), so
+/// bursts share one transaction. Also says whether the queue is still open.
+fn next_batch<T>(queue: &Receiver<T>, max: usize, wait: Duration) -> (Vec<T>, bool) {
+    let Ok(first) = queue.recv() else {
+        return (Vec::new(), false);
+    };
+    let mut batch = vec![first];
+    let deadline = Instant::now() + wait;
+    while batch.len() < max {
+        match queue.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(record) => batch.push(record),
+            Err(RecvTimeoutError::Timeout) => break,
+            Err(RecvTimeoutError::Disconnected) => return (batch, false),
+        }
+    }
+    (batch, true)
+}
+
+/// The SQLite database
+#[cfg(feature = "history")]
+struct Store {
+    conn: rusqlite::Connection,
+}
+
+#[cfg(feature = "history")]
+impl Store {
+    fn open(path: &Path) -> Result<Self, HistoryError> {
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                // A missing directory shows up as an open error just after
+                let _ = std::fs::create_dir_all(parent);
+            }
+        }
+        let conn = rusqlite::Connection::open(path)?;
+        migrate(&conn)?;
+        Ok(Self { conn })
+    }
+
+    /// Write a batch in one transaction
+    fn write(&mut self, batch: &[HistoryRecord]) -> Result<(), HistoryError> {
+        let tx = self.conn.transaction()?;
+        for record in batch {
+            match record {
+                HistoryRecord::Task(task) => {
+                    tx.prepare_cached(
+                        "INSERT OR REPLACE INTO tasks
+                         (task_id, fetched_at, proved_at, submitted_at, duration_secs)
+                         VALUES (?1, ?2, ?3, ?4, ?5)",
+                    )?
+                    .execute(rusqlite::params![
+                        task.task_id,
+                        task.fetched_at,
+                        task.proved_at,
+                        task.submitted_at,
+                        task.duration_secs.map(|secs| secs as i64),
+                    ])?;
+                }
+                HistoryRecord::System(sample) => {
+                    tx.prepare_cached(
+                        "INSERT INTO system_samples
+                         (sampled_at, cpu_percent, ram_bytes, ram_usage_pct,
+                          network_rx_bytes_per_sec, network_tx_bytes_per_sec)
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    )?
+                    .execute(rusqlite::params![
+                        sample.sampled_at,
+                        sample.cpu_percent,
+                        sample.ram_bytes as i64,
+                        sample.ram_usage_pct,
+                        sample.network_rx_bytes_per_sec,
+                        sample.network_tx_bytes_per_sec,
+                    ])?;
+                }
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+}
+
+/// Bring the schema up to date: create the tables if absent
+#[cfg(feature = "history")]
+fn migrate(conn: &rusqlite::Connection) -> rusqlite::Result<()> {
+    let version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    if version < SCHEMA_VERSION {
+        conn.execute_batch(&format!(
+            "BEGIN; {} PRAGMA user_version = {}; COMMIT;",
+            SCHEMA, SCHEMA_VERSION
+        ))?;
+    }
+    Ok(())
+}
+
+/// Without the `history` feature there is no database to open
+#[cfg(not(feature = "history"))]
+enum Store {}
+
+#[cfg(not(feature = "history"))]
+impl Store {
+    fn open(_path: &Path) -> Result<Self, HistoryError> {
+        Err(HistoryError::Unsupported)
+    }
+
+    fn write(&mut self, _batch: &[HistoryRecord]) -> Result<(), HistoryError> {
+        match *self {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_task_times_end_up_in_its_row() {
+        let mut pending = PendingTasks::new(2);
+        pending.entry("a").fetched_at = Some("2025-01-01 12:00:00".to_string());
+        pending.entry("a").duration_secs = Some(90);
+        pending.entry("b");
+        pending.entry("c");
+
+        // "a" was the oldest and has been forgotten
+        assert_eq!(pending.take("a").fetched_at, None);
+        assert_eq!(pending.take("c").task_id, "c");
+        assert!(pending.records.contains_key("b"));
+    }
+
+    #[test]
+    fn test_batches_gather_queued_records_up_to_the_limit() {
+        let (records, queue) = mpsc::sync_channel(10);
+        for n in 0..5 {
+            records.send(n).unwrap();
+        }
+
+        assert_eq!(next_batch(&queue, 3, Duration::ZERO), (vec![0, 1, 2], true));
+        drop(records);
+        assert_eq!(next_batch(&queue, 3, Duration::ZERO), (vec![3, 4], false));
+    }
+
+    #[cfg(feature = "history")]
+    #[test]
+    fn test_rows_are_written_to_a_migrated_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.db");
+        let mut store = Store::open(&path).unwrap();
+        store
+            .write(&[HistoryRecord::Task(TaskRecord {
+                task_id: "a".to_string(),
+                submitted_at: "2025-01-01 12:05:00".to_string(),
+                duration_secs: Some(90),
+                ..TaskRecord::default()
+            })])
+            .unwrap();
+
+        // Reopening leaves the existing schema and rows alone
+        let store = Store::open(&path).unwrap();
+        let duration_secs: i64 = store
+            .conn
+            .query_row(
+                "SELECT duration_secs FROM tasks WHERE task_id = 'a'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(duration_secs, 90);
+    }
+}
@@ -0,0 +1,175 @@
+//! System log forwarding
+//!
+//! Mirrors every activity log event to the local syslog socket (picked up by
+//! journald on most Linux servers), with a severity derived from the event and
+//! the reporting worker as an RFC 5424 structured data field. Needs the
+//! `syslog` feature. Forwarding is best effort: if the socket can't be reached
+//! it stops at the first failure, which the dashboard reports once.
+
+use crate::events::{Event as WorkerEvent, EventType};
+use crate::logging::LogLevel;
+
+/// Structured data ID the worker field is recorded under
+#[cfg_attr(not(feature = "syslog"), allow(dead_code))]
+const SD_ID: &str = "nexus@32473";
+
+/// Syslog severity of a forwarded event
+#[cfg_attr(not(feature = "syslog"), allow(dead_code))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+    Debug,
+}
+
+impl Severity {
+    /// Errors are errors, successes info and state changes debug; anything
+    /// else follows its log level
+    pub fn of(event: &WorkerEvent) -> Self {
+        match event.event_type {
+            EventType::Error => Severity::Error,
+            EventType::Success => Severity::Info,
+            EventType::StateChange => Severity::Debug,
+            EventType::Refresh | EventType::Waiting => match event.log_level {
+                LogLevel::Error | LogLevel::Warn => Severity::Warning,
+                LogLevel::Info => Severity::Info,
+                LogLevel::Debug | LogLevel::Trace => Severity::Debug,
+            },
+        }
+    }
+}
+
+/// Why events can't be forwarded
+#[derive(Debug, thiserror::Error)]
+pub enum SyslogError {
+    #[cfg(feature = "syslog")]
+    #[error(transparent)]
+    Syslog(#[from] syslog::Error),
+    #[cfg(not(feature = "syslog"))]
+    #[error("this build lacks the `syslog` feature")]
+    Unsupported,
+}
+
+/// Connection to the local syslog socket
+pub struct Syslog {
+    backend: Backend,
+}
+
+impl std::fmt::Debug for Syslog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Syslog").finish_non_exhaustive()
+    }
+}
+
+impl Syslog {
+    /// Connect to the local syslog socket
+    pub fn connect() -> Result<Self, SyslogError> {
+        Ok(Self {
+            backend: Backend::connect()?,
+        })
+    }
+
+    /// Forward one event
+    pub fn forward(&mut self, event: &WorkerEvent) -> Result<(), SyslogError> {
+        let msg = event.msg.trim_end().replace('\n', " ");
+        self.backend
+            .send(Severity::of(event), &format!("{:?}", event.worker), msg)
+    }
+}
+
+/// The syslog crate's logger, writing RFC 5424 messages
+#[cfg(feature = "syslog")]
+struct Backend {
+    logger: syslog::Logger<syslog::LoggerBackend, syslog::Formatter5424>,
+}
+
+#[cfg(feature = "syslog")]
+impl Backend {
+    fn connect() -> Result<Self, SyslogError> {
+        let formatter = syslog::Formatter5424 {
+            facility: syslog::Facility::LOG_DAEMON,
+            hostname: None,
+            process: "nexus-network".to_string(),
+            pid: std::process::id(),
+        };
+        Ok(Self {
+            logger: syslog::unix(formatter)?,
+        })
+    }
+
+    fn send(&mut self, severity: Severity, worker: &str, msg: String) -> Result<(), SyslogError> {
+        let mut data = syslog::StructuredData::new();
+        data.entry(SD_ID.to_string())
+            .or_default()
+            .insert("worker".to_string(), worker.to_string());
+        let message = (0, data, msg);
+        match severity {
+            Severity::Error => self.logger.err(message)?,
+            Severity::Warning => self.logger.warning(message)?,
+            Severity::Info => self.logger.info(message)?,
+            Severity::Debug => self.logger.debug(message)?,
+        }
+        Ok(())
+    }
+}
+
+/// Without the `syslog` feature there is no socket to connect to
+#[cfg(not(feature = "syslog"))]
+enum Backend {}
+
+#[cfg(not(feature = "syslog"))]
+impl Backend {
+    fn connect() -> Result<Self, SyslogError> {
+        Err(SyslogError::Unsupported)
+    }
+
+    fn send(
+        &mut self,
+        _severity: Severity,
+        _worker: &str,
+        _msg: String,
+    ) -> Result<(), SyslogError> {
+        match *self {}
+    }
+}
+
+/// Warning logged once when forwarding stops
+pub fn syslog_disabled_event(e: &SyslogError) -> WorkerEvent {
+    WorkerEvent::dashboard_with_level(
+        format!("System log forwarding disabled: {}", e),
+        EventType::Error,
+        LogLevel::Warn,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_severity_follows_event_type_then_log_level() {
+        let error =
+            WorkerEvent::prover_with_level(0, "x".to_string(), EventType::Error, LogLevel::Warn);
+        let success = WorkerEvent::proof_submitter_with_level(
+            "x".to_string(),
+            EventType::Success,
+            LogLevel::Info,
+        );
+        let state_change = WorkerEvent::prover_with_level(
+            0,
+            "x".to_string(),
+            EventType::StateChange,
+            LogLevel::Info,
+        );
+        let retry = WorkerEvent::task_fetcher_with_level(
+            "x".to_string(),
+            EventType::Refresh,
+            LogLevel::Warn,
+        );
+        assert_eq!(Severity::of(&error), Severity::Error);
+        assert_eq!(Severity::of(&success), Severity::Info);
+        assert_eq!(Severity::of(&state_change), Severity::Debug);
+        assert_eq!(Severity::of(&retry), Severity::Warning);
+    }
+}
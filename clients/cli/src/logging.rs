@@ -1,7 +1,8 @@
 use log::LevelFilter;
+use serde::{Deserialize, Serialize};
 use std::env;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum LogLevel {
     Trace = 0,
     Debug = 1,
@@ -2,38 +2,68 @@
 
 mod analytics;
 mod cli_messages;
+mod clipboard;
 mod config;
 mod consts;
 mod environment;
+mod event_log;
 mod events;
+mod healthcheck;
+mod history;
+mod journal;
 mod keys;
 mod logging;
+mod metrics_server;
 mod network;
 #[path = "proto/nexus.orchestrator.rs"]
 mod nexus_orchestrator;
+mod notifier;
 mod orchestrator;
 mod prover;
 mod register;
 mod runtime;
+mod schedule;
 mod session;
+mod stats;
+mod status_server;
 pub mod system;
 mod task;
+mod task_csv;
 mod ui;
 mod version;
+mod webhook;
 mod workers;
 
 use crate::config::{Config, get_config_path};
 use crate::environment::Environment;
+use crate::event_log::{EventLogConfig, LogFormat};
+use crate::healthcheck::HealthThresholds;
+use crate::metrics_server::MetricsServer;
+use crate::notifier::FailureNotifier;
 use crate::orchestrator::OrchestratorClient;
 use crate::prover::engine::ProvingEngine;
 use crate::register::{register_node, register_user};
+use crate::schedule::Schedule;
+use crate::session::replay::setup_replay;
 use crate::session::{run_headless_mode, run_tui_mode, setup_session};
+use crate::stats::{LifetimeStats, get_stats_path};
+use crate::status_server::StatusServer;
+use crate::ui::UIConfig;
+use crate::ui::dashboard::regression::RegressionConfig;
+use crate::ui::dashboard::snapshot::DashboardSnapshot;
+use crate::ui::metrics::{MetricsSnapshot, RamThresholds};
+use crate::ui::theme::{Theme, ThemeName, no_color_requested};
 use crate::version::manager::validate_version_requirements;
+use crate::webhook::Webhook;
 use clap::{ArgAction, Parser, Subcommand};
 use postcard::to_allocvec;
 use std::error::Error;
 use std::io::Write;
+use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::process::exit;
+use std::time::Duration;
+use tokio::sync::watch;
 
 #[derive(Parser)]
 #[command(author, version = concat!(env!("CARGO_PKG_VERSION"), " (build ", env!("BUILD_TIMESTAMP"), ")"), about, long_about = None)]
@@ -56,7 +86,15 @@ enum Command {
         #[arg(long = "headless", action = ArgAction::SetTrue)]
         headless: bool,
 
-        /// DEPRECATED: WILL BE IGNORED. Maximum number of threads to use for proving.
+        /// In headless mode, write events and periodic metrics snapshots as JSON lines
+        #[arg(long = "json", action = ArgAction::SetTrue, requires = "headless")]
+        json: bool,
+
+        /// Seconds between metrics snapshots in JSON mode
+        #[arg(long = "json-metrics-interval", value_name = "SECS", default_value_t = consts::cli_consts::JSON_METRICS_INTERVAL_SECS, value_parser = clap::value_parser!(u64).range(1..))]
+        json_metrics_interval: u64,
+
+        /// Number of provers to run at once (1-8, default 1). Each needs its own memory for proving.
         #[arg(long = "max-threads", value_name = "MAX_THREADS")]
         max_threads: Option<u32>,
 
@@ -80,6 +118,174 @@ enum Command {
         /// Available levels: SMALL, SMALL_MEDIUM, MEDIUM, LARGE, EXTRA_LARGE, EXTRA_LARGE2
         #[arg(long = "max-difficulty", value_name = "DIFFICULTY")]
         max_difficulty: Option<String>,
+
+        /// Daily proving schedule as comma-separated HH:MM-HH:MM=SETTING windows, where SETTING
+        /// is `paused`, `full`, or how many of the `--max-threads` provers may run. Windows may wrap
+        /// past midnight; where they overlap, the most restrictive setting wins.
+        /// Example: 09:00-17:00=1,17:00-09:00=full
+        #[arg(long = "schedule", value_name = "SCHEDULE")]
+        schedule: Option<Schedule>,
+
+        /// Points credited per successful proof, used for the dashboard's points total
+        #[arg(long = "points-per-task", value_name = "POINTS", default_value_t = consts::cli_consts::DEFAULT_POINTS_PER_TASK)]
+        points_per_task: u64,
+
+        /// Seconds a task fetch may take before the dashboard reports a timeout
+        #[arg(long = "fetch-timeout", value_name = "SECS", default_value_t = consts::cli_consts::DEFAULT_FETCH_TIMEOUT_SECS, value_parser = clap::value_parser!(u64).range(1..))]
+        fetch_timeout_secs: u64,
+
+        /// Seconds a single proof may run before the prover is flagged as stuck
+        #[arg(long = "stuck-threshold", value_name = "SECS", default_value_t = consts::cli_consts::DEFAULT_STUCK_PROVER_SECS, value_parser = clap::value_parser!(u64).range(1..))]
+        stuck_threshold_secs: u64,
+
+        /// Percentage slowdown over the baseline proving time that raises a performance regression alert
+        #[arg(long = "regression-pct", value_name = "PCT", default_value_t = consts::cli_consts::DEFAULT_REGRESSION_PCT)]
+        regression_pct: f64,
+
+        /// Baseline proving time for the regression alert; by default the average of the first proofs
+        #[arg(long = "regression-baseline", value_name = "SECS")]
+        regression_baseline_secs: Option<f64>,
+
+        /// Seconds without task fetcher activity before the dashboard shows the orchestrator as unreachable
+        #[arg(long = "stale-after", value_name = "SECS", default_value_t = consts::cli_consts::DEFAULT_STALE_FETCHER_SECS, value_parser = clap::value_parser!(u64).range(1..))]
+        stale_threshold_secs: u64,
+
+        /// Milliseconds between dashboard redraws; raise it to save CPU on low-power machines (minimum 50)
+        #[arg(long = "refresh-ms", value_name = "MS", default_value_t = consts::cli_consts::DEFAULT_REFRESH_MS)]
+        refresh_ms: u64,
+
+        /// RAM usage (percent of total memory) at which the RAM gauge turns yellow
+        #[arg(long = "ram-warn-pct", value_name = "PCT", default_value_t = consts::cli_consts::DEFAULT_RAM_WARN_PCT)]
+        ram_warn_pct: f32,
+
+        /// RAM usage (percent of total memory) at which the RAM gauge turns red
+        #[arg(long = "ram-critical-pct", value_name = "PCT", default_value_t = consts::cli_consts::DEFAULT_RAM_CRITICAL_PCT)]
+        ram_critical_pct: f32,
+
+        /// CPU temperature (°C) from which it is shown in red, where the CPU has a readable sensor
+        #[arg(long = "cpu-temp-warn", value_name = "CELSIUS", default_value_t = consts::cli_consts::DEFAULT_CPU_TEMP_WARN_CELSIUS)]
+        cpu_temp_warn: f32,
+
+        /// Weight of each new CPU and RAM sample in the displayed average, from 0 (never changes) to 1 (no smoothing)
+        #[arg(long = "metrics-smoothing", value_name = "ALPHA", default_value_t = consts::cli_consts::DEFAULT_METRICS_SMOOTHING)]
+        metrics_smoothing: f32,
+
+        /// Show instantaneous CPU and RAM readings instead of smoothed ones
+        #[arg(long = "raw-metrics", action = ArgAction::SetTrue, conflicts_with = "metrics_smoothing")]
+        raw_metrics: bool,
+
+        /// Number of events the activity log keeps for scrollback
+        #[arg(long = "event-history", value_name = "N", default_value_t = consts::cli_consts::MAX_ACTIVITY_LOGS, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        event_history: usize,
+
+        /// Number of tasks to submit per day; the dashboard shows today's progress and whether it is on pace
+        #[arg(long = "daily-target", value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+        daily_target: Option<u64>,
+
+        /// Serve Prometheus metrics at http://ADDR/metrics (e.g. 0.0.0.0:9090)
+        #[arg(long = "metrics-addr", value_name = "ADDR")]
+        metrics_addr: Option<SocketAddr>,
+
+        /// Serve a read-only JSON snapshot of the dashboard to each connection on ADDR (e.g. 127.0.0.1:9091)
+        #[arg(long = "status-addr", value_name = "ADDR")]
+        status_addr: Option<SocketAddr>,
+
+        /// POST a JSON record (task ID, timestamp, total points) to this URL for each submitted proof
+        #[arg(long = "webhook-url", value_name = "URL")]
+        webhook_url: Option<String>,
+
+        /// Append every event to this file, rotating it at 10 MB
+        #[arg(long = "log-file", value_name = "PATH")]
+        log_file: Option<PathBuf>,
+
+        /// Line format for --log-file: text or json
+        #[arg(
+            long = "log-format",
+            value_name = "FORMAT",
+            default_value = "text",
+            requires = "log_file"
+        )]
+        log_format: LogFormat,
+
+        /// Record each submitted task and a per-minute system sample in this SQLite database (requires the `history` feature)
+        #[arg(long = "history-db", value_name = "PATH")]
+        history_db: Option<PathBuf>,
+
+        /// Append one row per completed task (fetch time, proving time, submission latency, points) to this CSV file
+        #[arg(long = "csv", value_name = "PATH")]
+        csv: Option<PathBuf>,
+
+        /// Mirror every event to the system log (journald), with a severity per event (requires the `syslog` feature)
+        #[arg(long = "syslog", action = ArgAction::SetTrue)]
+        syslog: bool,
+
+        /// Write a JSON dump of the dashboard state to this file on exit (and on [D]), for bug reports
+        #[arg(long = "dump-state", value_name = "PATH")]
+        dump_state: Option<PathBuf>,
+
+        /// Leave node IDs out of state dumps, for sharing them publicly
+        #[arg(long = "redact-node-ids", action = ArgAction::SetTrue)]
+        redact_node_ids: bool,
+
+        /// Color theme: dark, light or high-contrast (defaults to dark, or no color when NO_COLOR is set)
+        #[arg(long = "theme", value_name = "THEME")]
+        theme: Option<ThemeName>,
+
+        /// Replay a JSON event log (written with --log-format json) instead of proving
+        #[arg(long = "replay", value_name = "PATH", conflicts_with = "log_file")]
+        replay: Option<PathBuf>,
+
+        /// Replay this many times faster than recorded (0 = as fast as possible)
+        #[arg(
+            long = "replay-speed",
+            value_name = "FACTOR",
+            default_value_t = 1.0,
+            requires = "replay"
+        )]
+        replay_speed: f64,
+
+        /// Discard lifetime statistics from earlier sessions and start from zero
+        #[arg(long = "reset-stats", action = ArgAction::SetTrue)]
+        reset_stats: bool,
+
+        /// Show a desktop notification after this many consecutive proving or submission failures (requires the `notify` feature)
+        #[arg(long = "notify-after", value_name = "FAILURES")]
+        notify_after: Option<usize>,
+
+        /// Minimum seconds between failure notifications
+        #[arg(long = "notify-cooldown", value_name = "SECS", default_value_t = consts::cli_consts::DEFAULT_NOTIFY_COOLDOWN_SECS, requires = "notify_after")]
+        notify_cooldown_secs: u64,
+
+        /// Don't show the confirmation after the session's first successful proof
+        #[arg(long = "no-first-proof-message", action = ArgAction::SetTrue)]
+        no_first_proof_message: bool,
+    },
+    /// Print the dashboard snapshot of an instance started with --status-addr, then exit
+    Status {
+        /// Status address of the running instance
+        #[arg(long = "addr", value_name = "ADDR", default_value = consts::cli_consts::DEFAULT_STATUS_ADDR)]
+        addr: SocketAddr,
+
+        /// Print the snapshot as a single compact JSON object, for scripts
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Check an instance started with --status-addr, for liveness probes. Exits 0 if it
+    /// submitted a proof recently enough, 1 if it is idle or a prover is stuck, 2 if it
+    /// can't be reached
+    Healthcheck {
+        /// Status address of the running instance
+        #[arg(long = "addr", value_name = "ADDR", default_value = consts::cli_consts::DEFAULT_STATUS_ADDR)]
+        addr: SocketAddr,
+
+        /// Longest time without a successful submission (or since startup, before the
+        /// first) that is still healthy, in seconds
+        #[arg(long = "max-since-success", value_name = "SECS", default_value_t = consts::cli_consts::DEFAULT_HEALTH_MAX_SINCE_SUCCESS_SECS)]
+        max_since_success: u64,
+
+        /// Longest a single proof may run before its prover counts as stuck, in seconds
+        #[arg(long = "max-stuck", value_name = "SECS", default_value_t = consts::cli_consts::DEFAULT_STUCK_PROVER_SECS)]
+        max_stuck: u64,
     },
     /// Register a new user
     RegisterUser {
@@ -124,12 +330,46 @@ async fn main() -> Result<(), Box<dyn Error>> {
         Command::Start {
             node_id,
             headless,
+            json,
+            json_metrics_interval,
             max_threads,
             orchestrator_url,
             check_mem,
             with_background,
             max_tasks,
             max_difficulty,
+            schedule,
+            points_per_task,
+            fetch_timeout_secs,
+            stuck_threshold_secs,
+            regression_pct,
+            regression_baseline_secs,
+            stale_threshold_secs,
+            refresh_ms,
+            ram_warn_pct,
+            ram_critical_pct,
+            cpu_temp_warn,
+            metrics_smoothing,
+            raw_metrics,
+            event_history,
+            daily_target,
+            metrics_addr,
+            status_addr,
+            webhook_url,
+            log_file,
+            log_format,
+            history_db,
+            csv,
+            syslog,
+            dump_state,
+            redact_node_ids,
+            theme,
+            replay,
+            replay_speed,
+            reset_stats,
+            notify_after,
+            notify_cooldown_secs,
+            no_first_proof_message,
         } => {
             // If a custom orchestrator URL is provided, create a custom environment
             let final_environment = if let Some(url) = orchestrator_url {
@@ -144,14 +384,78 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 final_environment,
                 config_path,
                 headless,
+                json.then_some(json_metrics_interval),
                 max_threads,
                 check_mem,
                 with_background,
                 max_tasks,
                 max_difficulty,
+                schedule,
+                points_per_task,
+                fetch_timeout_secs,
+                stuck_threshold_secs,
+                regression_pct,
+                regression_baseline_secs,
+                stale_threshold_secs,
+                refresh_ms,
+                ram_warn_pct,
+                ram_critical_pct,
+                cpu_temp_warn,
+                metrics_smoothing,
+                raw_metrics,
+                event_history,
+                daily_target,
+                metrics_addr,
+                status_addr,
+                webhook_url,
+                log_file,
+                log_format,
+                history_db,
+                csv,
+                syslog,
+                dump_state,
+                redact_node_ids,
+                theme,
+                replay,
+                replay_speed,
+                reset_stats,
+                notify_after,
+                notify_cooldown_secs,
+                !no_first_proof_message,
             )
             .await
         }
+        Command::Status { addr, json } => match status_server::fetch_snapshot(addr).await {
+            Ok(snapshot) => {
+                let output = if json {
+                    serde_json::to_string(&snapshot)?
+                } else {
+                    serde_json::to_string_pretty(&snapshot)?
+                };
+                println!("{}", output);
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!(
+                    "No running instance answered on {} ({}). Is it started with --status-addr {}?",
+                    addr, e, addr
+                );
+                exit(1);
+            }
+        },
+        Command::Healthcheck {
+            addr,
+            max_since_success,
+            max_stuck,
+        } => {
+            let thresholds = HealthThresholds {
+                max_since_success: Duration::from_secs(max_since_success),
+                max_proving: Duration::from_secs(max_stuck),
+            };
+            let health = healthcheck::check(addr, thresholds).await;
+            println!("{}", health);
+            exit(health.exit_code());
+        }
         Command::Logout => {
             print_cmd_info!("Logging out", "Clearing node configuration file...");
             Config::clear_node_config(&config_path).map_err(Into::into)
@@ -190,22 +494,148 @@ async fn main() -> Result<(), Box<dyn Error>> {
 /// * `env` - The environment to connect to.
 /// * `config_path` - Path to the configuration file.
 /// * `headless` - If true, runs without the terminal UI.
-/// * `max_threads` - Optional maximum number of threads to use for proving.
+/// * `json_metrics_interval` - In headless mode, emit JSON lines with metrics snapshots at this interval (seconds).
+/// * `max_threads` - Optional number of provers to run at once.
 /// * `check_mem` - Whether to check risky memory usage.
 /// * `with_background` - Whether to use the alternate TUI background color.
 /// * `max_tasks` - Optional maximum number of tasks to prove.
+/// * `max_difficulty` - Optional override for the task difficulty to request.
+/// * `schedule` - Optional daily schedule controlling when proving runs.
+/// * `points_per_task` - Points credited per successful proof.
+/// * `fetch_timeout_secs` - Seconds before a pending fetch is shown as timed out.
+/// * `stuck_threshold_secs` - Seconds before a proof in progress is flagged as stuck.
+/// * `regression_pct` - Slowdown percentage over the baseline that raises a regression alert.
+/// * `regression_baseline_secs` - Fixed baseline proving time, in seconds, for the regression alert.
+/// * `stale_threshold_secs` - Seconds of fetcher silence before the connection is shown as lost.
+/// * `refresh_ms` - Milliseconds between dashboard redraws.
+/// * `ram_warn_pct` - RAM usage percentage at which the RAM gauge turns yellow.
+/// * `ram_critical_pct` - RAM usage percentage at which the RAM gauge turns red.
+/// * `cpu_temp_warn` - CPU temperature in °C from which it is shown in red.
+/// * `metrics_smoothing` - Weight of each new CPU and RAM sample in their moving averages.
+/// * `raw_metrics` - Whether to show CPU and RAM readings unsmoothed.
+/// * `event_history` - Number of events the activity log retains.
+/// * `daily_target` - Optional number of tasks to submit per local day.
+/// * `metrics_addr` - Optional address to serve Prometheus metrics on.
+/// * `status_addr` - Optional address to serve JSON dashboard snapshots on.
+/// * `webhook_url` - Optional URL notified of each submitted proof.
+/// * `log_file` - Optional file every event is appended to.
+/// * `log_format` - Line format for the event log file.
+/// * `history_db` - Optional SQLite database proving history is recorded in.
+/// * `csv` - Optional CSV file completed tasks are appended to.
+/// * `syslog` - Whether events are mirrored to the system log.
+/// * `dump_state` - File a JSON state dump is written to on exit and on [D].
+/// * `redact_node_ids` - Whether state dumps leave out node IDs.
+/// * `theme` - Color theme, if one was chosen.
+/// * `replay` - Optional JSON event log to replay instead of proving.
+/// * `replay_speed` - Speed-up factor for the replay.
+/// * `reset_stats` - Whether to discard lifetime statistics from earlier sessions.
+/// * `notify_after` - Optional number of consecutive failures that triggers a desktop notification.
+/// * `notify_cooldown_secs` - Minimum seconds between failure notifications.
+/// * `show_first_proof` - Whether to confirm the session's first successful proof.
 #[allow(clippy::too_many_arguments)]
 async fn start(
     node_id: Option<u64>,
     env: Environment,
     config_path: std::path::PathBuf,
     headless: bool,
+    json_metrics_interval: Option<u64>,
     max_threads: Option<u32>,
     check_mem: bool,
     with_background: bool,
     max_tasks: Option<u32>,
     max_difficulty: Option<String>,
+    schedule: Option<Schedule>,
+    points_per_task: u64,
+    fetch_timeout_secs: u64,
+    stuck_threshold_secs: u64,
+    regression_pct: f64,
+    regression_baseline_secs: Option<f64>,
+    stale_threshold_secs: u64,
+    refresh_ms: u64,
+    ram_warn_pct: f32,
+    ram_critical_pct: f32,
+    cpu_temp_warn: f32,
+    metrics_smoothing: f32,
+    raw_metrics: bool,
+    event_history: usize,
+    daily_target: Option<u64>,
+    metrics_addr: Option<SocketAddr>,
+    status_addr: Option<SocketAddr>,
+    webhook_url: Option<String>,
+    log_file: Option<PathBuf>,
+    log_format: LogFormat,
+    history_db: Option<PathBuf>,
+    csv: Option<PathBuf>,
+    syslog: bool,
+    dump_state: Option<PathBuf>,
+    redact_node_ids: bool,
+    theme: Option<ThemeName>,
+    replay: Option<PathBuf>,
+    replay_speed: f64,
+    reset_stats: bool,
+    notify_after: Option<usize>,
+    notify_cooldown_secs: u64,
+    show_first_proof: bool,
 ) -> Result<(), Box<dyn Error>> {
+    if !(0.0..=100.0).contains(&ram_warn_pct)
+        || !(0.0..=100.0).contains(&ram_critical_pct)
+        || ram_warn_pct > ram_critical_pct
+    {
+        return Err(format!(
+            "Invalid RAM thresholds: expected 0 <= --ram-warn-pct ({}) <= --ram-critical-pct ({}) <= 100",
+            ram_warn_pct, ram_critical_pct
+        )
+        .into());
+    }
+    let ram_thresholds = RamThresholds {
+        warn_pct: ram_warn_pct,
+        critical_pct: ram_critical_pct,
+    };
+    let theme = Theme::resolve(theme, no_color_requested());
+    if !(metrics_smoothing > 0.0 && metrics_smoothing <= 1.0) {
+        return Err(format!(
+            "Invalid --metrics-smoothing {}: expected a weight above 0 and at most 1",
+            metrics_smoothing
+        )
+        .into());
+    }
+    let metrics_smoothing = (!raw_metrics).then_some(metrics_smoothing);
+    if !(regression_pct > 0.0 && regression_baseline_secs.is_none_or(|secs| secs > 0.0)) {
+        return Err(format!(
+            "Invalid regression alert: expected --regression-pct ({}) and --regression-baseline above 0",
+            regression_pct
+        )
+        .into());
+    }
+    let regression = RegressionConfig {
+        threshold_pct: regression_pct,
+        baseline_secs: regression_baseline_secs,
+    };
+
+    // Replays need no network, config or worker: just feed the recorded events through
+    if let Some(path) = replay {
+        let session = setup_replay(&path, replay_speed, env, node_id.unwrap_or(0), headless)?;
+        let ui_config = UIConfig::new(with_background, session.num_workers, false, None)
+            .with_points_per_task(points_per_task)
+            .with_fetch_timeout(fetch_timeout_secs)
+            .with_stuck_threshold(stuck_threshold_secs)
+            .with_regression(regression)
+            .with_state_dump(dump_state, redact_node_ids)
+            .with_stale_threshold(stale_threshold_secs)
+            .with_refresh_interval(refresh_ms)
+            .with_ram_thresholds(ram_thresholds)
+            .with_cpu_temp_warning(cpu_temp_warn)
+            .with_theme(theme)
+            .with_metrics_smoothing(metrics_smoothing)
+            .with_activity_log_capacity(event_history)
+            .with_daily_target(daily_target);
+        return if headless {
+            run_headless_mode(session, ui_config, json_metrics_interval).await
+        } else {
+            run_tui_mode(session, ui_config).await
+        };
+    }
+
     // 1. Version checking (will internally perform country detection without race)
     validate_version_requirements().await?;
 
@@ -241,6 +671,38 @@ async fn start(
         None
     };
 
+    // Bind the metrics endpoint before any work starts, so a bad address fails fast
+    let metrics_publisher = match metrics_addr {
+        Some(addr) => {
+            let server = MetricsServer::bind(addr)
+                .await
+                .map_err(|e| format!("Failed to serve metrics on {}: {}", addr, e))?;
+            let (publisher, snapshot) = watch::channel(MetricsSnapshot::default());
+            tokio::spawn(server.run(snapshot));
+            Some(publisher)
+        }
+        None => None,
+    };
+
+    let snapshot_publisher = match status_addr {
+        Some(addr) => {
+            let server = StatusServer::bind(addr)
+                .await
+                .map_err(|e| format!("Failed to serve status on {}: {}", addr, e))?;
+            let (publisher, snapshot) = watch::channel(DashboardSnapshot::default());
+            tokio::spawn(server.run(snapshot));
+            Some(publisher)
+        }
+        None => None,
+    };
+
+    let webhook = match webhook_url {
+        Some(url) => {
+            Some(Webhook::spawn(url).map_err(|e| format!("Failed to set up the webhook: {}", e))?)
+        }
+        None => None,
+    };
+
     let session = setup_session(
         config,
         env,
@@ -248,14 +710,56 @@ async fn start(
         max_threads,
         max_tasks,
         max_difficulty_parsed,
+        schedule,
     )
     .await?;
 
+    // Lifetime statistics live next to the config; without a home directory they aren't kept
+    let stats_path = get_stats_path().ok();
+    if reset_stats {
+        if let Some(path) = &stats_path {
+            LifetimeStats::clear(path)?;
+        }
+    }
+
+    if notify_after.is_some() && !cfg!(feature = "notify") {
+        eprintln!("Warning: --notify-after has no effect; this build lacks the `notify` feature");
+    }
+    let failure_notifier = notify_after.map(|threshold| {
+        FailureNotifier::new(threshold, Duration::from_secs(notify_cooldown_secs))
+    });
+
+    let ui_config = UIConfig::new(with_background, session.num_workers, false, None)
+        .with_schedule(session.schedule.clone())
+        .with_first_proof_banner(show_first_proof)
+        .with_points_per_task(points_per_task)
+        .with_fetch_timeout(fetch_timeout_secs)
+        .with_stuck_threshold(stuck_threshold_secs)
+        .with_regression(regression)
+        .with_state_dump(dump_state, redact_node_ids)
+        .with_stale_threshold(stale_threshold_secs)
+        .with_refresh_interval(refresh_ms)
+        .with_ram_thresholds(ram_thresholds)
+        .with_cpu_temp_warning(cpu_temp_warn)
+        .with_theme(theme)
+        .with_metrics_smoothing(metrics_smoothing)
+        .with_activity_log_capacity(event_history)
+        .with_daily_target(daily_target)
+        .with_metrics_publisher(metrics_publisher)
+        .with_snapshot_publisher(snapshot_publisher)
+        .with_stats_path(stats_path)
+        .with_failure_notifier(failure_notifier)
+        .with_webhook(webhook)
+        .with_event_log(log_file.map(|path| EventLogConfig::new(path, log_format)))
+        .with_history_db(history_db)
+        .with_task_csv(csv)
+        .with_syslog(syslog);
+
     // 4. Run appropriate mode
     if headless {
-        run_headless_mode(session).await
+        run_headless_mode(session, ui_config, json_metrics_interval).await
     } else {
-        run_tui_mode(session, with_background).await
+        run_tui_mode(session, ui_config).await
     }
 }
 
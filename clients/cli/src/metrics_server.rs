@@ -0,0 +1,254 @@
+//! Prometheus metrics endpoint
+//!
+//! Serves the latest dashboard metrics snapshot in the Prometheus text
+//! exposition format at `GET /metrics`.
+
+use crate::consts::cli_consts::{ACCEPT_RETRY_DELAY_MS, METRICS_REQUEST_TIMEOUT_SECS};
+use crate::ui::metrics::MetricsSnapshot;
+use std::fmt::Write;
+use std::io;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+
+/// Largest request head we are willing to read
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+/// Minimal HTTP server exposing `/metrics`
+pub struct MetricsServer {
+    listener: TcpListener,
+}
+
+impl MetricsServer {
+    /// Bind the endpoint, so address errors surface before proving starts
+    pub async fn bind(addr: SocketAddr) -> io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(addr).await?,
+        })
+    }
+
+    /// Address the server is listening on
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Serve scrapes until the task is dropped. Each request reads the latest
+    /// published snapshot, so scrapes never wait on the dashboard update loop.
+    pub async fn run(self, snapshot: watch::Receiver<MetricsSnapshot>) {
+        loop {
+            let stream = match self.listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(_) => {
+                    // Errors such as running out of file descriptors persist
+                    // for a while; retrying straight away would spin
+                    tokio::time::sleep(Duration::from_millis(ACCEPT_RETRY_DELAY_MS)).await;
+                    continue;
+                }
+            };
+            let snapshot = snapshot.clone();
+            tokio::spawn(async move {
+                let _ = handle_connection(stream, snapshot).await;
+            });
+        }
+    }
+}
+
+/// Answer a single HTTP request and close the connection
+async fn handle_connection(
+    mut stream: TcpStream,
+    snapshot: watch::Receiver<MetricsSnapshot>,
+) -> io::Result<()> {
+    // A client that connects and never finishes its request would otherwise
+    // hold the connection and its task open forever
+    let read_request = async {
+        let mut request = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") && request.len() < MAX_REQUEST_BYTES {
+            let n = stream.read(&mut buf).await?;
+            if n == 0 {
+                break;
+            }
+            request.extend_from_slice(&buf[..n]);
+        }
+        Ok::<_, io::Error>(request)
+    };
+    let request = tokio::time::timeout(
+        Duration::from_secs(METRICS_REQUEST_TIMEOUT_SECS),
+        read_request,
+    )
+    .await
+    .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "no request"))??;
+
+    let request = String::from_utf8_lossy(&request);
+    let mut request_line = request
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace();
+    let method = request_line.next().unwrap_or_default();
+    let path = request_line.next().unwrap_or_default();
+
+    let (status, body) = match (method, path) {
+        ("GET", "/metrics") => ("200 OK", render_prometheus(&snapshot.borrow())),
+        ("GET", _) => ("404 Not Found", "Not Found\n".to_string()),
+        _ => ("405 Method Not Allowed", "Method Not Allowed\n".to_string()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Render a snapshot in the Prometheus text exposition format
+pub fn render_prometheus(snapshot: &MetricsSnapshot) -> String {
+    let zkvm = &snapshot.zkvm;
+    let system = &snapshot.system;
+
+    let metrics: [(&str, &str, &str, f64); 10] = [
+        (
+            "nexus_tasks_fetched_total",
+            "counter",
+            "Tasks obtained from the orchestrator.",
+            zkvm.tasks_fetched as f64,
+        ),
+        (
+            "nexus_tasks_proved_total",
+            "counter",
+            "Tasks a proof was generated for.",
+            zkvm.tasks_proved as f64,
+        ),
+        (
+            "nexus_tasks_submitted_total",
+            "counter",
+            "Proofs accepted by the orchestrator.",
+            zkvm.tasks_submitted as f64,
+        ),
+        (
+            "nexus_proof_failures_total",
+            "counter",
+            "Proof generation failures.",
+            zkvm.proof_failures as f64,
+        ),
+        (
+            "nexus_submit_failures_total",
+            "counter",
+            "Proof submission failures.",
+            zkvm.submit_failures as f64,
+        ),
+        (
+            "nexus_dropped_tasks_total",
+            "counter",
+            "Tasks that went missing between fetch and submission.",
+            zkvm.dropped_tasks as f64,
+        ),
+        (
+            "nexus_cpu_usage_percent",
+            "gauge",
+            "CPU usage of the prover process.",
+            system.cpu_percent as f64,
+        ),
+        (
+            "nexus_ram_bytes",
+            "gauge",
+            "Current RAM usage of the prover process.",
+            system.ram_bytes as f64,
+        ),
+        (
+            "nexus_peak_ram_bytes",
+            "gauge",
+            "Peak RAM usage of the prover process.",
+            system.peak_ram_bytes as f64,
+        ),
+        (
+            "nexus_zkvm_runtime_seconds",
+            "gauge",
+            "Total time spent proving.",
+            zkvm.zkvm_runtime_secs as f64,
+        ),
+    ];
+
+    let mut out = String::new();
+    for (name, kind, help, value) in metrics {
+        let _ = writeln!(out, "# HELP {} {}", name, help);
+        let _ = writeln!(out, "# TYPE {} {}", name, kind);
+        let _ = writeln!(out, "{} {}", name, value);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn parse_samples(body: &str) -> HashMap<String, f64> {
+        body.lines()
+            .filter(|line| !line.starts_with('#') && !line.is_empty())
+            .filter_map(|line| {
+                let (name, value) = line.split_once(' ')?;
+                Some((name.to_string(), value.parse().ok()?))
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_metrics_endpoint_serves_latest_snapshot() {
+        let server = MetricsServer::bind("127.0.0.1:0".parse().unwrap())
+            .await
+            .unwrap();
+        let addr = server.local_addr().unwrap();
+        let (sender, receiver) = watch::channel(MetricsSnapshot::default());
+        tokio::spawn(server.run(receiver));
+
+        let mut snapshot = MetricsSnapshot::default();
+        snapshot.zkvm.tasks_fetched = 4;
+        snapshot.zkvm.tasks_proved = 3;
+        snapshot.zkvm.tasks_submitted = 2;
+        snapshot.system.ram_bytes = 1024;
+        sender.send_replace(snapshot);
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        assert!(head.starts_with("HTTP/1.1 200 OK"));
+        assert!(body.contains("# TYPE nexus_tasks_fetched_total counter"));
+        assert!(body.contains("# TYPE nexus_ram_bytes gauge"));
+
+        let samples = parse_samples(body);
+        assert_eq!(samples["nexus_tasks_fetched_total"], 4.0);
+        assert_eq!(samples["nexus_tasks_proved_total"], 3.0);
+        assert_eq!(samples["nexus_tasks_submitted_total"], 2.0);
+        assert_eq!(samples["nexus_ram_bytes"], 1024.0);
+        assert_eq!(samples["nexus_zkvm_runtime_seconds"], 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_path_is_not_found() {
+        let server = MetricsServer::bind("127.0.0.1:0".parse().unwrap())
+            .await
+            .unwrap();
+        let addr = server.local_addr().unwrap();
+        let (_sender, receiver) = watch::channel(MetricsSnapshot::default());
+        tokio::spawn(server.run(receiver));
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(b"GET / HTTP/1.1\r\n\r\n").await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 404 Not Found"));
+    }
+}
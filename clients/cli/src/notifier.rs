@@ -0,0 +1,163 @@
+//! Failure notifications
+//!
+//! Raises a desktop notification when provers or the proof submitter keep
+//! failing, so an unattended node doesn't fail silently for hours. Delivery
+//! uses `notify-rust` when built with the `notify` feature and is best effort:
+//! a missing notification backend is ignored.
+
+use crate::consts::cli_consts::FAILURE_NOTIFY_WINDOW_SECS;
+use crate::events::{Event as WorkerEvent, EventType, Worker};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Tracks consecutive proving and submission failures
+#[derive(Debug, Clone)]
+pub struct FailureNotifier {
+    /// Consecutive failures needed to notify
+    threshold: usize,
+    /// Failures older than this no longer count towards the threshold
+    window: Duration,
+    /// Minimum time between notifications
+    cooldown: Duration,
+    /// Times of the current run of failures, oldest first
+    failures: VecDeque<Instant>,
+    /// When the last notification was raised
+    last_notified: Option<Instant>,
+}
+
+impl FailureNotifier {
+    /// Notify after `threshold` consecutive failures, at most once per `cooldown`.
+    pub fn new(threshold: usize, cooldown: Duration) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            window: Duration::from_secs(FAILURE_NOTIFY_WINDOW_SECS),
+            cooldown,
+            failures: VecDeque::new(),
+            last_notified: None,
+        }
+    }
+
+    /// Record an event, raising a notification if it completes a run of failures.
+    pub fn record(&mut self, event: &WorkerEvent, now: Instant) {
+        if let Some(message) = self.check(event, now) {
+            send_notification(message);
+        }
+    }
+
+    /// Record an event, returning the notification text if one is due.
+    pub fn check(&mut self, event: &WorkerEvent, now: Instant) -> Option<String> {
+        if !matches!(event.worker, Worker::Prover(_) | Worker::ProofSubmitter) {
+            return None;
+        }
+        match event.event_type {
+            EventType::Error => {}
+            // Any success breaks the run
+            EventType::Success => {
+                self.failures.clear();
+                return None;
+            }
+            _ => return None,
+        }
+
+        self.failures.push_back(now);
+        while self
+            .failures
+            .front()
+            .is_some_and(|&at| now.duration_since(at) > self.window)
+        {
+            self.failures.pop_front();
+        }
+        if self.failures.len() < self.threshold {
+            return None;
+        }
+        if self
+            .last_notified
+            .is_some_and(|at| now.duration_since(at) < self.cooldown)
+        {
+            return None;
+        }
+
+        let count = self.failures.len();
+        self.failures.clear();
+        self.last_notified = Some(now);
+        Some(format!(
+            "{} failures in a row. Latest: {}",
+            count, event.msg
+        ))
+    }
+}
+
+/// Show a desktop notification without blocking the caller.
+#[cfg(feature = "notify")]
+fn send_notification(message: String) {
+    // Delivery can block on the notification daemon, so it gets its own thread
+    std::thread::spawn(move || {
+        let _ = notify_rust::Notification::new()
+            .appname("nexus-network")
+            .summary("Nexus prover is failing")
+            .body(&message)
+            .show();
+    });
+}
+
+/// Show a desktop notification without blocking the caller.
+#[cfg(not(feature = "notify"))]
+fn send_notification(_message: String) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logging::LogLevel;
+
+    fn prover_error() -> WorkerEvent {
+        WorkerEvent::prover_with_level(0, "boom".to_string(), EventType::Error, LogLevel::Error)
+    }
+
+    fn submit_success() -> WorkerEvent {
+        WorkerEvent::proof_submitter_with_level(
+            "submitted".to_string(),
+            EventType::Success,
+            LogLevel::Info,
+        )
+    }
+
+    #[test]
+    fn test_notifies_after_consecutive_failures_with_cooldown() {
+        let start = Instant::now();
+        let mut notifier = FailureNotifier::new(3, Duration::from_secs(600));
+
+        assert!(notifier.check(&prover_error(), start).is_none());
+        assert!(notifier.check(&prover_error(), start).is_none());
+        let message = notifier.check(&prover_error(), start).unwrap();
+        assert_eq!(message, "3 failures in a row. Latest: boom");
+
+        // Still failing, but within the cooldown
+        for _ in 0..3 {
+            assert!(notifier.check(&prover_error(), start).is_none());
+        }
+        let later = start + Duration::from_secs(601);
+        assert!(notifier.check(&prover_error(), later).is_some());
+    }
+
+    #[test]
+    fn test_success_or_stale_failures_break_the_run() {
+        let start = Instant::now();
+        let mut notifier = FailureNotifier::new(2, Duration::ZERO);
+
+        notifier.check(&prover_error(), start);
+        notifier.check(&submit_success(), start);
+        assert!(notifier.check(&prover_error(), start).is_none());
+
+        let stale = start + Duration::from_secs(FAILURE_NOTIFY_WINDOW_SECS + 1);
+        assert!(notifier.check(&prover_error(), stale).is_none());
+
+        // Fetcher errors don't count
+        let fetch_error = WorkerEvent::task_fetcher_with_level(
+            "offline".to_string(),
+            EventType::Error,
+            LogLevel::Error,
+        );
+        assert!(notifier.check(&fetch_error, stale).is_none());
+        assert!(notifier.check(&prover_error(), stale).is_some());
+    }
+}
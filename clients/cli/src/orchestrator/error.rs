@@ -3,6 +3,7 @@
 use prost::DecodeError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
 use thiserror::Error;
 
 #[allow(non_snake_case)] // used for json parsing
@@ -67,12 +68,24 @@ impl OrchestratorError {
                 }
                 headers
                     .get("retry-after")
-                    .and_then(|value| value.parse::<u32>().ok())
+                    .and_then(|value| parse_backoff(value))
+                    .map(|delay| delay.as_secs_f64().ceil() as u32)
             }
             _ => None,
         }
     }
 
+    /// The Retry-After header value, if one was sent but couldn't be parsed
+    pub fn unparsed_retry_after(&self) -> Option<&str> {
+        match self {
+            Self::Http { headers, .. } => headers
+                .get("retry-after")
+                .map(String::as_str)
+                .filter(|value| parse_backoff(value).is_none()),
+            _ => None,
+        }
+    }
+
     pub fn to_pretty(&self) -> Option<String> {
         match self {
             Self::Http {
@@ -93,6 +106,26 @@ impl OrchestratorError {
     }
 }
 
+/// Parse a backoff duration: whole or fractional seconds ("90", "90.0"), or
+/// minutes and seconds ("1m30s", "1m 30s", "2m", "45s").
+pub fn parse_backoff(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<f64>() {
+        return Duration::try_from_secs_f64(secs).ok();
+    }
+
+    let (minutes, rest) = match value.split_once('m') {
+        Some((minutes, rest)) => (minutes.trim().parse::<u64>().ok()?, rest.trim()),
+        None => (0, value),
+    };
+    let seconds = match rest.strip_suffix('s') {
+        Some(seconds) => seconds.trim().parse::<u64>().ok()?,
+        None if rest.is_empty() && value.contains('m') => 0,
+        None => return None,
+    };
+    Some(Duration::from_secs(minutes * 60 + seconds))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,5 +167,55 @@ mod tests {
         };
 
         assert_eq!(error.get_retry_after_seconds(), None);
+        assert_eq!(error.unparsed_retry_after(), Some("invalid"));
+    }
+
+    #[test]
+    fn test_get_retry_after_seconds_rounds_fractions_up() {
+        let mut headers = HashMap::new();
+        headers.insert("retry-after".to_string(), "1m30s".to_string());
+        let error = OrchestratorError::Http {
+            status: 429,
+            message: "Rate limited".to_string(),
+            headers: headers.clone(),
+        };
+        assert_eq!(error.get_retry_after_seconds(), Some(90));
+        assert_eq!(error.unparsed_retry_after(), None);
+
+        headers.insert("retry-after".to_string(), "90.2".to_string());
+        let error = OrchestratorError::Http {
+            status: 429,
+            message: "Rate limited".to_string(),
+            headers,
+        };
+        assert_eq!(error.get_retry_after_seconds(), Some(91));
+    }
+
+    #[test]
+    fn test_parse_backoff_integer_seconds() {
+        assert_eq!(parse_backoff("120"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_backoff(" 0 "), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_parse_backoff_fractional_seconds() {
+        assert_eq!(parse_backoff("90.0"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_backoff("1.5"), Some(Duration::from_millis(1500)));
+        assert_eq!(parse_backoff("-1.0"), None);
+    }
+
+    #[test]
+    fn test_parse_backoff_minutes_and_seconds() {
+        assert_eq!(parse_backoff("1m30s"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_backoff("1m 30s"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_backoff("2m"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_backoff("45s"), Some(Duration::from_secs(45)));
+    }
+
+    #[test]
+    fn test_parse_backoff_rejects_anything_else() {
+        for value in ["", "soon", "1h", "m", "1m30", "30 seconds", "1.5m"] {
+            assert_eq!(parse_backoff(value), None, "{:?}", value);
+        }
     }
 }
@@ -3,15 +3,20 @@
 use crate::environment::Environment;
 use crate::events::Event;
 use crate::orchestrator::OrchestratorClient;
+use crate::schedule::Schedule;
 use crate::workers::authenticated_worker::AuthenticatedWorker;
 use crate::workers::core::WorkerConfig;
 use ed25519_dalek::SigningKey;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU32;
 use tokio::sync::{broadcast, mpsc};
 use tokio::task::JoinHandle;
 
-/// Start single authenticated worker
+/// Start one authenticated worker per prover, numbered from 0, feeding one
+/// event channel
 #[allow(clippy::too_many_arguments)]
-pub async fn start_authenticated_worker(
+pub async fn start_authenticated_workers(
+    num_workers: usize,
     node_id: u64,
     signing_key: SigningKey,
     orchestrator: OrchestratorClient,
@@ -20,6 +25,7 @@ pub async fn start_authenticated_worker(
     client_id: String,
     max_tasks: Option<u32>,
     max_difficulty: Option<crate::nexus_orchestrator::TaskDifficulty>,
+    schedule: Option<Schedule>,
 ) -> (
     mpsc::Receiver<Event>,
     Vec<JoinHandle<()>>,
@@ -27,22 +33,30 @@ pub async fn start_authenticated_worker(
 ) {
     let mut config = WorkerConfig::new(environment, client_id);
     config.max_difficulty = max_difficulty;
+    config.schedule = schedule;
     let (event_sender, event_receiver) =
         mpsc::channel::<Event>(crate::consts::cli_consts::EVENT_QUEUE_SIZE);
 
     // Create a separate shutdown sender for max tasks completion
     let (shutdown_sender, _) = broadcast::channel(1);
 
-    let worker = AuthenticatedWorker::new(
-        node_id,
-        signing_key,
-        orchestrator,
-        config,
-        event_sender,
-        max_tasks,
-        shutdown_sender.clone(),
-    );
+    // Shared so that max tasks counts every worker's submissions
+    let tasks_completed = Arc::new(AtomicU32::new(0));
 
-    let join_handles = worker.run(shutdown).await;
+    let mut join_handles = Vec::new();
+    for index in 0..num_workers {
+        let worker = AuthenticatedWorker::new(
+            index,
+            node_id,
+            signing_key.clone(),
+            orchestrator.clone(),
+            config.clone(),
+            event_sender.clone(),
+            max_tasks,
+            tasks_completed.clone(),
+            shutdown_sender.clone(),
+        );
+        join_handles.extend(worker.run(shutdown.resubscribe()).await);
+    }
     (event_receiver, join_handles, shutdown_sender)
 }
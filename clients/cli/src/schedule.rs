@@ -0,0 +1,295 @@
+//! Proving schedule
+//!
+//! A schedule is a list of daily time windows, each mapped to a setting that
+//! controls how much proving happens during that window, e.g.
+//! `22:00-07:00=full,07:00-18:00=1,18:00-22:00=paused`.
+//!
+//! Precedence: when windows overlap, the most restrictive setting wins
+//! (`paused` beats any worker count, and a lower count beats a higher one), so
+//! entry order never matters. Times outside every window run unrestricted.
+
+use chrono::{Local, Timelike};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+const MINUTES_PER_DAY: u16 = 24 * 60;
+
+/// What the schedule allows during a window
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ScheduleSetting {
+    /// Don't fetch new tasks (an in-flight task is allowed to finish)
+    Paused,
+    /// Run at most this many workers
+    Concurrency(usize),
+    /// Run all configured workers
+    Full,
+}
+
+impl ScheduleSetting {
+    /// Maximum number of workers allowed by this setting
+    pub fn worker_cap(self) -> usize {
+        match self {
+            ScheduleSetting::Paused => 0,
+            ScheduleSetting::Concurrency(n) => n,
+            ScheduleSetting::Full => usize::MAX,
+        }
+    }
+
+    /// Whether proving is paused entirely
+    pub fn is_paused(self) -> bool {
+        self.worker_cap() == 0
+    }
+
+    /// Whether the worker with this index (counting from 0) may take new tasks
+    pub fn allows_worker(self, index: usize) -> bool {
+        index < self.worker_cap()
+    }
+}
+
+impl Display for ScheduleSetting {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduleSetting::Paused | ScheduleSetting::Concurrency(0) => write!(f, "paused"),
+            ScheduleSetting::Concurrency(1) => write!(f, "1 worker"),
+            ScheduleSetting::Concurrency(n) => write!(f, "{} workers", n),
+            ScheduleSetting::Full => write!(f, "full"),
+        }
+    }
+}
+
+/// A daily time window, in minutes since local midnight
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ScheduleEntry {
+    /// Start of the window (inclusive)
+    pub start: u16,
+    /// End of the window (exclusive); may be earlier than `start` to wrap past midnight
+    pub end: u16,
+    /// Setting applied inside the window
+    pub setting: ScheduleSetting,
+}
+
+impl ScheduleEntry {
+    /// Whether the window covers the given minute of the day.
+    /// A window whose start equals its end covers the whole day.
+    fn contains(&self, minute: u16) -> bool {
+        if self.start < self.end {
+            self.start <= minute && minute < self.end
+        } else {
+            minute >= self.start || minute < self.end
+        }
+    }
+}
+
+/// A set of daily windows controlling proving over time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    entries: Vec<ScheduleEntry>,
+}
+
+impl Schedule {
+    /// Setting in effect at the given minute of the day, if any window covers it
+    pub fn setting_at(&self, minute: u16) -> Option<ScheduleSetting> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.contains(minute))
+            .map(|entry| entry.setting)
+            .min_by_key(|setting| setting.worker_cap())
+    }
+
+    /// Next minute of the day at which the effective setting changes, with the new setting
+    pub fn next_change_at(&self, minute: u16) -> Option<(u16, Option<ScheduleSetting>)> {
+        let current = self.setting_at(minute);
+        (1..MINUTES_PER_DAY)
+            .map(|offset| (minute + offset) % MINUTES_PER_DAY)
+            .map(|candidate| (candidate, self.setting_at(candidate)))
+            .find(|(_, setting)| *setting != current)
+    }
+
+    /// Setting in effect right now, by local time
+    pub fn current_setting(&self) -> Option<ScheduleSetting> {
+        self.setting_at(local_minute_of_day())
+    }
+
+    /// Next change from now, by local time
+    pub fn next_change(&self) -> Option<(u16, Option<ScheduleSetting>)> {
+        self.next_change_at(local_minute_of_day())
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = String;
+
+    /// Parse a comma-separated list of `HH:MM-HH:MM=SETTING` entries, where
+    /// SETTING is `paused`, `full`, or a worker count (0 means paused).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries = s
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(parse_entry)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if entries.is_empty() {
+            return Err("schedule must contain at least one HH:MM-HH:MM=SETTING entry".to_string());
+        }
+        Ok(Self { entries })
+    }
+}
+
+/// Format a minute of the day as `HH:MM`
+pub fn format_minute_of_day(minute: u16) -> String {
+    format!("{:02}:{:02}", minute / 60, minute % 60)
+}
+
+fn local_minute_of_day() -> u16 {
+    let now = Local::now();
+    (now.hour() * 60 + now.minute()) as u16
+}
+
+fn parse_entry(entry: &str) -> Result<ScheduleEntry, String> {
+    let (window, setting) = entry
+        .split_once('=')
+        .ok_or_else(|| format!("'{}': expected HH:MM-HH:MM=SETTING", entry))?;
+    let (start, end) = window
+        .split_once('-')
+        .ok_or_else(|| format!("'{}': expected a HH:MM-HH:MM window", entry))?;
+
+    Ok(ScheduleEntry {
+        start: parse_time(start.trim())?,
+        end: parse_time(end.trim())?,
+        setting: parse_setting(setting.trim())?,
+    })
+}
+
+fn parse_time(time: &str) -> Result<u16, String> {
+    let invalid = || format!("'{}': expected a HH:MM time", time);
+    let (hours, minutes) = time.split_once(':').ok_or_else(invalid)?;
+    let hours: u16 = hours.parse().map_err(|_| invalid())?;
+    let minutes: u16 = minutes.parse().map_err(|_| invalid())?;
+    if hours >= 24 || minutes >= 60 {
+        return Err(invalid());
+    }
+    Ok(hours * 60 + minutes)
+}
+
+fn parse_setting(setting: &str) -> Result<ScheduleSetting, String> {
+    match setting.to_ascii_lowercase().as_str() {
+        "paused" | "pause" | "off" => Ok(ScheduleSetting::Paused),
+        "full" | "on" => Ok(ScheduleSetting::Full),
+        count => match count.parse::<usize>() {
+            Ok(0) => Ok(ScheduleSetting::Paused),
+            Ok(n) => Ok(ScheduleSetting::Concurrency(n)),
+            Err(_) => Err(format!(
+                "'{}': expected 'paused', 'full', or a worker count",
+                setting
+            )),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minute(time: &str) -> u16 {
+        parse_time(time).unwrap()
+    }
+
+    #[test]
+    fn test_parse_schedule() {
+        let schedule: Schedule = "22:00-07:00=full, 07:00-18:00=2,18:00-22:00=Paused"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            schedule.setting_at(minute("23:30")),
+            Some(ScheduleSetting::Full)
+        );
+        assert_eq!(
+            schedule.setting_at(minute("03:00")),
+            Some(ScheduleSetting::Full)
+        );
+        assert_eq!(
+            schedule.setting_at(minute("07:00")),
+            Some(ScheduleSetting::Concurrency(2))
+        );
+        assert_eq!(
+            schedule.setting_at(minute("21:59")),
+            Some(ScheduleSetting::Paused)
+        );
+    }
+
+    #[test]
+    fn test_parse_schedule_rejects_invalid_entries() {
+        assert!("".parse::<Schedule>().is_err());
+        assert!("22:00=full".parse::<Schedule>().is_err());
+        assert!("22:00-07:00".parse::<Schedule>().is_err());
+        assert!("24:00-07:00=full".parse::<Schedule>().is_err());
+        assert!("22:00-07:60=full".parse::<Schedule>().is_err());
+        assert!("22:00-07:00=fast".parse::<Schedule>().is_err());
+    }
+
+    #[test]
+    fn test_zero_workers_means_paused() {
+        let schedule: Schedule = "09:00-17:00=0".parse().unwrap();
+        assert_eq!(
+            schedule.setting_at(minute("12:00")),
+            Some(ScheduleSetting::Paused)
+        );
+    }
+
+    #[test]
+    fn test_worker_count_caps_provers_by_index() {
+        let capped = ScheduleSetting::Concurrency(2);
+        assert!(capped.allows_worker(0));
+        assert!(capped.allows_worker(1));
+        assert!(!capped.allows_worker(2));
+        assert!(!ScheduleSetting::Paused.allows_worker(0));
+        assert!(ScheduleSetting::Full.allows_worker(7));
+    }
+
+    #[test]
+    fn test_overlap_most_restrictive_wins() {
+        let schedule: Schedule = "08:00-20:00=4,12:00-13:00=paused,10:00-16:00=2"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            schedule.setting_at(minute("09:00")),
+            Some(ScheduleSetting::Concurrency(4))
+        );
+        assert_eq!(
+            schedule.setting_at(minute("11:00")),
+            Some(ScheduleSetting::Concurrency(2))
+        );
+        assert_eq!(
+            schedule.setting_at(minute("12:30")),
+            Some(ScheduleSetting::Paused)
+        );
+    }
+
+    #[test]
+    fn test_uncovered_time_has_no_setting() {
+        let schedule: Schedule = "09:00-17:00=paused".parse().unwrap();
+        assert_eq!(schedule.setting_at(minute("17:00")), None);
+        assert_eq!(schedule.setting_at(minute("08:59")), None);
+    }
+
+    #[test]
+    fn test_next_change() {
+        let schedule: Schedule = "09:00-17:00=paused".parse().unwrap();
+
+        assert_eq!(
+            schedule.next_change_at(minute("08:00")),
+            Some((minute("09:00"), Some(ScheduleSetting::Paused)))
+        );
+        assert_eq!(
+            schedule.next_change_at(minute("10:00")),
+            Some((minute("17:00"), None))
+        );
+
+        // A whole-day window never changes
+        let constant: Schedule = "00:00-00:00=2".parse().unwrap();
+        assert_eq!(constant.next_change_at(minute("10:00")), None);
+    }
+}
@@ -0,0 +1,88 @@
+//! Headless stdin commands
+//!
+//! In headless mode a supervisor can drive the running node by writing one
+//! command per line to its stdin. Lines are read on a separate thread so a
+//! quiet (or closed) stdin never holds up the event loop.
+
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+use tokio::sync::mpsc;
+
+/// A command accepted on stdin in headless mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeadlessCommand {
+    /// Print the dashboard snapshot
+    Snapshot,
+    /// Zero the lifetime statistics
+    ResetStats,
+    /// Shut down as if Ctrl+C had been pressed
+    Quit,
+}
+
+/// A line that isn't a known command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownCommand(pub String);
+
+impl fmt::Display for UnknownCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unknown command '{}' (expected snapshot, reset-stats or quit)",
+            self.0
+        )
+    }
+}
+
+impl FromStr for HeadlessCommand {
+    type Err = UnknownCommand;
+
+    /// Surrounding whitespace and case are ignored, and `_` may stand in for `-`
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let command = line.trim();
+        match command.to_ascii_lowercase().replace('_', "-").as_str() {
+            "snapshot" => Ok(Self::Snapshot),
+            "reset-stats" => Ok(Self::ResetStats),
+            "quit" | "exit" => Ok(Self::Quit),
+            _ => Err(UnknownCommand(command.to_string())),
+        }
+    }
+}
+
+/// Read commands from stdin in the background. Blank lines are skipped; the
+/// channel closes when stdin does.
+///
+/// This is a plain thread rather than a tokio task: a blocking stdin read on
+/// the runtime would keep the process from exiting until the next line.
+pub fn spawn_stdin_reader() -> mpsc::Receiver<Result<HeadlessCommand, UnknownCommand>> {
+    let (sender, receiver) = mpsc::channel(8);
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if line.trim().is_empty() {
+                continue;
+            }
+            if sender.blocking_send(line.parse()).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commands_are_parsed_leniently() {
+        assert_eq!("snapshot".parse(), Ok(HeadlessCommand::Snapshot));
+        assert_eq!("  Reset-Stats \r".parse(), Ok(HeadlessCommand::ResetStats));
+        assert_eq!("reset_stats".parse(), Ok(HeadlessCommand::ResetStats));
+        assert_eq!("QUIT\n".parse(), Ok(HeadlessCommand::Quit));
+
+        let err = " status ".parse::<HeadlessCommand>().unwrap_err();
+        assert_eq!(err, UnknownCommand("status".to_string()));
+        assert!(err.to_string().contains("expected snapshot"));
+    }
+}
@@ -2,28 +2,88 @@
 
 use super::{
     SessionData,
-    messages::{print_session_exit_success, print_session_shutdown, print_session_starting},
+    commands::{HeadlessCommand, spawn_stdin_reader},
+    messages::{
+        print_session_exit_success, print_session_shutdown, print_session_starting,
+        print_state_dump,
+    },
+    summary::SessionSummary,
 };
-use crate::print_cmd_info;
+use crate::consts::cli_consts::JSON_METRICS_INTERVAL_SECS;
+use crate::events::{Event, ProofPhase};
+use crate::ui::UIConfig;
+use crate::ui::dashboard::DashboardState;
+use crate::ui::dashboard::snapshot::DashboardSnapshot;
+use crate::ui::metrics::{SystemMetrics, ZkVMMetrics};
 use crate::version::checker::check_for_new_version;
+use crate::{print_cmd_error, print_cmd_info};
+use serde::Serialize;
 use std::error::Error;
+use std::time::{Duration, Instant};
+
+/// One line of `--json` output
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum JsonRecord<'a> {
+    /// A worker event
+    Event(&'a Event),
+    /// Periodic snapshot of the zkVM and system metrics
+    Metrics {
+        zkvm: &'a ZkVMMetrics,
+        system: &'a SystemMetrics,
+    },
+    /// Totals for the session, written on exit
+    Summary {
+        uptime_secs: u64,
+        zkvm: &'a ZkVMMetrics,
+        avg_proving_secs: Option<f64>,
+        peak_ram_bytes: u64,
+    },
+    /// Informational message that isn't a worker event
+    Notice { title: &'a str, msg: &'a str },
+    /// The dashboard snapshot, in reply to the `snapshot` command
+    Snapshot(&'a DashboardSnapshot),
+    /// A stdin command that was not understood or failed
+    CommandError { msg: &'a str },
+}
+
+impl JsonRecord<'_> {
+    /// Write the record to stdout as a single line
+    fn print(&self) {
+        match serde_json::to_string(self) {
+            Ok(line) => println!("{}", line),
+            Err(e) => eprintln!("Failed to serialize JSON record: {}", e),
+        }
+    }
+}
 
 /// Runs the application in headless mode
 ///
 /// This function handles:
-/// 1. Console event logging
+/// 1. Console event logging (plain text, or JSON lines)
 /// 2. Ctrl+C shutdown handling
-/// 3. Event loop management
+/// 3. Commands read from stdin (`snapshot`, `reset-stats`, `quit`)
+/// 4. Event loop management
 ///
 /// # Arguments
 /// * `session` - Session data from setup
+/// * `ui_config` - Metrics configuration (nothing is rendered)
+/// * `json_metrics_interval` - If set, emit JSON lines, with a metrics snapshot every this many seconds
 ///
 /// # Returns
 /// * `Ok(())` - Headless mode completed successfully
 /// * `Err` - Headless mode failed
-pub async fn run_headless_mode(mut session: SessionData) -> Result<(), Box<dyn Error>> {
+pub async fn run_headless_mode(
+    mut session: SessionData,
+    ui_config: UIConfig,
+    json_metrics_interval: Option<u64>,
+) -> Result<(), Box<dyn Error>> {
+    let json = json_metrics_interval.is_some();
+
     // Print session start message
-    print_session_starting("headless", session.node_id);
+    if !json {
+        print_session_starting("headless", session.node_id);
+    }
 
     // Check for new version and inform user
     let current_version = env!("CARGO_PKG_VERSION");
@@ -31,9 +91,30 @@ pub async fn run_headless_mode(mut session: SessionData) -> Result<(), Box<dyn E
     // First check constraint violations
     if let Some(message) = check_for_new_version(current_version).await {
         // If no constraints violated, check for newer versions available
-        print_cmd_info!("Version check", "{}", message);
+        if json {
+            JsonRecord::Notice {
+                title: "Version check",
+                msg: &message,
+            }
+            .print();
+        } else {
+            print_cmd_info!("Version check", "{}", message);
+        }
     }
 
+    // Metrics are tracked the same way as in the dashboard, just never rendered
+    let mut first_proof_pending = ui_config.show_first_proof;
+    let mut metrics_state = DashboardState::new(
+        Some(session.node_id),
+        session.orchestrator.environment().clone(),
+        Instant::now(),
+        ui_config,
+    );
+    let mut refresh_interval = tokio::time::interval(Duration::from_secs(1));
+    let mut json_interval = tokio::time::interval(Duration::from_secs(
+        json_metrics_interval.unwrap_or(JSON_METRICS_INTERVAL_SECS),
+    ));
+
     // Trigger shutdown on Ctrl+C
     let shutdown_sender_clone = session.shutdown_sender.clone();
     tokio::spawn(async move {
@@ -44,13 +125,88 @@ pub async fn run_headless_mode(mut session: SessionData) -> Result<(), Box<dyn E
 
     let mut shutdown_receiver = session.shutdown_sender.subscribe();
     let mut max_tasks_shutdown_receiver = session.max_tasks_shutdown_sender.subscribe();
+    let mut commands = spawn_stdin_reader();
 
     // Event loop: log events to console until shutdown
     loop {
         tokio::select! {
             Some(event) = session.event_receiver.recv() => {
-                println!("{}", event);
+                if json {
+                    JsonRecord::Event(&event).print();
+                } else {
+                    println!("{}", event);
+                }
+
+                if first_proof_pending && matches!(event.phase, Some(ProofPhase::Submitted { .. })) {
+                    first_proof_pending = false;
+                    if json {
+                        JsonRecord::Notice {
+                            title: "Your prover is working!",
+                            msg: "First proof submitted.",
+                        }
+                        .print();
+                    } else {
+                        print_cmd_info!("✓ Your prover is working!", "First proof submitted.");
+                    }
+                }
+
+                metrics_state.add_event(event);
+                metrics_state.process_pending_events();
+                metrics_state.publish_metrics();
+            }
+            _ = refresh_interval.tick() => {
+                metrics_state.update();
+                // Events the dashboard raised itself, e.g. webhook failures
+                for event in metrics_state.take_raised_events() {
+                    if json {
+                        JsonRecord::Event(&event).print();
+                    } else {
+                        println!("{}", event);
+                    }
+                }
             }
+            _ = json_interval.tick(), if json => {
+                JsonRecord::Metrics {
+                    zkvm: &metrics_state.zkvm_metrics,
+                    system: &metrics_state.system_metrics,
+                }
+                .print();
+            }
+            Some(command) = commands.recv() => match command {
+                Ok(HeadlessCommand::Snapshot) => {
+                    let snapshot = metrics_state.dashboard_snapshot(Instant::now());
+                    if json {
+                        JsonRecord::Snapshot(&snapshot).print();
+                    } else {
+                        match serde_json::to_string(&snapshot) {
+                            Ok(line) => println!("{}", line),
+                            Err(e) => print_cmd_error!("Snapshot failed", &e.to_string()),
+                        }
+                    }
+                }
+                Ok(HeadlessCommand::ResetStats) => match metrics_state.reset_stats() {
+                    Ok(()) if json => JsonRecord::Notice {
+                        title: "Stats reset",
+                        msg: "Lifetime statistics were reset.",
+                    }
+                    .print(),
+                    Ok(()) => print_cmd_info!("Stats reset", "Lifetime statistics were reset."),
+                    Err(e) if json => JsonRecord::CommandError {
+                        msg: &format!("Failed to reset stats: {}", e),
+                    }
+                    .print(),
+                    Err(e) => print_cmd_error!("Failed to reset stats", &e.to_string()),
+                },
+                // Same path as Ctrl+C, so the workers stop too
+                Ok(HeadlessCommand::Quit) => {
+                    let _ = session.shutdown_sender.send(());
+                }
+                Err(unknown) if json => JsonRecord::CommandError {
+                    msg: &unknown.to_string(),
+                }
+                .print(),
+                Err(unknown) => print_cmd_error!(&unknown.to_string()),
+            },
             _ = shutdown_receiver.recv() => {
                 break;
             }
@@ -60,12 +216,104 @@ pub async fn run_headless_mode(mut session: SessionData) -> Result<(), Box<dyn E
         }
     }
 
+    metrics_state.save_stats();
+    let summary = SessionSummary::from_state(&metrics_state);
+    if let Some((path, written)) = metrics_state.dump_state_on_exit() {
+        match written {
+            Ok(()) if json => JsonRecord::Notice {
+                title: "State dumped",
+                msg: &format!("State written to {}", path.display()),
+            }
+            .print(),
+            Err(e) if json => JsonRecord::CommandError {
+                msg: &format!("Failed to write state to {}: {}", path.display(), e),
+            }
+            .print(),
+            written => print_state_dump(&path, written),
+        }
+    }
+
     // Wait for workers to finish
-    print_session_shutdown();
+    if !json {
+        print_session_shutdown();
+    }
     for handle in session.join_handles {
         let _ = handle.await;
     }
-    print_session_exit_success();
+    if json {
+        JsonRecord::Summary {
+            uptime_secs: summary.uptime.as_secs(),
+            zkvm: &summary.zkvm,
+            avg_proving_secs: summary.zkvm.avg_proving_secs(),
+            peak_ram_bytes: summary.system.peak_ram_bytes,
+        }
+        .print();
+    } else {
+        println!("{}", summary);
+        print_session_exit_success();
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventType;
+    use crate::logging::LogLevel;
+
+    #[test]
+    fn test_event_record_is_one_tagged_json_object() {
+        let event = Event::task_fetcher_with_level(
+            "Step 1 of 4: Got task abc".to_string(),
+            EventType::Success,
+            LogLevel::Info,
+        )
+        .with_phase(ProofPhase::Fetched {
+            task_id: "abc".to_string(),
+        });
+
+        let line = serde_json::to_string(&JsonRecord::Event(&event)).unwrap();
+        assert!(!line.contains('\n'));
+
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["type"], "event");
+        assert_eq!(value["worker"], "TaskFetcher");
+        assert_eq!(value["event_type"], "Success");
+        assert_eq!(value["msg"], "Step 1 of 4: Got task abc");
+        assert_eq!(value["phase"]["kind"], "fetched");
+        assert_eq!(value["phase"]["task_id"], "abc");
+        assert!(value["prover_state"].is_null());
+    }
+
+    #[test]
+    fn test_metrics_record_contains_both_snapshots() {
+        let zkvm = ZkVMMetrics::default();
+        let system = SystemMetrics::default();
+
+        let line = serde_json::to_string(&JsonRecord::Metrics {
+            zkvm: &zkvm,
+            system: &system,
+        })
+        .unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["type"], "metrics");
+        assert_eq!(value["zkvm"]["tasks_submitted"], 0);
+        assert!(value["system"]["cpu_percent"].is_number());
+        assert!(value["system"].get("last_cpu_update").is_none());
+    }
+
+    #[test]
+    fn test_snapshot_record_flattens_the_snapshot() {
+        let snapshot = DashboardSnapshot {
+            node_id: Some(7),
+            ..DashboardSnapshot::default()
+        };
+
+        let line = serde_json::to_string(&JsonRecord::Snapshot(&snapshot)).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["type"], "snapshot");
+        assert_eq!(value["node_id"], 7);
+    }
+}
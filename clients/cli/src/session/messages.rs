@@ -1,5 +1,8 @@
 //! Unified messaging system for session operations
 
+use std::io;
+use std::path::Path;
+
 // ANSI Color Codes for session messages
 pub const COLOR_INFO: &str = "\x1b[1;36m"; // Bold Cyan
 pub const COLOR_SUCCESS: &str = "\x1b[1;32m"; // Bold Green
@@ -48,6 +51,17 @@ pub fn print_session_shutdown() {
     SessionMessage::info("Shutting down...").print();
 }
 
+/// Print where the exit state dump was written, or why it couldn't be
+pub fn print_state_dump(path: &Path, written: io::Result<()>) {
+    match written {
+        Ok(()) => SessionMessage::info(format!("State written to {}", path.display())).print(),
+        Err(e) => crate::print_cmd_error!(
+            "Failed to write state dump",
+            &format!("{}: {}", path.display(), e)
+        ),
+    }
+}
+
 /// Print session exit message
 pub fn print_session_exit_success() {
     SessionMessage::success("Nexus CLI exited successfully").print();
@@ -1,6 +1,9 @@
+pub mod commands;
 pub mod headless_mode;
 pub mod messages;
+pub mod replay;
 pub mod setup;
+pub mod summary;
 pub mod tui_mode;
 
 pub use headless_mode::run_headless_mode;
@@ -0,0 +1,82 @@
+//! Event log replay
+//!
+//! Feeds the events of a recorded JSON event log through the usual session
+//! channels instead of starting a worker, so a past run can be watched in the
+//! dashboard or headless output.
+
+use super::SessionData;
+use crate::environment::Environment;
+use crate::event_log::{read_events, replay_delay};
+use crate::orchestrator::OrchestratorClient;
+use std::error::Error;
+use std::path::Path;
+use std::time::Instant;
+use tokio::sync::{broadcast, mpsc};
+
+/// Sets up a session that replays a recorded event log
+///
+/// # Arguments
+/// * `path` - Event log written with `--log-format json`
+/// * `speed` - Replay this many times faster than recorded (0 = no delays)
+/// * `env` - Environment shown in the dashboard
+/// * `node_id` - Node ID shown in the dashboard
+/// * `exit_when_done` - Shut the session down once every event has been replayed
+///
+/// # Returns
+/// * `Ok(SessionData)` - Replay started
+/// * `Err` - The log couldn't be read or holds no events
+pub fn setup_replay(
+    path: &Path,
+    speed: f64,
+    env: Environment,
+    node_id: u64,
+    exit_when_done: bool,
+) -> Result<SessionData, Box<dyn Error>> {
+    let events = read_events(path)?;
+    if events.is_empty() {
+        return Err(format!(
+            "No replayable events in {} (was it written with --log-format json?)",
+            path.display()
+        )
+        .into());
+    }
+
+    let (event_sender, event_receiver) = mpsc::channel(events.len());
+    let (shutdown_sender, _) = broadcast::channel(1);
+    let (max_tasks_shutdown_sender, _) = broadcast::channel(1);
+
+    let mut shutdown = shutdown_sender.subscribe();
+    let done_sender = max_tasks_shutdown_sender.clone();
+    let feeder = tokio::spawn(async move {
+        let mut previous: Option<String> = None;
+        for mut event in events {
+            if let Some(previous) = &previous {
+                let delay = replay_delay(previous, &event.timestamp, speed);
+                tokio::select! {
+                    _ = tokio::time::sleep(delay) => {}
+                    _ = shutdown.recv() => return,
+                }
+            }
+            previous = Some(event.timestamp.clone());
+            // Timing is measured from when the event is replayed, not when it was read
+            event.emitted_at = Instant::now();
+            if event_sender.send(event).await.is_err() {
+                return;
+            }
+        }
+        if exit_when_done {
+            let _ = done_sender.send(());
+        }
+    });
+
+    Ok(SessionData {
+        event_receiver,
+        join_handles: vec![feeder],
+        shutdown_sender,
+        max_tasks_shutdown_sender,
+        node_id,
+        orchestrator: OrchestratorClient::new(env),
+        num_workers: 1,
+        schedule: None,
+    })
+}
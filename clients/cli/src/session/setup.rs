@@ -5,7 +5,8 @@ use crate::config::Config;
 use crate::environment::Environment;
 use crate::events::Event;
 use crate::orchestrator::OrchestratorClient;
-use crate::runtime::start_authenticated_worker;
+use crate::runtime::start_authenticated_workers;
+use crate::schedule::Schedule;
 use ed25519_dalek::SigningKey;
 use std::error::Error;
 use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
@@ -27,8 +28,10 @@ pub struct SessionData {
     pub node_id: u64,
     /// Orchestrator client
     pub orchestrator: OrchestratorClient,
-    /// Number of workers (for display purposes)
+    /// Number of workers started
     pub num_workers: usize,
+    /// Proving schedule applied by the workers (for display purposes)
+    pub schedule: Option<Schedule>,
 }
 
 /// Warn the user if their available memory seems insufficient for the task(s) at hand
@@ -63,14 +66,15 @@ pub fn warn_memory_configuration(max_threads: Option<u32>) {
 /// This function handles all the common setup required for both TUI and headless modes:
 /// 1. Creates signing key for the prover
 /// 2. Sets up shutdown channel
-/// 3. Starts authenticated worker
+/// 3. Starts the authenticated workers
 /// 4. Returns session data for mode-specific handling
 ///
 /// # Arguments
 /// * `config` - Resolved configuration with node_id and client_id
 /// * `env` - Environment to connect to
-/// * `max_threads` - Optional maximum number of threads for proving
+/// * `max_threads` - Optional number of provers to run at once
 /// * `max_difficulty` - Optional override for task difficulty
+/// * `schedule` - Optional daily schedule controlling when proving runs
 ///
 /// # Returns
 /// * `Ok(SessionData)` - Successfully set up session
@@ -82,6 +86,7 @@ pub async fn setup_session(
     max_threads: Option<u32>,
     max_tasks: Option<u32>,
     max_difficulty: Option<crate::nexus_orchestrator::TaskDifficulty>,
+    schedule: Option<Schedule>,
 ) -> Result<SessionData, Box<dyn Error>> {
     let node_id = config.node_id.parse::<u64>()?;
     let client_id = config.user_id;
@@ -107,8 +112,9 @@ pub async fn setup_session(
     // Set wallet for reporting
     set_wallet_address_for_reporting(config.wallet_address.clone());
 
-    // Start authenticated worker (only mode we support now)
-    let (event_receiver, join_handles, max_tasks_shutdown_sender) = start_authenticated_worker(
+    // Start the authenticated workers (only mode we support now)
+    let (event_receiver, join_handles, max_tasks_shutdown_sender) = start_authenticated_workers(
+        num_workers,
         node_id,
         signing_key,
         orchestrator_client.clone(),
@@ -117,6 +123,7 @@ pub async fn setup_session(
         client_id,
         max_tasks,
         max_difficulty,
+        schedule.clone(),
     )
     .await;
 
@@ -128,5 +135,6 @@ pub async fn setup_session(
         node_id,
         orchestrator: orchestrator_client,
         num_workers,
+        schedule,
     })
 }
@@ -0,0 +1,139 @@
+//! Session summary
+//!
+//! Totals for the session that just ended, printed on a graceful exit once the
+//! terminal has been restored.
+
+use crate::ui::dashboard::DashboardState;
+use crate::ui::metrics::{SystemMetrics, ZkVMMetrics, format_duration};
+use std::fmt;
+use std::time::Duration;
+
+/// What a session achieved, read from the final dashboard state
+#[derive(Debug, Clone)]
+pub struct SessionSummary {
+    /// How long the session ran
+    pub uptime: Duration,
+    /// Counters for this session only, excluding totals restored at startup
+    pub zkvm: ZkVMMetrics,
+    /// Final system metrics, with the peak RAM of this session only
+    pub system: SystemMetrics,
+}
+
+impl SessionSummary {
+    /// Summarize the session tracked by `state`
+    pub fn from_state(state: &DashboardState) -> Self {
+        let start = &state.stats_at_start;
+        let end = &state.zkvm_metrics;
+        let tasks_submitted = end.tasks_submitted.saturating_sub(start.tasks_submitted);
+        let zkvm = ZkVMMetrics {
+            tasks_fetched: end.tasks_fetched.saturating_sub(start.tasks_fetched),
+            tasks_proved: end.tasks_proved.saturating_sub(start.tasks_proved),
+            tasks_submitted,
+            proof_failures: end.proof_failures.saturating_sub(start.proof_failures),
+            submit_failures: end.submit_failures.saturating_sub(start.submit_failures),
+            zkvm_runtime_secs: end
+                .zkvm_runtime_secs
+                .saturating_sub(start.zkvm_runtime_secs),
+            last_task_status: end.last_task_status,
+            untimed_proofs: end.untimed_proofs.saturating_sub(start.untimed_proofs),
+            total_points: end
+                .total_points
+                .saturating_sub(start.total_points.unwrap_or_default()),
+            // Not persisted, so already for this session only
+            total_proof_bytes: end.total_proof_bytes,
+            sized_proofs: end.sized_proofs,
+            dropped_tasks: end.dropped_tasks,
+        };
+        // The all-time peak may have been restored from an earlier session
+        let system = SystemMetrics {
+            peak_ram_bytes: state.session_peak_ram_bytes,
+            ..state.system_metrics.clone()
+        };
+        Self {
+            uptime: state.start_time.elapsed(),
+            zkvm,
+            system,
+        }
+    }
+}
+
+impl fmt::Display for SessionSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let zkvm = &self.zkvm;
+        writeln!(f, "Session summary")?;
+        writeln!(
+            f,
+            "  Uptime:          {}",
+            format_duration(self.uptime.as_secs())
+        )?;
+        writeln!(
+            f,
+            "  Tasks:           {} fetched, {} proved, {} submitted",
+            zkvm.tasks_fetched, zkvm.tasks_proved, zkvm.tasks_submitted
+        )?;
+        writeln!(
+            f,
+            "  Failures:        {} proof, {} submit, {} dropped",
+            zkvm.proof_failures, zkvm.submit_failures, zkvm.dropped_tasks
+        )?;
+        writeln!(f, "  Points earned:   {}", zkvm.format_points())?;
+        writeln!(f, "  Peak RAM:        {}", self.system.format_peak_ram())?;
+        let average = match zkvm.avg_proving_secs() {
+            Some(secs) => format_duration(secs.round() as u64),
+            None => "n/a".to_string(),
+        };
+        write!(f, "  Avg proof time:  {}", average)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::LifetimeStats;
+    use crate::ui::app::UIConfig;
+    use crate::ui::dashboard::testing::{test_state, test_state_with};
+
+    #[test]
+    fn test_summary_excludes_totals_from_earlier_sessions() {
+        let mut state =
+            test_state_with(UIConfig::new(false, 1, false, None).with_points_per_task(100));
+        state.stats_at_start = LifetimeStats {
+            tasks_fetched: 10,
+            tasks_proved: 10,
+            tasks_submitted: 10,
+            zkvm_runtime_secs: 1000,
+            total_points: Some(3000),
+            ..LifetimeStats::default()
+        };
+        state.zkvm_metrics.tasks_fetched = 14;
+        state.zkvm_metrics.tasks_proved = 13;
+        state.zkvm_metrics.tasks_submitted = 12;
+        state.zkvm_metrics.total_points = 3000 + 200;
+        state.zkvm_metrics.proof_failures = 1;
+        state.zkvm_metrics.zkvm_runtime_secs = 1000 + 3 * 90;
+
+        let summary = SessionSummary::from_state(&state);
+        assert_eq!(summary.zkvm.tasks_fetched, 4);
+        assert_eq!(summary.zkvm.tasks_proved, 3);
+        assert_eq!(summary.zkvm.tasks_submitted, 2);
+        assert_eq!(summary.zkvm.total_points, 200);
+        assert_eq!(summary.zkvm.avg_proving_secs(), Some(90.0));
+
+        let text = summary.to_string();
+        assert!(text.contains("4 fetched, 3 proved, 2 submitted"));
+        assert!(text.contains("1 proof, 0 submit"));
+        assert!(text.contains("Avg proof time:  1m 30s"));
+    }
+
+    #[test]
+    fn test_summary_peak_ram_is_this_sessions_own() {
+        let mut state = test_state();
+        // A lifetime peak restored from an earlier session
+        state.system_metrics.peak_ram_bytes = 8 * 1024 * 1024 * 1024;
+        state.system_metrics.sampled_ram_bytes = 1024 * 1024 * 1024;
+        state.track_peak_ram();
+
+        let summary = SessionSummary::from_state(&state);
+        assert_eq!(summary.system.peak_ram_bytes, 1024 * 1024 * 1024);
+    }
+}
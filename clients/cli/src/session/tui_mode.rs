@@ -2,7 +2,10 @@
 
 use super::{
     SessionData,
-    messages::{print_session_exit_success, print_session_shutdown, print_session_starting},
+    messages::{
+        print_session_exit_success, print_session_shutdown, print_session_starting,
+        print_state_dump,
+    },
 };
 use crate::orchestrator::Orchestrator;
 use crate::ui::{self, UIConfig};
@@ -24,15 +27,12 @@ use std::{error::Error, io};
 ///
 /// # Arguments
 /// * `session` - Session data from setup
-/// * `with_background` - Whether to enable background colors
+/// * `ui_config` - Dashboard configuration; version info is filled in here
 ///
 /// # Returns
 /// * `Ok(())` - TUI mode completed successfully
 /// * `Err` - TUI mode failed
-pub async fn run_tui_mode(
-    session: SessionData,
-    with_background: bool,
-) -> Result<(), Box<dyn Error>> {
+pub async fn run_tui_mode(session: SessionData, ui_config: UIConfig) -> Result<(), Box<dyn Error>> {
     // Print session start message
     print_session_starting("TUI", session.node_id);
 
@@ -60,14 +60,13 @@ pub async fn run_tui_mode(
     let mut terminal = Terminal::new(backend)?;
 
     // Create the application and run it
-    let ui_config = UIConfig::new(
-        with_background,
-        session.num_workers,
-        version_update_available,
+    let ui_config = UIConfig {
+        update_available: version_update_available,
         latest_version,
-    );
+        ..ui_config
+    };
 
-    let app = ui::App::new(
+    let mut app = ui::App::new(
         Some(session.node_id),
         session.orchestrator.environment().clone(),
         session.event_receiver,
@@ -76,7 +75,7 @@ pub async fn run_tui_mode(
         ui_config,
     );
 
-    let result = ui::run(&mut terminal, app).await;
+    let result = ui::run(&mut terminal, &mut app).await;
 
     // Clean up the terminal after running the application
     disable_raw_mode()?;
@@ -87,14 +86,24 @@ pub async fn run_tui_mode(
     )?;
     terminal.show_cursor()?;
 
+    // Dump before handling the result: a failed UI is when the state is most wanted
+    if let Some((path, written)) = app.dump_state_on_exit() {
+        print_state_dump(&path, written);
+    }
+
     // Handle the result
-    result?;
+    let summary = result?;
 
     // Wait for workers to finish
     print_session_shutdown();
     for handle in session.join_handles {
         let _ = handle.await;
     }
+
+    // The terminal is back in cooked mode, so the summary prints cleanly
+    if let Some(summary) = summary {
+        println!("{}", summary);
+    }
     print_session_exit_success();
 
     Ok(())
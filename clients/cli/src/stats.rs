@@ -0,0 +1,177 @@
+//! Lifetime statistics
+//!
+//! Cumulative dashboard counters are saved to `~/.nexus/stats.json` and
+//! restored on startup, so totals survive restarts. Transient state such as
+//! the activity log and in-flight timers is never persisted.
+
+use crate::ui::metrics::{SystemMetrics, ZkVMMetrics};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Get the path to the lifetime statistics file, typically ~/.nexus/stats.json.
+pub fn get_stats_path() -> Result<PathBuf, io::Error> {
+    let home_path = home::home_dir().ok_or(io::Error::new(
+        io::ErrorKind::NotFound,
+        "Home directory not found",
+    ))?;
+    Ok(home_path.join(".nexus").join("stats.json"))
+}
+
+/// Cumulative counters carried across sessions
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct LifetimeStats {
+    /// Tasks obtained from the orchestrator
+    pub tasks_fetched: usize,
+    /// Tasks a proof was generated for
+    pub tasks_proved: usize,
+    /// Proofs accepted by the orchestrator
+    pub tasks_submitted: usize,
+    /// Proof generation failures
+    pub proof_failures: usize,
+    /// Proof submission failures
+    pub submit_failures: usize,
+    /// Total zkVM runtime in seconds
+    pub zkvm_runtime_secs: u64,
+    /// Proofs whose duration is missing from `zkvm_runtime_secs`
+    pub untimed_proofs: usize,
+    /// Highest process RAM usage ever observed, in bytes
+    pub peak_ram_bytes: u64,
+    /// Points credited so far. Missing from files saved before the total was
+    /// kept, whose total is derived from `tasks_submitted`.
+    pub total_points: Option<u64>,
+}
+
+impl LifetimeStats {
+    /// Capture the cumulative counters from the current metrics.
+    pub fn capture(zkvm: &ZkVMMetrics, system: &SystemMetrics) -> Self {
+        Self {
+            tasks_fetched: zkvm.tasks_fetched,
+            tasks_proved: zkvm.tasks_proved,
+            tasks_submitted: zkvm.tasks_submitted,
+            proof_failures: zkvm.proof_failures,
+            submit_failures: zkvm.submit_failures,
+            zkvm_runtime_secs: zkvm.zkvm_runtime_secs,
+            untimed_proofs: zkvm.untimed_proofs,
+            peak_ram_bytes: system.peak_ram_bytes,
+            total_points: Some(zkvm.total_points),
+        }
+    }
+
+    /// Seed fresh metrics with these counters.
+    pub fn restore(&self, zkvm: &mut ZkVMMetrics, system: &mut SystemMetrics) {
+        zkvm.tasks_fetched = self.tasks_fetched;
+        zkvm.tasks_proved = self.tasks_proved;
+        zkvm.tasks_submitted = self.tasks_submitted;
+        zkvm.proof_failures = self.proof_failures;
+        zkvm.submit_failures = self.submit_failures;
+        zkvm.zkvm_runtime_secs = self.zkvm_runtime_secs;
+        zkvm.untimed_proofs = self.untimed_proofs;
+        system.peak_ram_bytes = self.peak_ram_bytes;
+        if let Some(total_points) = self.total_points {
+            zkvm.total_points = total_points;
+        }
+    }
+
+    /// Load statistics from the given path. A missing or unreadable file
+    /// starts from zero rather than failing the session.
+    pub fn load(path: &Path) -> Self {
+        fs::read(path)
+            .ok()
+            .and_then(|buf| serde_json::from_slice(&buf).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save statistics to the given path, creating its directory if needed.
+    /// Writes to a temporary file first so a crash never leaves a truncated file.
+    pub fn save(&self, path: &Path) -> Result<(), io::Error> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Serialization failed: {}", e),
+            )
+        })?;
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, json)?;
+        fs::rename(&tmp_path, path)
+    }
+
+    /// Delete the statistics file, if there is one.
+    pub fn clear(path: &Path) -> Result<(), io::Error> {
+        match fs::remove_file(path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn get_stats() -> LifetimeStats {
+        LifetimeStats {
+            tasks_fetched: 12,
+            tasks_proved: 10,
+            tasks_submitted: 9,
+            proof_failures: 2,
+            submit_failures: 1,
+            zkvm_runtime_secs: 3600,
+            untimed_proofs: 1,
+            peak_ram_bytes: 4 * 1024 * 1024 * 1024,
+            total_points: Some(3100),
+        }
+    }
+
+    #[test]
+    // Loading saved statistics should return the same statistics.
+    fn test_load_recovers_saved_stats() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("nested").join("stats.json");
+
+        let stats = get_stats();
+        stats.save(&path).unwrap();
+
+        assert_eq!(LifetimeStats::load(&path), stats);
+    }
+
+    #[test]
+    // A missing or corrupt file should start from zero.
+    fn test_load_falls_back_to_zero() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("stats.json");
+        assert_eq!(LifetimeStats::load(&path), LifetimeStats::default());
+
+        fs::write(&path, "{ not json").unwrap();
+        assert_eq!(LifetimeStats::load(&path), LifetimeStats::default());
+    }
+
+    #[test]
+    // Clearing should remove the file, and tolerate it already being gone.
+    fn test_clear_removes_stats() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("stats.json");
+        get_stats().save(&path).unwrap();
+
+        LifetimeStats::clear(&path).unwrap();
+        assert!(!path.exists());
+        LifetimeStats::clear(&path).unwrap();
+    }
+
+    #[test]
+    // Capturing restored metrics should round-trip the counters.
+    fn test_capture_restore_round_trip() {
+        let stats = get_stats();
+        let mut zkvm = ZkVMMetrics::default();
+        let mut system = SystemMetrics::default();
+        stats.restore(&mut zkvm, &mut system);
+
+        assert_eq!(LifetimeStats::capture(&zkvm, &system), stats);
+    }
+}
@@ -0,0 +1,142 @@
+//! Status API
+//!
+//! Read-only JSON view of the dashboard over a local TCP socket. Each
+//! connection receives the latest dashboard snapshot as a single JSON line and
+//! is then closed, so `nc 127.0.0.1 <port>` is a complete client, and so is
+//! `nexus-network status`.
+
+use crate::consts::cli_consts::{ACCEPT_RETRY_DELAY_MS, STATUS_REQUEST_TIMEOUT_SECS};
+use crate::ui::dashboard::snapshot::DashboardSnapshot;
+use std::io;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+
+/// Listener serving dashboard snapshots
+pub struct StatusServer {
+    listener: TcpListener,
+}
+
+impl StatusServer {
+    /// Bind the socket, so address errors surface before proving starts
+    pub async fn bind(addr: SocketAddr) -> io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(addr).await?,
+        })
+    }
+
+    /// Address the server is listening on
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Serve snapshots until the task is dropped. Connections only read the
+    /// latest published snapshot, so they never wait on the dashboard.
+    pub async fn run(self, snapshot: watch::Receiver<DashboardSnapshot>) {
+        loop {
+            let stream = match self.listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(_) => {
+                    // Errors such as running out of file descriptors persist
+                    // for a while; retrying straight away would spin
+                    tokio::time::sleep(Duration::from_millis(ACCEPT_RETRY_DELAY_MS)).await;
+                    continue;
+                }
+            };
+            let snapshot = snapshot.clone();
+            tokio::spawn(async move {
+                let _ = handle_connection(stream, snapshot).await;
+            });
+        }
+    }
+}
+
+/// Write the latest snapshot as one JSON line and close the connection
+async fn handle_connection(
+    mut stream: TcpStream,
+    snapshot: watch::Receiver<DashboardSnapshot>,
+) -> io::Result<()> {
+    // Serialize before writing so the borrow isn't held across an await
+    let line = serde_json::to_string(&*snapshot.borrow())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    stream.write_all(line.as_bytes()).await?;
+    stream.write_all(b"\n").await?;
+    stream.shutdown().await
+}
+
+/// Read one snapshot from a running instance's status API, checked to be a
+/// JSON object but otherwise passed through as served
+pub async fn fetch_snapshot(addr: SocketAddr) -> io::Result<serde_json::Value> {
+    let request = async {
+        let mut stream = TcpStream::connect(addr).await?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await?;
+        Ok::<_, io::Error>(response)
+    };
+    let response = tokio::time::timeout(Duration::from_secs(STATUS_REQUEST_TIMEOUT_SECS), request)
+        .await
+        .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "no response"))??;
+
+    let snapshot: serde_json::Value = serde_json::from_str(response.trim_end())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    if !snapshot.is_object() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "response is not a dashboard snapshot",
+        ));
+    }
+    Ok(snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_each_connection_gets_one_snapshot_line() {
+        let server = StatusServer::bind("127.0.0.1:0".parse().unwrap())
+            .await
+            .unwrap();
+        let addr = server.local_addr().unwrap();
+        let (sender, receiver) = watch::channel(DashboardSnapshot::default());
+        tokio::spawn(server.run(receiver));
+
+        let mut snapshot = DashboardSnapshot::default();
+        snapshot.node_id = Some(7);
+        sender.send_replace(snapshot);
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        assert_eq!(response.lines().count(), 1);
+        let value: serde_json::Value = serde_json::from_str(response.trim_end()).unwrap();
+        assert_eq!(value["node_id"], 7);
+        assert_eq!(value["fetching"]["state"], "idle");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_snapshot_reads_a_running_server() {
+        let server = StatusServer::bind("127.0.0.1:0".parse().unwrap())
+            .await
+            .unwrap();
+        let addr = server.local_addr().unwrap();
+        let (sender, receiver) = watch::channel(DashboardSnapshot::default());
+        tokio::spawn(server.run(receiver));
+
+        let mut snapshot = DashboardSnapshot::default();
+        snapshot.current_task = Some("task-9".to_string());
+        sender.send_replace(snapshot);
+        let value = fetch_snapshot(addr).await.unwrap();
+        assert_eq!(value["current_task"], "task-9");
+
+        // Nothing listens on a freshly released port
+        let closed = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        assert!(fetch_snapshot(closed).await.is_err());
+    }
+}
@@ -0,0 +1,238 @@
+//! Completed task CSV
+//!
+//! Appends one row per submitted task to a CSV file for spreadsheet analysis:
+//! when it was fetched, how long it took to prove and submit, and the points
+//! it earned. Each row is flushed as soon as it's written, so a
+//! crash loses at most the task in flight. Like the event log, writes happen on
+//! a background thread and a failing file is reported once.
+
+use crate::consts::cli_consts::TASK_CSV_QUEUE_SIZE;
+use crate::consts::cli_consts::history::PENDING_TASKS;
+use crate::history::PendingTasks;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Column names, written at the top of a fresh file
+pub const HEADER: &str = "task_id,fetched_at,proving_secs,submission_latency_secs,points\n";
+
+/// One completed task. Values that weren't observed are left empty.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompletedTask {
+    pub task_id: String,
+    /// When the task was fetched (event timestamp), if that was observed
+    pub fetched_at: Option<String>,
+    /// Proving time in seconds, if the proof was timed
+    pub proving_secs: Option<u64>,
+    /// Time from proof generation to acceptance, if both were observed
+    pub submission_latency: Option<Duration>,
+    pub points: u64,
+}
+
+impl CompletedTask {
+    /// Format the task as a CSV row, including the trailing newline
+    pub fn to_row(&self) -> String {
+        let cells = [
+            csv_cell(&self.task_id),
+            self.fetched_at.as_deref().map(csv_cell).unwrap_or_default(),
+            optional(self.proving_secs),
+            self.submission_latency
+                .map(|latency| format!("{:.3}", latency.as_secs_f64()))
+                .unwrap_or_default(),
+            self.points.to_string(),
+        ];
+        cells.join(",") + "\n"
+    }
+}
+
+fn optional(value: Option<u64>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+/// Quote a cell that would otherwise break the row
+fn csv_cell(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Handle to the background writer, plus the fetch and proof details of tasks
+/// that haven't been submitted yet
+#[derive(Debug)]
+pub struct TaskCsv {
+    /// Taken on drop, closing the queue so the writer finishes
+    rows: Option<SyncSender<CompletedTask>>,
+    failure: Receiver<String>,
+    pending: PendingTasks,
+    writer: Option<JoinHandle<()>>,
+}
+
+impl TaskCsv {
+    /// Open (creating if needed) the file and start the writer. Path errors
+    /// surface here, before anything is queued.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let file = open_csv(path)?;
+        let (rows, queue) = mpsc::sync_channel(TASK_CSV_QUEUE_SIZE);
+        let (failed, failure) = mpsc::channel();
+        let path = path.to_path_buf();
+        let writer = std::thread::spawn(move || run(file, path, queue, failed));
+        Ok(Self {
+            rows: Some(rows),
+            failure,
+            pending: PendingTasks::new(PENDING_TASKS),
+            writer: Some(writer),
+        })
+    }
+
+    /// Note when a task was fetched
+    pub fn task_fetched(&mut self, task_id: &str, at: &str) {
+        self.pending.entry(task_id).fetched_at = Some(at.to_string());
+    }
+
+    /// Note how long a task took to prove
+    pub fn task_proved(&mut self, task_id: &str, duration: Option<Duration>) {
+        self.pending.entry(task_id).duration_secs = duration.map(|duration| duration.as_secs());
+    }
+
+    /// Queue the row for a submitted task
+    pub fn task_submitted(&mut self, task_id: &str, latency: Option<Duration>, points: u64) {
+        let record = self.pending.take(task_id);
+        let row = CompletedTask {
+            task_id: record.task_id,
+            fetched_at: record.fetched_at,
+            proving_secs: record.duration_secs,
+            submission_latency: latency,
+            points,
+        };
+        // Rows are dropped if the writer falls a full queue behind
+        if let Some(rows) = &self.rows {
+            let _ = rows.try_send(row);
+        }
+    }
+
+    /// Why the writer stopped, if it has
+    pub fn failure(&self) -> Option<String> {
+        self.failure.try_recv().ok()
+    }
+}
+
+impl Drop for TaskCsv {
+    /// Close the queue and wait for the writer to finish the rows in it
+    fn drop(&mut self) {
+        self.rows = None;
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+        }
+    }
+}
+
+/// Open the file for appending, writing the header if it's new (or empty)
+fn open_csv(path: &Path) -> io::Result<File> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent)?;
+        }
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if file.metadata()?.len() == 0 {
+        file.write_all(HEADER.as_bytes())?;
+        file.flush()?;
+    }
+    Ok(file)
+}
+
+/// Write queued rows until the dashboard goes away or a write fails
+fn run(
+    mut file: File,
+    path: PathBuf,
+    queue: Receiver<CompletedTask>,
+    failed: mpsc::Sender<String>,
+) {
+    for row in queue {
+        let written = file
+            .write_all(row.to_row().as_bytes())
+            .and_then(|()| file.flush());
+        if let Err(e) = written {
+            let _ = failed.send(format!("Task CSV {} disabled: {}", path.display(), e));
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_missing_values_are_empty_cells() {
+        let row = CompletedTask {
+            task_id: "a,1".to_string(),
+            fetched_at: Some("2025-01-01 12:00:00".to_string()),
+            proving_secs: Some(90),
+            points: 300,
+            ..CompletedTask::default()
+        };
+        assert_eq!(row.to_row(), "\"a,1\",2025-01-01 12:00:00,90,,300\n");
+
+        let row = CompletedTask {
+            task_id: "b".to_string(),
+            submission_latency: Some(Duration::from_millis(1500)),
+            ..CompletedTask::default()
+        };
+        assert_eq!(row.to_row(), "b,,,1.500,0\n");
+    }
+
+    /// Wait for the background writer to get the file to `expected`
+    fn wait_for(path: &Path, expected: &str) {
+        let mut contents = String::new();
+        for _ in 0..200 {
+            contents = fs::read_to_string(path).unwrap();
+            if contents == expected {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(contents, expected);
+    }
+
+    #[test]
+    fn test_header_is_written_once_and_rows_follow_it() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("tasks.csv");
+
+        let mut csv = TaskCsv::open(&path).unwrap();
+        csv.task_fetched("a", "2025-01-01 12:00:00");
+        csv.task_proved("a", Some(Duration::from_secs(90)));
+        csv.task_submitted("a", Some(Duration::from_secs(2)), 300);
+        let first = HEADER.to_string() + "a,2025-01-01 12:00:00,90,2.000,300\n";
+        wait_for(&path, &first);
+        drop(csv);
+
+        // Reopening an existing file appends without a second header
+        let mut csv = TaskCsv::open(&path).unwrap();
+        csv.task_submitted("b", None, 300);
+        wait_for(&path, &(first + "b,,,,300\n"));
+    }
+
+    #[test]
+    fn test_drop_waits_for_queued_rows() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("tasks.csv");
+
+        let mut csv = TaskCsv::open(&path).unwrap();
+        for task_id in ["a", "b", "c"] {
+            csv.task_submitted(task_id, None, 300);
+        }
+        drop(csv);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            HEADER.to_string() + "a,,,,300\nb,,,,300\nc,,,,300\n"
+        );
+    }
+}
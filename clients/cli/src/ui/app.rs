@@ -2,15 +2,31 @@
 //!
 //! Contains the App struct and main UI event handling logic
 
+use crate::consts::cli_consts::{
+    DEFAULT_CPU_TEMP_WARN_CELSIUS, DEFAULT_FETCH_TIMEOUT_SECS, DEFAULT_METRICS_SMOOTHING,
+    DEFAULT_POINTS_PER_TASK, DEFAULT_REFRESH_MS, DEFAULT_STALE_FETCHER_SECS,
+    DEFAULT_STUCK_PROVER_SECS, MAX_ACTIVITY_LOGS, MIN_REFRESH_MS,
+};
 use crate::environment::Environment;
+use crate::event_log::EventLogConfig;
 use crate::events::Event as WorkerEvent;
+use crate::notifier::FailureNotifier;
+use crate::schedule::Schedule;
+use crate::session::summary::SessionSummary;
+use crate::ui::dashboard::input::is_quit_key;
+use crate::ui::dashboard::regression::RegressionConfig;
+use crate::ui::dashboard::snapshot::DashboardSnapshot;
 use crate::ui::dashboard::{DashboardState, render_dashboard};
 use crate::ui::login::render_login;
+use crate::ui::metrics::{MetricsSnapshot, RamThresholds};
 use crate::ui::splash::render_splash;
+use crate::ui::theme::Theme;
+use crate::webhook::Webhook;
 use crossterm::event::{self, Event, KeyCode};
 use ratatui::{Frame, Terminal, backend::Backend};
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
-use tokio::sync::{broadcast, mpsc};
+use tokio::sync::{broadcast, mpsc, watch};
 
 /// UI configuration data grouped by concern
 #[derive(Debug, Clone)]
@@ -19,6 +35,31 @@ pub struct UIConfig {
     pub num_threads: usize,
     pub update_available: bool,
     pub latest_version: Option<String>,
+    pub schedule: Option<Schedule>,
+    pub show_first_proof: bool,
+    pub points_per_task: u64,
+    pub fetch_timeout_secs: u64,
+    pub stuck_threshold_secs: u64,
+    pub stale_threshold_secs: u64,
+    pub refresh_ms: u64,
+    pub ram_thresholds: RamThresholds,
+    pub cpu_temp_warn_celsius: f32,
+    pub theme: Theme,
+    pub metrics_smoothing: Option<f32>,
+    pub activity_log_capacity: usize,
+    pub daily_target: Option<u64>,
+    pub metrics_publisher: Option<watch::Sender<MetricsSnapshot>>,
+    pub snapshot_publisher: Option<watch::Sender<DashboardSnapshot>>,
+    pub stats_path: Option<PathBuf>,
+    pub failure_notifier: Option<FailureNotifier>,
+    pub webhook: Option<Webhook>,
+    pub event_log: Option<EventLogConfig>,
+    pub history_db: Option<PathBuf>,
+    pub task_csv: Option<PathBuf>,
+    pub syslog: bool,
+    pub regression: RegressionConfig,
+    pub dump_state: Option<PathBuf>,
+    pub redact_node_ids: bool,
 }
 
 impl UIConfig {
@@ -33,8 +74,185 @@ impl UIConfig {
             num_threads,
             update_available,
             latest_version,
+            schedule: None,
+            show_first_proof: true,
+            points_per_task: DEFAULT_POINTS_PER_TASK,
+            fetch_timeout_secs: DEFAULT_FETCH_TIMEOUT_SECS,
+            stuck_threshold_secs: DEFAULT_STUCK_PROVER_SECS,
+            stale_threshold_secs: DEFAULT_STALE_FETCHER_SECS,
+            refresh_ms: DEFAULT_REFRESH_MS,
+            ram_thresholds: RamThresholds::default(),
+            cpu_temp_warn_celsius: DEFAULT_CPU_TEMP_WARN_CELSIUS,
+            theme: Theme::default(),
+            metrics_smoothing: Some(DEFAULT_METRICS_SMOOTHING),
+            activity_log_capacity: MAX_ACTIVITY_LOGS,
+            daily_target: None,
+            metrics_publisher: None,
+            snapshot_publisher: None,
+            stats_path: None,
+            failure_notifier: None,
+            webhook: None,
+            event_log: None,
+            history_db: None,
+            task_csv: None,
+            syslog: false,
+            regression: RegressionConfig::default(),
+            dump_state: None,
+            redact_node_ids: false,
         }
     }
+
+    /// Show the proving schedule on the dashboard
+    pub fn with_schedule(mut self, schedule: Option<Schedule>) -> Self {
+        self.schedule = schedule;
+        self
+    }
+
+    /// Show (or suppress) the one-time confirmation after the first submitted proof
+    pub fn with_first_proof_banner(mut self, show_first_proof: bool) -> Self {
+        self.show_first_proof = show_first_proof;
+        self
+    }
+
+    /// Set the points credited for each submitted proof
+    pub fn with_points_per_task(mut self, points_per_task: u64) -> Self {
+        self.points_per_task = points_per_task;
+        self
+    }
+
+    /// Seconds a fetch may take before it is shown as timed out
+    pub fn with_fetch_timeout(mut self, fetch_timeout_secs: u64) -> Self {
+        self.fetch_timeout_secs = fetch_timeout_secs;
+        self
+    }
+
+    /// Seconds a single proof may take before its prover is flagged as stuck
+    pub fn with_stuck_threshold(mut self, stuck_threshold_secs: u64) -> Self {
+        self.stuck_threshold_secs = stuck_threshold_secs;
+        self
+    }
+
+    /// Seconds the task fetcher may stay silent before the connection is shown as lost
+    pub fn with_stale_threshold(mut self, stale_threshold_secs: u64) -> Self {
+        self.stale_threshold_secs = stale_threshold_secs;
+        self
+    }
+
+    /// Milliseconds between dashboard redraws (at least `MIN_REFRESH_MS`)
+    pub fn with_refresh_interval(mut self, refresh_ms: u64) -> Self {
+        self.refresh_ms = refresh_ms.max(MIN_REFRESH_MS);
+        self
+    }
+
+    /// RAM usage percentages at which the RAM gauge turns yellow and red
+    pub fn with_ram_thresholds(mut self, ram_thresholds: RamThresholds) -> Self {
+        self.ram_thresholds = ram_thresholds;
+        self
+    }
+
+    /// CPU temperature (°C) from which it is shown in red
+    pub fn with_cpu_temp_warning(mut self, cpu_temp_warn_celsius: f32) -> Self {
+        self.cpu_temp_warn_celsius = cpu_temp_warn_celsius;
+        self
+    }
+
+    /// Draw with these colors
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Weight of each new CPU and RAM sample in their moving averages, or
+    /// `None` to show raw readings
+    pub fn with_metrics_smoothing(mut self, metrics_smoothing: Option<f32>) -> Self {
+        self.metrics_smoothing = metrics_smoothing;
+        self
+    }
+
+    /// Set how many events the activity log retains (at least one)
+    pub fn with_activity_log_capacity(mut self, activity_log_capacity: usize) -> Self {
+        self.activity_log_capacity = activity_log_capacity.max(1);
+        self
+    }
+
+    /// Track submissions against a number of tasks per day
+    pub fn with_daily_target(mut self, daily_target: Option<u64>) -> Self {
+        self.daily_target = daily_target;
+        self
+    }
+
+    /// Publish a metrics snapshot after every dashboard update
+    pub fn with_metrics_publisher(
+        mut self,
+        metrics_publisher: Option<watch::Sender<MetricsSnapshot>>,
+    ) -> Self {
+        self.metrics_publisher = metrics_publisher;
+        self
+    }
+
+    /// Publish a dashboard snapshot for the status API after every update
+    pub fn with_snapshot_publisher(
+        mut self,
+        snapshot_publisher: Option<watch::Sender<DashboardSnapshot>>,
+    ) -> Self {
+        self.snapshot_publisher = snapshot_publisher;
+        self
+    }
+
+    /// Persist lifetime statistics at this path, restoring them on startup
+    pub fn with_stats_path(mut self, stats_path: Option<PathBuf>) -> Self {
+        self.stats_path = stats_path;
+        self
+    }
+
+    /// Raise desktop notifications on repeated proving or submission failures
+    pub fn with_failure_notifier(mut self, failure_notifier: Option<FailureNotifier>) -> Self {
+        self.failure_notifier = failure_notifier;
+        self
+    }
+
+    /// Report each submitted proof to a webhook
+    pub fn with_webhook(mut self, webhook: Option<Webhook>) -> Self {
+        self.webhook = webhook;
+        self
+    }
+
+    /// Append every activity log event to a rotating file
+    pub fn with_event_log(mut self, event_log: Option<EventLogConfig>) -> Self {
+        self.event_log = event_log;
+        self
+    }
+
+    /// Record proving history in a SQLite database
+    pub fn with_history_db(mut self, history_db: Option<PathBuf>) -> Self {
+        self.history_db = history_db;
+        self
+    }
+
+    /// Append a row per completed task to a CSV file
+    pub fn with_task_csv(mut self, task_csv: Option<PathBuf>) -> Self {
+        self.task_csv = task_csv;
+        self
+    }
+
+    /// Mirror every event to the system log
+    pub fn with_syslog(mut self, syslog: bool) -> Self {
+        self.syslog = syslog;
+        self
+    }
+
+    /// Slowdown over the baseline proving time that raises a regression alert
+    pub fn with_regression(mut self, regression: RegressionConfig) -> Self {
+        self.regression = regression;
+        self
+    }
+
+    /// Where state dumps are written (also at exit), and whether they redact node IDs
+    pub fn with_state_dump(mut self, dump_state: Option<PathBuf>, redact_node_ids: bool) -> Self {
+        self.dump_state = dump_state;
+        self.redact_node_ids = redact_node_ids;
+        self
+    }
 }
 
 /// The different screens in the application.
@@ -73,17 +291,8 @@ pub struct App {
     /// Receives max tasks completion signal.
     max_tasks_shutdown_receiver: broadcast::Receiver<()>,
 
-    /// Whether to disable background colors
-    with_background_color: bool,
-
-    /// Number of worker threads being used for proving.
-    num_threads: usize,
-
-    /// Whether a version update is available.
-    version_update_available: bool,
-
-    /// Latest version available, if any.
-    latest_version: Option<String>,
+    /// Display configuration passed on to the dashboard.
+    ui_config: UIConfig,
 }
 
 impl App {
@@ -104,10 +313,28 @@ impl App {
             event_receiver,
             shutdown_sender,
             max_tasks_shutdown_receiver,
-            with_background_color: ui_config.with_background_color,
-            num_threads: ui_config.num_threads,
-            version_update_available: ui_config.update_available,
-            latest_version: ui_config.latest_version,
+            ui_config,
+        }
+    }
+
+    /// Write the state dump requested with `--dump-state`, if the dashboard
+    /// was reached. Works after the UI loop has failed, too.
+    pub fn dump_state_on_exit(&self) -> Option<(PathBuf, std::io::Result<()>)> {
+        match &self.current_screen {
+            Screen::Dashboard(state) => state.dump_state_on_exit(),
+            _ => None,
+        }
+    }
+
+    /// Save the dashboard's lifetime statistics and summarize the session,
+    /// if the dashboard is showing.
+    fn finish(&mut self) -> Option<SessionSummary> {
+        match &mut self.current_screen {
+            Screen::Dashboard(state) => {
+                state.save_stats();
+                Some(SessionSummary::from_state(state))
+            }
+            _ => None,
         }
     }
 
@@ -115,24 +342,23 @@ impl App {
     #[allow(unused)]
     pub fn login(&mut self) {
         let node_id = Some(123); // Placeholder for node ID, replace with actual logic to get node ID
-        let ui_config = UIConfig::new(
-            self.with_background_color,
-            self.num_threads,
-            self.version_update_available,
-            self.latest_version.clone(),
-        );
         let state = DashboardState::new(
             node_id,
             self.environment.clone(),
             self.start_time,
-            ui_config,
+            self.ui_config.clone(),
         );
         self.current_screen = Screen::Dashboard(Box::new(state));
     }
 }
 
 /// Runs the application UI in a loop, handling events and rendering the appropriate screen.
-pub async fn run<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> std::io::Result<()> {
+/// Returns a summary of the session if the dashboard was reached. The app is
+/// borrowed so its state can still be dumped when the loop fails.
+pub async fn run<B: Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+) -> std::io::Result<Option<SessionSummary>> {
     let splash_start = Instant::now();
     let splash_duration = Duration::from_secs(2);
 
@@ -142,7 +368,7 @@ pub async fn run<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> std::i
         if app.max_tasks_shutdown_receiver.try_recv().is_ok() {
             // Send shutdown signal to workers and exit
             let _ = app.shutdown_sender.send(());
-            return Ok(());
+            return Ok(app.finish());
         }
 
         // Queue all incoming events for processing
@@ -162,57 +388,66 @@ pub async fn run<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> std::i
                 state.update();
             }
         }
-        terminal.draw(|f| render(f, &app.current_screen))?;
+        // A paused dashboard keeps its last frame on screen
+        let draw = match &mut app.current_screen {
+            Screen::Dashboard(state) => state.should_draw(),
+            _ => true,
+        };
+        if draw {
+            terminal.draw(|f| render(f, &app.current_screen, &app.ui_config.theme))?;
+        }
 
         // Handle splash-to-login transition
         if let Screen::Splash = app.current_screen {
             if splash_start.elapsed() >= splash_duration {
-                let ui_config = UIConfig::new(
-                    app.with_background_color,
-                    app.num_threads,
-                    app.version_update_available,
-                    app.latest_version.clone(),
-                );
                 app.current_screen = Screen::Dashboard(Box::new(DashboardState::new(
                     app.node_id,
                     app.environment.clone(),
                     app.start_time,
-                    ui_config,
+                    app.ui_config.clone(),
                 )));
                 continue;
             }
         }
 
         // Poll for key events
-        if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
+        if event::poll(Duration::from_millis(app.ui_config.refresh_ms))? {
+            let event = event::read()?;
+            if let Event::Resize(..) = event {
+                // Re-flow at the new size straight away instead of on the next
+                // tick, onto a cleared screen so nothing is left over from the
+                // old dimensions
+                terminal.autoresize()?;
+                terminal.clear()?;
+                if let Screen::Dashboard(state) = &mut app.current_screen {
+                    // Even a held frame no longer fits the terminal
+                    state.request_redraw();
+                }
+                continue;
+            }
+            if let Event::Key(key) = event {
                 // Skip events that are not KeyEventKind::Press
                 if key.kind == event::KeyEventKind::Release {
                     continue;
                 }
 
-                // Handle exit events
-                if matches!(key.code, KeyCode::Esc | KeyCode::Char('q')) {
+                // Handle exit events, including Ctrl-C, which raw mode
+                // delivers as a key press instead of a signal
+                if is_quit_key(&key) {
                     // Send shutdown signal to workers
                     let _ = app.shutdown_sender.send(());
-                    return Ok(());
+                    return Ok(app.finish());
                 }
 
                 match &mut app.current_screen {
                     Screen::Splash => {
                         // Any key press will skip the splash screen
                         if key.code != KeyCode::Esc && key.code != KeyCode::Char('q') {
-                            let ui_config = UIConfig::new(
-                                app.with_background_color,
-                                app.num_threads,
-                                app.version_update_available,
-                                app.latest_version.clone(),
-                            );
                             app.current_screen = Screen::Dashboard(Box::new(DashboardState::new(
                                 app.node_id,
                                 app.environment.clone(),
                                 app.start_time,
-                                ui_config,
+                                app.ui_config.clone(),
                             )));
                         }
                     }
@@ -221,7 +456,7 @@ pub async fn run<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> std::i
                             app.login();
                         }
                     }
-                    Screen::Dashboard(_dashboard_state) => {}
+                    Screen::Dashboard(dashboard_state) => dashboard_state.handle_key_event(key),
                 }
             }
         }
@@ -229,10 +464,10 @@ pub async fn run<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> std::i
 }
 
 /// Renders the current screen based on the application state.
-fn render(f: &mut Frame, screen: &Screen) {
+fn render(f: &mut Frame, screen: &Screen, theme: &Theme) {
     match screen {
-        Screen::Splash => render_splash(f),
-        Screen::Login => render_login(f),
+        Screen::Splash => render_splash(f, theme),
+        Screen::Login => render_login(f, theme),
         Screen::Dashboard(state) => render_dashboard(f, state),
     }
 }
@@ -0,0 +1,78 @@
+//! Averaging window for the displayed metrics
+//!
+//! The throughput and proving-time figures in the zkVM panel can be averaged
+//! over the whole throughput window, the last minute or five, or just the
+//! latest proof. Each is recomputed from the samples already retained, so
+//! switching windows takes effect immediately.
+
+use super::state::DashboardState;
+use crate::ui::metrics::AverageWindow;
+use std::time::Instant;
+
+impl DashboardState {
+    /// Switch to the next averaging window.
+    pub fn cycle_average_window(&mut self) {
+        self.average_window = self.average_window.next();
+    }
+
+    /// Submissions per hour over the selected window.
+    pub fn displayed_tasks_per_hour(&self, now: Instant) -> f64 {
+        if let Some(window) = self.average_window.duration() {
+            return self.throughput.tasks_per_hour_over(now, window);
+        }
+        match self.average_window {
+            AverageWindow::Instant => self.throughput.instant_tasks_per_hour(now),
+            _ => self.throughput.tasks_per_hour(now),
+        }
+    }
+
+    /// Proving time in seconds over the selected window, or `None` if no
+    /// proof falls within it.
+    pub fn displayed_proving_secs(&self, now: Instant) -> Option<f64> {
+        if let Some(window) = self.average_window.duration() {
+            return self.proving_times.average_over(now, window);
+        }
+        match self.average_window {
+            AverageWindow::Instant => self.proving_times.latest().map(|secs| secs as f64),
+            _ => self.zkvm_metrics.avg_proving_secs(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::dashboard::testing::test_state;
+    use crate::ui::metrics::AverageWindow;
+    use std::time::Duration;
+
+    #[test]
+    fn test_average_window_switches_the_displayed_figures() {
+        let mut state = test_state();
+        let start = state.start_time;
+        let mins = |n: u64| start + Duration::from_secs(60 * n);
+
+        state.zkvm_metrics.tasks_proved = 2;
+        state.zkvm_metrics.zkvm_runtime_secs = 300;
+        state.proving_times.record(mins(2), 240);
+        state.proving_times.record(mins(10), 60);
+        state.throughput.record(mins(2), 300);
+        state.throughput.record(mins(10), 300);
+
+        let now = mins(10);
+        assert_eq!(state.average_window, AverageWindow::Overall);
+        assert_eq!(state.displayed_proving_secs(now), Some(150.0));
+        assert_eq!(state.displayed_tasks_per_hour(now), 12.0);
+
+        state.cycle_average_window();
+        assert_eq!(state.displayed_proving_secs(now), Some(60.0));
+        assert_eq!(state.displayed_tasks_per_hour(now), 7.5);
+
+        state.cycle_average_window();
+        assert_eq!(state.average_window, AverageWindow::OneMinute);
+        assert_eq!(state.displayed_tasks_per_hour(now), 60.0);
+
+        // Nothing finished in the last five minutes
+        state.cycle_average_window();
+        assert_eq!(state.displayed_proving_secs(mins(20)), None);
+    }
+}
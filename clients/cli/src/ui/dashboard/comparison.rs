@@ -0,0 +1,144 @@
+//! Before/after comparison around a user-set mark
+//!
+//! Lets users mark a point in time (e.g. right after changing a setting) and
+//! compare the stats gathered since the mark against those from before it
+
+use super::state::DashboardState;
+use crate::ui::metrics::{SystemMetrics, ZkVMMetrics};
+
+use std::time::{Duration, Instant};
+
+/// Snapshot of the cumulative metrics taken when the user set a mark
+#[derive(Debug, Clone)]
+pub struct MetricsMark {
+    /// When the mark was set
+    pub marked_at: Instant,
+    /// Length of the "before" window (dashboard start to mark)
+    pub before_duration: Duration,
+    /// Cumulative metrics at the moment of the mark
+    pub metrics: ZkVMMetrics,
+}
+
+/// Aggregate stats for one side of the comparison
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct WindowStats {
+    /// Average proving time per submitted task, in seconds
+    pub avg_proof_secs: Option<f64>,
+    /// Submitted tasks per hour
+    pub tasks_per_hour: Option<f64>,
+    /// Percentage of fetched tasks that were not submitted
+    pub error_rate: Option<f64>,
+}
+
+impl WindowStats {
+    /// Compute stats for the span between two cumulative snapshots
+    pub fn between(start: &ZkVMMetrics, end: &ZkVMMetrics, duration: Duration) -> Self {
+        let submitted = end.tasks_submitted.saturating_sub(start.tasks_submitted);
+        let fetched = end.tasks_fetched.saturating_sub(start.tasks_fetched);
+        let runtime_secs = end
+            .zkvm_runtime_secs
+            .saturating_sub(start.zkvm_runtime_secs);
+        let hours = duration.as_secs_f64() / 3600.0;
+
+        Self {
+            avg_proof_secs: (submitted > 0).then(|| runtime_secs as f64 / submitted as f64),
+            tasks_per_hour: (hours > 0.0).then(|| submitted as f64 / hours),
+            error_rate: (fetched > 0)
+                .then(|| fetched.saturating_sub(submitted) as f64 / fetched as f64 * 100.0),
+        }
+    }
+}
+
+impl DashboardState {
+    /// Mark the current point in time, replacing any previous mark
+    pub fn set_mark(&mut self) {
+        let now = Instant::now();
+        self.mark = Some(MetricsMark {
+            marked_at: now,
+            before_duration: now.duration_since(self.start_time),
+            metrics: self.zkvm_metrics.clone(),
+        });
+    }
+
+    /// Remove the current mark and hide the comparison
+    pub fn clear_mark(&mut self) {
+        self.mark = None;
+    }
+
+    /// Stats before and after the mark, if one is set. The "before" window
+    /// starts from the counters restored at startup, not from zero.
+    pub fn mark_comparison(&self) -> Option<(WindowStats, WindowStats)> {
+        let mark = self.mark.as_ref()?;
+        let mut session_start = ZkVMMetrics::default();
+        self.stats_at_start
+            .restore(&mut session_start, &mut SystemMetrics::default());
+        let before = WindowStats::between(&session_start, &mark.metrics, mark.before_duration);
+        let after =
+            WindowStats::between(&mark.metrics, &self.zkvm_metrics, mark.marked_at.elapsed());
+        Some((before, after))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::LifetimeStats;
+    use crate::ui::dashboard::testing::test_state;
+
+    #[test]
+    fn test_window_stats_between_snapshots() {
+        let start = ZkVMMetrics {
+            tasks_fetched: 2,
+            tasks_submitted: 2,
+            zkvm_runtime_secs: 100,
+            ..ZkVMMetrics::default()
+        };
+        let end = ZkVMMetrics {
+            tasks_fetched: 6,
+            tasks_submitted: 5,
+            zkvm_runtime_secs: 250,
+            ..ZkVMMetrics::default()
+        };
+
+        let stats = WindowStats::between(&start, &end, Duration::from_secs(1800));
+
+        assert_eq!(stats.avg_proof_secs, Some(50.0));
+        assert_eq!(stats.tasks_per_hour, Some(6.0));
+        assert_eq!(stats.error_rate, Some(25.0));
+    }
+
+    #[test]
+    fn test_window_stats_empty_window() {
+        let metrics = ZkVMMetrics::default();
+
+        let stats = WindowStats::between(&metrics, &metrics, Duration::ZERO);
+
+        assert_eq!(stats, WindowStats::default());
+    }
+
+    #[test]
+    fn test_before_window_excludes_restored_stats() {
+        let mut state = test_state();
+        // Earlier sessions submitted 1000 tasks, this one 3 in its first 30 min
+        state.stats_at_start = LifetimeStats {
+            tasks_fetched: 1000,
+            tasks_submitted: 1000,
+            zkvm_runtime_secs: 50_000,
+            ..LifetimeStats::default()
+        };
+        state.zkvm_metrics.tasks_fetched = 1004;
+        state.zkvm_metrics.tasks_submitted = 1003;
+        state.zkvm_metrics.zkvm_runtime_secs = 50_300;
+        state.mark = Some(MetricsMark {
+            marked_at: Instant::now(),
+            before_duration: Duration::from_secs(1800),
+            metrics: state.zkvm_metrics.clone(),
+        });
+
+        let (before, _) = state.mark_comparison().unwrap();
+
+        assert_eq!(before.avg_proof_secs, Some(100.0));
+        assert_eq!(before.tasks_per_hour, Some(6.0));
+        assert_eq!(before.error_rate, Some(25.0));
+    }
+}
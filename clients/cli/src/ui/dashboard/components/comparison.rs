@@ -0,0 +1,79 @@
+//! Dashboard comparison panel component
+//!
+//! Renders stats before vs after the user-set mark
+
+use super::super::state::DashboardState;
+use crate::ui::theme::Theme;
+use ratatui::Frame;
+use ratatui::prelude::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Paragraph};
+
+/// Render the before/after comparison, if a mark is set.
+pub fn render_comparison_panel(f: &mut Frame, area: ratatui::layout::Rect, state: &DashboardState) {
+    let Some((before, after)) = state.mark_comparison() else {
+        return;
+    };
+    let theme = &state.theme;
+
+    let header = Line::from(Span::styled(
+        format!("{:<10}{:>8}{:>8}{:>8}", "", "Before", "After", "Delta"),
+        Style::default()
+            .fg(theme.label)
+            .add_modifier(Modifier::BOLD),
+    ));
+
+    let lines = vec![
+        header,
+        comparison_line(
+            theme,
+            "Avg proof",
+            before.avg_proof_secs,
+            after.avg_proof_secs,
+            "s",
+        ),
+        comparison_line(
+            theme,
+            "Tasks/h",
+            before.tasks_per_hour,
+            after.tasks_per_hour,
+            "",
+        ),
+        comparison_line(theme, "Errors", before.error_rate, after.error_rate, "%"),
+    ];
+
+    let block = Block::default()
+        .title("SINCE MARK [M]")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.accent));
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Format one metric row as "label  before  after  delta".
+fn comparison_line(
+    theme: &Theme,
+    label: &str,
+    before: Option<f64>,
+    after: Option<f64>,
+    unit: &str,
+) -> Line<'static> {
+    let format_value = |value: Option<f64>| match value {
+        Some(v) => format!("{:.1}{}", v, unit),
+        None => "-".to_string(),
+    };
+    let delta = match (before, after) {
+        (Some(b), Some(a)) => format!("{:+.1}{}", a - b, unit),
+        _ => "-".to_string(),
+    };
+
+    Line::from(vec![
+        Span::styled(format!("{:<10}", label), Style::default().fg(theme.label)),
+        Span::styled(
+            format!("{:>8}{:>8}", format_value(before), format_value(after)),
+            Style::default().fg(theme.text),
+        ),
+        Span::styled(format!("{:>8}", delta), Style::default().fg(theme.accent)),
+    ])
+}
@@ -0,0 +1,66 @@
+//! Dashboard last errors component
+//!
+//! Renders the most recent error of each worker type in full
+
+use super::super::state::DashboardState;
+use super::super::utils::{format_compact_timestamp, get_worker_color};
+use crate::events::Worker;
+use ratatui::Frame;
+use ratatui::prelude::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Paragraph, Wrap};
+
+/// Lines given to each error: its source, then room for a wrapped message
+const LINES_PER_ERROR: u16 = 3;
+
+/// Height needed to show the remembered errors (0 if none).
+pub fn errors_panel_height(state: &DashboardState) -> u16 {
+    let count = state.last_errors.iter().count() as u16;
+    if count == 0 {
+        0
+    } else {
+        count * LINES_PER_ERROR + 2
+    }
+}
+
+/// Render the last error of each worker type, oldest pipeline stage first.
+pub fn render_errors_panel(f: &mut Frame, area: ratatui::layout::Rect, state: &DashboardState) {
+    let theme = &state.theme;
+    let mut lines = Vec::new();
+    for event in state.last_errors.iter() {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{} ", worker_name(&event.worker)),
+                Style::default().fg(get_worker_color(theme, &event.worker)),
+            ),
+            Span::styled(
+                format_compact_timestamp(&event.timestamp),
+                Style::default().fg(theme.muted),
+            ),
+        ]));
+        lines.push(Line::from(Span::styled(
+            event.msg.clone(),
+            Style::default().fg(theme.error),
+        )));
+    }
+
+    let block = Block::default()
+        .title("LAST ERRORS · [X] clear")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.error));
+
+    f.render_widget(
+        Paragraph::new(lines).block(block).wrap(Wrap { trim: true }),
+        area,
+    );
+}
+
+fn worker_name(worker: &Worker) -> String {
+    match worker {
+        Worker::TaskFetcher => "Fetcher".to_string(),
+        Worker::Prover(id) => format!("Prover {}", id),
+        Worker::ProofSubmitter => "Submitter".to_string(),
+        Worker::Dashboard => "Dashboard".to_string(),
+    }
+}
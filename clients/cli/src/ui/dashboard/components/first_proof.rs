@@ -0,0 +1,56 @@
+//! Dashboard first-proof banner component
+//!
+//! Renders the one-time confirmation shown after a session's first submitted proof
+
+use super::super::state::DashboardState;
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::prelude::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Clear, Paragraph};
+
+const BANNER_WIDTH: u16 = 52;
+const BANNER_HEIGHT: u16 = 5;
+
+/// Render the first-proof confirmation over the centre of the dashboard, if it is showing.
+pub fn render_first_proof_banner(f: &mut Frame, area: Rect, state: &DashboardState) {
+    if !state.first_proof_banner_visible() {
+        return;
+    }
+    let theme = &state.theme;
+
+    let width = BANNER_WIDTH.min(area.width);
+    let height = BANNER_HEIGHT.min(area.height);
+    let banner_area = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+
+    let lines = vec![
+        Line::from(Span::styled(
+            "✓ Your prover is working! First proof submitted.",
+            Style::default()
+                .fg(theme.active)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            "Press any key to dismiss",
+            Style::default().fg(theme.label),
+        )),
+    ];
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .border_style(Style::default().fg(theme.active));
+
+    f.render_widget(Clear, banner_area);
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .alignment(Alignment::Center),
+        banner_area,
+    );
+}
@@ -1,17 +1,25 @@
 //! Dashboard footer component
 //!
-//! Renders footer with quit instructions and version info
+//! Renders footer with quit instructions and version info, or a message in
+//! their place
 
+use super::super::state::DashboardState;
 use ratatui::Frame;
 use ratatui::layout::Alignment;
-use ratatui::prelude::{Color, Modifier, Style};
+use ratatui::prelude::{Modifier, Style};
 use ratatui::widgets::{Block, BorderType, Borders, Paragraph};
 
 /// Render enhanced footer.
-pub fn render_footer(f: &mut Frame, area: ratatui::layout::Rect) {
-    let footer_text = "[Q] Quit | Nexus Prover Dashboard".to_string();
-
-    let footer_color = Color::Cyan;
+pub fn render_footer(f: &mut Frame, area: ratatui::layout::Rect, state: &DashboardState) {
+    let theme = &state.theme;
+    let (footer_text, footer_color) = match state.visible_toast() {
+        Some(toast) if toast.subtle => (toast.message.clone(), theme.muted),
+        Some(toast) => (toast.message.clone(), theme.highlight),
+        None => (
+            "[Q] Quit | [Space] Pause | [M] Mark / Unmark | [PgUp/PgDn] Scroll | [W] Worker | [E] Errors | [N] Nodes | [X] Clear Errors | [C] Clear Log | [Y] Copy Task | [A] Averaging | [R] Reset Peak | [D] Dump State | Nexus Prover Dashboard".to_string(),
+            theme.border,
+        ),
+    };
 
     let footer = Paragraph::new(footer_text)
         .alignment(Alignment::Center)
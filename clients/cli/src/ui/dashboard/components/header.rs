@@ -4,14 +4,18 @@
 
 use super::super::state::DashboardState;
 use crate::events::ProverState;
+use crate::ui::metrics::format_duration;
 
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout};
-use ratatui::prelude::{Color, Modifier, Style};
+use ratatui::prelude::{Modifier, Style};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Borders, Gauge, Paragraph};
+use std::time::Instant;
 
 /// Render enhanced header with title and stage progress.
 pub fn render_header(f: &mut Frame, area: ratatui::layout::Rect, state: &DashboardState) {
+    let theme = &state.theme;
     let header_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(2), Constraint::Length(2)])
@@ -28,14 +32,38 @@ pub fn render_header(f: &mut Frame, area: ratatui::layout::Rect, state: &Dashboa
     } else {
         format!("NEXUS PROVER v{}", version)
     };
+    let title_text = if state.paused {
+        format!("{} | PAUSED - [Space] resume", title_text)
+    } else {
+        title_text
+    };
+
+    let title_color = if state.paused {
+        theme.warning
+    } else if state.update_available {
+        theme.highlight
+    } else {
+        theme.border
+    };
 
-    let title_color = if state.update_available {
-        Color::LightYellow
+    // Failures since startup, standing out for a moment after each new one
+    let failures = state.failure_badge.count();
+    let badge_style = if state.failure_badge.is_flashing(Instant::now()) {
+        Style::default()
+            .fg(theme.error)
+            .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+    } else if failures > 0 {
+        Style::default().fg(theme.error)
     } else {
-        Color::Cyan
+        Style::default().fg(theme.muted)
     };
+    let title_line = Line::from(vec![
+        Span::raw(title_text),
+        Span::raw(" "),
+        Span::styled(format!(" {} FAILED ", failures), badge_style),
+    ]);
 
-    let title = Paragraph::new(title_text)
+    let title = Paragraph::new(title_line)
         .alignment(Alignment::Center)
         .style(
             Style::default()
@@ -56,38 +84,39 @@ pub fn render_header(f: &mut Frame, area: ratatui::layout::Rect, state: &Dashboa
             ProverState::Proving => {
                 // Animated proving gauge - loops every 20 ticks for smooth animation
                 let progress = ((state.tick % 20) as f64 / 20.0 * 100.0) as u16;
-                (
-                    "PROVING - Generating proof".to_string(),
-                    Color::LightGreen,
-                    progress,
-                )
+                let text = match (
+                    &state.current_task,
+                    state.current_task_elapsed(Instant::now()),
+                ) {
+                    (Some(task_id), Some(elapsed)) => format!(
+                        "PROVING - Generating proof for {} ({})",
+                        task_id,
+                        format_duration(elapsed.as_secs())
+                    ),
+                    _ => "PROVING - Generating proof".to_string(),
+                };
+                (text, theme.active, progress)
             }
             ProverState::Waiting => {
-                // Task fetching countdown logic
+                // Backoff countdown, filling towards the next fetch
                 let fetch_info = &state.task_fetch_info;
-                if !fetch_info.can_fetch_now && fetch_info.backoff_duration_secs > 0 {
-                    let remaining_secs = fetch_info
-                        .backoff_duration_secs
-                        .saturating_sub(fetch_info.time_since_last_fetch_secs);
-                    let progress = if fetch_info.backoff_duration_secs > 0 {
-                        ((fetch_info.time_since_last_fetch_secs as f64
-                            / fetch_info.backoff_duration_secs as f64)
-                            * 100.0) as u16
-                    } else {
-                        100
-                    };
-                    let display_text = if remaining_secs > 0 {
-                        format!("WAITING - Ready for next task ({}s)", remaining_secs)
-                    } else {
-                        "WAITING - Ready for next task".to_string()
-                    };
-                    (display_text, Color::LightBlue, progress.min(100))
-                } else {
+                if let Some(silence) = state.fetcher_silence(Instant::now()) {
                     (
-                        "WAITING - Ready for next task".to_string(),
-                        Color::LightBlue,
+                        format!(
+                            "DISCONNECTED - No activity from the orchestrator for {}",
+                            format_duration(silence.as_secs())
+                        ),
+                        theme.error,
                         100,
                     )
+                } else if fetch_info.is_backing_off() {
+                    (
+                        format!("WAITING - Next fetch in {}s", fetch_info.remaining_secs()),
+                        theme.info,
+                        fetch_info.progress_pct(),
+                    )
+                } else {
+                    ("READY - Ready to fetch".to_string(), theme.success, 100)
                 }
             }
         }
@@ -97,7 +126,7 @@ pub fn render_header(f: &mut Frame, area: ratatui::layout::Rect, state: &Dashboa
         .block(
             Block::default()
                 .borders(Borders::BOTTOM)
-                .border_style(Style::default().fg(Color::DarkGray)),
+                .border_style(Style::default().fg(theme.muted)),
         )
         .gauge_style(
             Style::default()
@@ -3,15 +3,19 @@
 //! Renders system information panel
 
 use crate::environment::Environment;
+use crate::schedule::{Schedule, format_minute_of_day};
+use crate::ui::metrics::format_duration;
 
 use super::super::state::DashboardState;
 use ratatui::Frame;
-use ratatui::prelude::{Color, Style};
+use ratatui::prelude::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Borders, Padding, Paragraph, Wrap};
+use std::time::Instant;
 
 /// Render enhanced info panel with better styling.
 pub fn render_info_panel(f: &mut Frame, area: ratatui::layout::Rect, state: &DashboardState) {
+    let theme = &state.theme;
     let mut info_lines = Vec::new();
 
     // Node information with enhanced formatting
@@ -22,15 +26,15 @@ pub fn render_info_panel(f: &mut Frame, area: ratatui::layout::Rect, state: &Das
     };
     info_lines.push(Line::from(vec![Span::styled(
         node_text,
-        Style::default().fg(Color::LightBlue),
+        Style::default().fg(theme.info),
     )]));
 
     // Environment with color coding
     let env_color = match state.environment {
-        Environment::Production => Color::Green,
+        Environment::Production => theme.success,
         Environment::Custom {
             orchestrator_url: _,
-        } => Color::Yellow,
+        } => theme.warning,
     };
     info_lines.push(Line::from(vec![Span::styled(
         format!("Env: {}", state.environment),
@@ -41,7 +45,7 @@ pub fn render_info_panel(f: &mut Frame, area: ratatui::layout::Rect, state: &Das
     let version = env!("CARGO_PKG_VERSION");
     info_lines.push(Line::from(vec![Span::styled(
         format!("Version: {}", version),
-        Style::default().fg(Color::Cyan),
+        Style::default().fg(theme.value),
     )]));
 
     // Uptime with better formatting
@@ -69,28 +73,52 @@ pub fn render_info_panel(f: &mut Frame, area: ratatui::layout::Rect, state: &Das
     };
     info_lines.push(Line::from(vec![Span::styled(
         uptime_string,
-        Style::default().fg(Color::LightGreen),
+        Style::default().fg(theme.active),
+    )]));
+
+    // Where the uptime went: waiting between fetches, fetching, or timed out
+    let times = state.fetching_times(Instant::now());
+    info_lines.push(Line::from(vec![Span::styled(
+        format!("Fetching: {:.1}% of uptime", times.active_pct()),
+        Style::default().fg(theme.active),
+    )]));
+    info_lines.push(Line::from(vec![Span::styled(
+        format!(
+            "  {} idle, {} active, {} timeout",
+            format_duration(times.idle.as_secs()),
+            format_duration(times.active.as_secs()),
+            format_duration(times.timeout.as_secs())
+        ),
+        Style::default().fg(theme.label),
     )]));
 
     // Threads info
     info_lines.push(Line::from(vec![Span::styled(
         format!("Threads: {}", state.num_threads),
-        Style::default().fg(Color::LightYellow),
+        Style::default().fg(theme.highlight),
     )]));
 
     // Total memory
     info_lines.push(Line::from(vec![Span::styled(
         format!("Memory: {:.1} GB", state.total_ram_gb),
-        Style::default().fg(Color::LightCyan),
+        Style::default().fg(theme.secondary),
     )]));
 
+    // Scheduled setting and the next change
+    if let Some(schedule) = &state.schedule {
+        info_lines.push(Line::from(vec![Span::styled(
+            schedule_text(schedule),
+            Style::default().fg(theme.accent),
+        )]));
+    }
+
     // Note: Task ID removed from system info as requested
 
     let info_block = Block::default()
         .title("SYSTEM INFO")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(Color::Cyan))
+        .border_style(Style::default().fg(theme.border))
         .padding(Padding::uniform(1));
 
     let info_paragraph = Paragraph::new(info_lines)
@@ -98,3 +126,23 @@ pub fn render_info_panel(f: &mut Frame, area: ratatui::layout::Rect, state: &Das
         .wrap(Wrap { trim: true });
     f.render_widget(info_paragraph, area);
 }
+
+/// Describe the current scheduled setting and when it next changes.
+fn schedule_text(schedule: &Schedule) -> String {
+    let describe = |setting: Option<_>| {
+        setting.map_or_else(
+            || "unscheduled".to_string(),
+            |setting| format!("{}", setting),
+        )
+    };
+    let current = describe(schedule.current_setting());
+    match schedule.next_change() {
+        Some((minute, next)) => format!(
+            "Schedule: {} → {} at {}",
+            current,
+            describe(next),
+            format_minute_of_day(minute)
+        ),
+        None => format!("Schedule: {}", current),
+    }
+}
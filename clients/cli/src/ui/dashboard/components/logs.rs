@@ -3,11 +3,11 @@
 //! Renders activity logs with event formatting
 
 use super::super::state::DashboardState;
-use super::super::utils::{clean_http_error_message, format_compact_timestamp, get_worker_color};
-use crate::events::EventType;
-use crate::logging::LogLevel;
+use super::super::utils::{
+    clean_http_error_message, event_marker, format_compact_timestamp, get_worker_color,
+};
 use ratatui::Frame;
-use ratatui::prelude::{Color, Style};
+use ratatui::prelude::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Borders, Padding, Paragraph, Wrap};
 
@@ -17,51 +17,63 @@ pub fn render_logs_panel(f: &mut Frame, area: ratatui::layout::Rect, state: &Das
     // Account for borders and padding (subtract 3 for top/bottom borders + padding)
     let max_logs = (area.height.saturating_sub(3)) as usize;
     let log_count = if max_logs > 0 { max_logs } else { 1 };
+    state.log_page_size.set(log_count);
+    let theme = &state.theme;
 
     let log_lines: Vec<Line> = state
-        .activity_logs
-        .iter()
-        .filter(|event| event.should_display())
+        .visible_logs()
         .rev()
+        .skip(state.scroll_offset) // Scrollback, counted from the newest event
         .take(log_count) // Show as many logs as fit in terminal
-        .map(|event| {
-            let status_icon = match (event.event_type, event.log_level) {
-                (EventType::Success, _) => "✅",
-                (EventType::Error, LogLevel::Error) => "❌",
-                (EventType::Error, LogLevel::Warn) => "",
-                (EventType::Error, _) => "❌",
-                (EventType::Refresh, _) => "",
-                (EventType::Waiting, _) => "",
-                (EventType::StateChange, _) => "", // StateChange events shouldn't be displayed, but add for completeness
-            };
+        .map(|entry| {
+            let event = &entry.event;
+            let (marker, marker_color) =
+                event_marker(theme, &event.worker, event.event_type, event.log_level);
 
-            let worker_color = get_worker_color(&event.worker);
+            let worker_color = get_worker_color(theme, &event.worker);
             let compact_time = format_compact_timestamp(&event.timestamp);
             let cleaned_msg = clean_http_error_message(&event.msg);
 
             // Don't truncate - let ratatui handle wrapping naturally
-            Line::from(vec![
-                Span::raw(format!("{} ", status_icon)),
+            let mut spans = vec![
+                Span::styled(format!("{} ", marker), Style::default().fg(marker_color)),
                 Span::styled(
                     format!("{} ", compact_time),
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 ),
                 Span::styled(cleaned_msg, Style::default().fg(worker_color)),
-            ])
+            ];
+            if entry.repeats > 1 {
+                spans.push(Span::styled(
+                    format!(" ×{}", entry.repeats),
+                    Style::default().fg(theme.muted),
+                ));
+            }
+            Line::from(spans)
         })
         .collect();
 
-    let log_paragraph = if log_lines.is_empty() {
+    let log_paragraph = if log_lines.is_empty() && state.log_filter.is_active() {
+        Paragraph::new(vec![Line::from("No events match the filter")])
+    } else if log_lines.is_empty() {
         Paragraph::new(vec![Line::from("Starting up...")])
     } else {
         Paragraph::new(log_lines)
     };
 
+    let mut title = "ACTIVITY LOG".to_string();
+    if state.log_filter.is_active() {
+        title.push_str(&format!(" [filter: {}]", state.log_filter.describe()));
+    }
+    if !state.is_following_logs() {
+        title.push_str(&format!(" ▲ {} newer · [End] live", state.scroll_offset));
+    }
+
     let logs_block = Block::default()
-        .title("ACTIVITY LOG")
+        .title(title)
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(Color::Cyan))
+        .border_style(Style::default().fg(theme.border))
         .padding(Padding::uniform(1));
 
     let log_widget = log_paragraph.block(logs_block).wrap(Wrap { trim: true });
@@ -2,51 +2,78 @@
 //!
 //! Renders system and zkVM metrics
 
+use super::super::layout::DashboardLayout;
+use super::super::nodes::NodeView;
 use super::super::state::DashboardState;
 use super::super::utils::format_compact_timestamp;
+use crate::ui::metrics::{
+    TargetPace, format_bytes, format_duration, format_point_count, format_points_estimate,
+};
+use chrono::Local;
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout};
 use ratatui::prelude::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, BorderType, Borders, Gauge, Padding, Paragraph, Wrap};
+use ratatui::widgets::{Block, BorderType, Borders, Gauge, Padding, Paragraph, Sparkline, Wrap};
+use std::collections::VecDeque;
+use std::time::Instant;
 
 /// Render enhanced metrics section with better layout.
-pub fn render_metrics_section(f: &mut Frame, area: ratatui::layout::Rect, state: &DashboardState) {
+pub fn render_metrics_section(
+    f: &mut Frame,
+    area: ratatui::layout::Rect,
+    state: &DashboardState,
+    layout: &DashboardLayout,
+) {
     let metrics_chunks = Layout::default()
-        .direction(Direction::Horizontal)
+        .direction(layout.direction())
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
-    render_system_metrics(f, metrics_chunks[0], state);
+    render_system_metrics(f, metrics_chunks[0], state, layout.sparklines);
     render_zkvm_metrics(f, metrics_chunks[1], state);
 }
 
 /// Render enhanced system metrics with better gauges.
-pub fn render_system_metrics(f: &mut Frame, area: ratatui::layout::Rect, state: &DashboardState) {
+pub fn render_system_metrics(
+    f: &mut Frame,
+    area: ratatui::layout::Rect,
+    state: &DashboardState,
+    show_history: bool,
+) {
     let metrics = &state.system_metrics;
+    let theme = &state.theme;
 
-    // Responsive gauge layout - each gauge gets equal space
+    // Responsive gauge layout - each gauge gets equal space.
+    // CPU, RAM and Peak RAM, plus GPU load and memory when available,
+    // followed by network bandwidth and, with room, a row with the CPU and
+    // RAM history sparklines.
+    let gauge_count = if metrics.has_gpu() { 5 } else { 3 };
+    let row_count = gauge_count + if show_history { 2 } else { 1 };
     let gauge_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage(33), // CPU gauge
-            Constraint::Percentage(33), // RAM gauge
-            Constraint::Percentage(34), // Peak RAM (slightly larger for rounding)
-        ])
+        .constraints(vec![Constraint::Ratio(1, row_count); row_count as usize])
         .split(area);
 
-    // CPU gauge with enhanced styling
+    // CPU gauge with enhanced styling, and the temperature where there's a sensor
+    let mut cpu_title = vec![Span::raw("CPU Usage")];
+    if let Some(celsius) = metrics.cpu_temp_celsius {
+        cpu_title.push(Span::styled(
+            format!(" {:.0}°C", celsius),
+            Style::default().fg(metrics.cpu_temp_color(state.cpu_temp_warn_celsius, theme)),
+        ));
+    }
     let cpu_gauge = Gauge::default()
         .block(
             Block::default()
-                .title("CPU Usage")
+                .title(Line::from(cpu_title))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(metrics.cpu_color())),
+                .border_style(Style::default().fg(metrics.cpu_color(theme))),
         )
         .gauge_style(
             Style::default()
-                .fg(metrics.cpu_color())
+                .fg(metrics.cpu_color(theme))
                 .add_modifier(Modifier::BOLD),
         )
         .percent((metrics.cpu_percent as u16).min(100))
@@ -59,78 +86,272 @@ pub fn render_system_metrics(f: &mut Frame, area: ratatui::layout::Rect, state:
                 .title("RAM Usage")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(metrics.ram_color())),
+                .border_style(Style::default().fg(metrics.ram_color(state.ram_thresholds, theme))),
         )
         .gauge_style(
             Style::default()
-                .fg(metrics.ram_color())
+                .fg(metrics.ram_color(state.ram_thresholds, theme))
                 .add_modifier(Modifier::BOLD),
         )
-        .percent((metrics.ram_ratio() * 100.0) as u16)
+        .percent(metrics.ram_usage_pct.round() as u16)
         .label(format!(
-            "{} / {:.1}GB",
+            "{} / {:.1}GB ({:.0}%)",
             metrics.format_ram(),
-            state.total_ram_gb
+            state.total_ram_gb,
+            metrics.ram_usage_pct
         ));
 
-    // Peak RAM gauge
+    // Peak RAM gauge, titled with the peak while proving once a proof was captured
+    let peak_title = match state.format_proving_peak_ram() {
+        Some(proving) => format!("Peak RAM ({} while proving)", proving),
+        None => "Peak RAM".to_string(),
+    };
     let peak_gauge = Gauge::default()
         .block(
             Block::default()
-                .title("Peak RAM")
+                .title(peak_title)
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(Color::LightBlue)),
+                .border_style(Style::default().fg(theme.info)),
         )
-        .gauge_style(
-            Style::default()
-                .fg(Color::LightBlue)
-                .add_modifier(Modifier::BOLD),
-        )
-        .percent((metrics.peak_ram_ratio() * 100.0) as u16)
-        .label(metrics.format_peak_ram());
+        .gauge_style(Style::default().fg(theme.info).add_modifier(Modifier::BOLD))
+        .percent(metrics.peak_ram_pct().round() as u16)
+        .label(format!(
+            "{} ({:.0}%)",
+            metrics.format_peak_ram(),
+            metrics.peak_ram_pct()
+        ));
 
     f.render_widget(cpu_gauge, gauge_chunks[0]);
     f.render_widget(ram_gauge, gauge_chunks[1]);
     f.render_widget(peak_gauge, gauge_chunks[2]);
+
+    if let Some(gpu_percent) = metrics.gpu_utilization_pct {
+        let gpu_gauge = Gauge::default()
+            .block(
+                Block::default()
+                    .title("GPU Usage")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.accent)),
+            )
+            .gauge_style(
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .percent((gpu_percent as u16).min(100))
+            .label(format!("{:.1}%", gpu_percent));
+
+        let gpu_memory_gauge = Gauge::default()
+            .block(
+                Block::default()
+                    .title("GPU Memory")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.accent)),
+            )
+            .gauge_style(
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .percent(((metrics.gpu_memory_ratio() * 100.0) as u16).min(100))
+            .label(metrics.format_gpu_memory());
+
+        f.render_widget(gpu_gauge, gauge_chunks[3]);
+        f.render_widget(gpu_memory_gauge, gauge_chunks[4]);
+    }
+
+    let network = Paragraph::new(Line::from(Span::styled(
+        metrics.format_network(),
+        Style::default().fg(theme.secondary),
+    )))
+    .block(
+        Block::default()
+            .title("Network")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.secondary)),
+    );
+    f.render_widget(network, gauge_chunks[gauge_count as usize]);
+    if !show_history {
+        return;
+    }
+
+    let history_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(gauge_chunks[gauge_count as usize + 1]);
+    f.render_widget(
+        history_sparkline("CPU History", &state.cpu_history, metrics.cpu_color(theme)),
+        history_chunks[0],
+    );
+    f.render_widget(
+        history_sparkline(
+            "RAM History",
+            &state.ram_history,
+            metrics.ram_color(state.ram_thresholds, theme),
+        ),
+        history_chunks[1],
+    );
+}
+
+/// Sparkline of percentage samples, scaled to at least 100%
+/// (CPU usage across several cores can go higher).
+fn history_sparkline<'a>(title: &'a str, history: &VecDeque<f32>, color: Color) -> Sparkline<'a> {
+    let samples: Vec<u64> = history
+        .iter()
+        .map(|&sample| sample.max(0.0).round() as u64)
+        .collect();
+    let max = samples.iter().copied().max().unwrap_or(0).max(100);
+    Sparkline::default()
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(color)),
+        )
+        .style(Style::default().fg(color))
+        .max(max)
+        .data(samples)
 }
 
 /// Render enhanced zkVM metrics panel.
 pub fn render_zkvm_metrics(f: &mut Frame, area: ratatui::layout::Rect, state: &DashboardState) {
     let metrics = &state.zkvm_metrics;
+    let theme = &state.theme;
     let mut zkvm_lines = Vec::new();
 
-    // // Points display - most prominent metric
-    // TODO: Add points display back in when we have a way to get pointsq
-    // zkvm_lines.push(Line::from(vec![
-    //     Span::styled("Points: ", Style::default().fg(Color::Gray)),
-    //     Span::styled(
-    //         metrics.format_points(),
-    //         Style::default()
-    //             .fg(Color::LightYellow)
-    //             .add_modifier(Modifier::BOLD),
-    //     ),
-    // ]));
+    // Points display - most prominent metric
+    zkvm_lines.push(Line::from(vec![
+        Span::styled("Points: ", Style::default().fg(theme.label)),
+        Span::styled(
+            metrics.format_points(),
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]));
+
+    // Per-node breakdown of the totals, when toggled on
+    if state.node_view == NodeView::PerNode {
+        for (node_id, node) in &state.node_metrics {
+            zkvm_lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  Node {}: ", node_id),
+                    Style::default().fg(theme.label),
+                ),
+                Span::styled(
+                    format!(
+                        "{} submitted, {} pts",
+                        node.tasks_submitted,
+                        format_point_count(node.points)
+                    ),
+                    Style::default().fg(theme.highlight),
+                ),
+                Span::styled(
+                    format!(
+                        " ({} fetched, {} proved, {} failed)",
+                        node.tasks_fetched, node.tasks_proved, node.failures
+                    ),
+                    Style::default().fg(theme.label),
+                ),
+            ]));
+        }
+    }
 
     // TODO: Add zkVM KHz display here, once we have a way to measure it locally.
 
-    // Tasks statistics
+    // Tasks statistics, one counter per pipeline stage
     zkvm_lines.push(Line::from(vec![
-        Span::styled("Tasks: ", Style::default().fg(Color::Gray)),
+        Span::styled("Tasks: ", Style::default().fg(theme.label)),
         Span::styled(
             format!("{}", metrics.tasks_fetched),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" fetched, ", Style::default().fg(theme.label)),
+        Span::styled(
+            format!("{}", metrics.tasks_proved),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" proved", Style::default().fg(theme.label)),
+    ]));
+
+    // Recent throughput over the selected averaging window
+    let now = Instant::now();
+    let window_label = state
+        .average_window
+        .label()
+        .map(|label| format!(" ({})", label))
+        .unwrap_or_default();
+    let tasks_per_hour = state.displayed_tasks_per_hour(now);
+    zkvm_lines.push(Line::from(vec![
+        Span::styled(
+            format!("Rate{}: ", window_label),
+            Style::default().fg(theme.label),
+        ),
+        Span::styled(
+            format!("{:.1}/h", tasks_per_hour),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(" ({:.2}/min)", tasks_per_hour / 60.0),
+            Style::default().fg(theme.label),
+        ),
+    ]));
+
+    // Progress against the daily target, if one is set
+    if let Some(daily_target) = &state.daily_target {
+        let (pace, pace_color) = match daily_target.pace(
+            Local::now().naive_local(),
+            state.throughput.tasks_per_hour(now),
+        ) {
+            TargetPace::Ahead => ("ahead", theme.success),
+            TargetPace::OnPace => ("on pace", theme.value),
+            TargetPace::Behind => ("behind", theme.warning),
+        };
+        zkvm_lines.push(Line::from(vec![
+            Span::styled("Today: ", Style::default().fg(theme.label)),
+            Span::styled(
+                format!("{} / {}", daily_target.submitted_today, daily_target.target),
+                Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(" ({:.0}%, ", daily_target.progress() * 100.0),
+                Style::default().fg(theme.label),
+            ),
+            Span::styled(pace, Style::default().fg(pace_color)),
+            Span::styled(")", Style::default().fg(theme.label)),
+        ]));
+    }
+
+    // Earnings estimate at the recent rate
+    let per_hour = state.throughput.points_per_hour(now);
+    zkvm_lines.push(Line::from(vec![
+        Span::styled("Est. Points: ", Style::default().fg(theme.label)),
+        Span::styled(
+            format!("{}/h", format_points_estimate(per_hour)),
             Style::default()
-                .fg(Color::White)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::BOLD),
         ),
+        Span::styled(
+            format!(
+                " ({}/day)",
+                format_points_estimate(per_hour.map(|points| points * 24.0))
+            ),
+            Style::default().fg(theme.label),
+        ),
     ]));
 
     zkvm_lines.push(Line::from(vec![
-        Span::styled("Completed: ", Style::default().fg(Color::Gray)),
+        Span::styled("Completed: ", Style::default().fg(theme.label)),
         Span::styled(
             format!("{} / {}", metrics.tasks_submitted, metrics.tasks_fetched),
             Style::default()
-                .fg(Color::Green)
+                .fg(theme.success)
                 .add_modifier(Modifier::BOLD),
         ),
     ]));
@@ -138,30 +359,166 @@ pub fn render_zkvm_metrics(f: &mut Frame, area: ratatui::layout::Rect, state: &D
     // Success rate with color coding
     let success_text = format!("{:.1}%", metrics.success_rate());
     zkvm_lines.push(Line::from(vec![
-        Span::styled("Success: ", Style::default().fg(Color::Gray)),
+        Span::styled("Success: ", Style::default().fg(theme.label)),
         Span::styled(
             success_text,
             Style::default()
-                .fg(metrics.success_rate_color())
+                .fg(metrics.success_rate_color(theme))
                 .add_modifier(Modifier::BOLD),
         ),
     ]));
 
+    // Tasks lost in flight point at a bug or crash, so they're always shown
+    zkvm_lines.push(Line::from(vec![
+        Span::styled("Dropped: ", Style::default().fg(theme.label)),
+        if metrics.dropped_tasks > 0 {
+            Span::styled(
+                format!("{} tasks never finished", metrics.dropped_tasks),
+                Style::default()
+                    .fg(theme.error)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            Span::styled("none", Style::default().fg(theme.success))
+        },
+    ]));
+
+    // Failure counters (only shown when something failed)
+    if metrics.proof_failures > 0 || metrics.submit_failures > 0 {
+        zkvm_lines.push(Line::from(vec![
+            Span::styled("Failures: ", Style::default().fg(theme.label)),
+            Span::styled(
+                format!(
+                    "{} proof, {} submit",
+                    metrics.proof_failures, metrics.submit_failures
+                ),
+                Style::default().fg(theme.error),
+            ),
+        ]));
+    }
+
     // Runtime information
     zkvm_lines.push(Line::from(vec![
-        Span::styled("Runtime: ", Style::default().fg(Color::Gray)),
-        Span::styled(metrics.format_runtime(), Style::default().fg(Color::Cyan)),
+        Span::styled("Runtime: ", Style::default().fg(theme.label)),
+        Span::styled(metrics.format_runtime(), Style::default().fg(theme.value)),
     ]));
 
+    // Average and recent extremes of proving time
+    if let Some(avg_secs) = state.displayed_proving_secs(now) {
+        let mut spans = vec![
+            Span::styled(
+                format!("Avg Proof{}: ", window_label),
+                Style::default().fg(theme.label),
+            ),
+            Span::styled(
+                format_duration(avg_secs.round() as u64),
+                Style::default().fg(theme.value),
+            ),
+        ];
+        if let (Some(min), Some(max)) = (state.proving_times.min(), state.proving_times.max()) {
+            spans.push(Span::styled(
+                format!(
+                    " (min {}, max {})",
+                    format_duration(min),
+                    format_duration(max)
+                ),
+                Style::default().fg(theme.label),
+            ));
+        }
+        zkvm_lines.push(Line::from(spans));
+    }
+
+    // Proving time regression, while the latest proofs are slower than the baseline
+    if let (Some(slowdown), Some(baseline)) = (
+        state.regression.slowdown_pct(),
+        state.regression.baseline_secs(),
+    ) {
+        zkvm_lines.push(Line::from(vec![
+            Span::styled("Regression: ", Style::default().fg(theme.label)),
+            Span::styled(
+                format!(
+                    "{:.0}% slower than {} baseline",
+                    slowdown,
+                    format_duration(baseline.round() as u64)
+                ),
+                Style::default()
+                    .fg(theme.error)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+
+    // Time proofs wait between generation and submission
+    if let (Some(avg), Some(max)) = (
+        state.submission_latency.average(),
+        state.submission_latency.max(),
+    ) {
+        zkvm_lines.push(Line::from(vec![
+            Span::styled("Submit Latency: ", Style::default().fg(theme.label)),
+            Span::styled(
+                format!("{:.1}s", avg.as_secs_f64()),
+                Style::default().fg(theme.value),
+            ),
+            Span::styled(
+                format!(
+                    " (max {:.1}s, {} pending)",
+                    max.as_secs_f64(),
+                    state.submission_latency.pending()
+                ),
+                Style::default().fg(theme.label),
+            ),
+        ]));
+    }
+
+    // Proof size, only once a prover has reported one
+    if let Some(avg_bytes) = metrics.avg_proof_bytes() {
+        zkvm_lines.push(Line::from(vec![
+            Span::styled("Proof Size: ", Style::default().fg(theme.label)),
+            Span::styled(format_bytes(avg_bytes), Style::default().fg(theme.value)),
+            Span::styled(
+                format!(" avg ({} total)", format_bytes(metrics.total_proof_bytes)),
+                Style::default().fg(theme.label),
+            ),
+        ]));
+    }
+
+    // Retries needed per submitted task, and by the current one so far
+    if let Some(avg) = state.retry_stats.average() {
+        let current = state
+            .current_task
+            .as_deref()
+            .and_then(|task_id| state.retry_stats.retries(task_id));
+        let mut spans = vec![
+            Span::styled("Retries/Task: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{:.1}", avg), Style::default().fg(theme.value)),
+        ];
+        if let Some(current) = current {
+            spans.push(Span::styled(
+                format!(" (current {})", current),
+                Style::default().fg(theme.label),
+            ));
+        }
+        zkvm_lines.push(Line::from(spans));
+    }
+
+    // Proofs whose timing was lost (only shown when it happens)
+    if metrics.untimed_proofs > 0 {
+        zkvm_lines.push(Line::from(vec![
+            Span::styled("Untimed: ", Style::default().fg(theme.label)),
+            Span::styled(
+                format!("{} (timing unavailable)", metrics.untimed_proofs),
+                Style::default().fg(theme.warning),
+            ),
+        ]));
+    }
+
     // Last task info
-    let status_color = match metrics.last_task_status.as_str() {
-        "Success" => Color::Green,
-        "Failed" => Color::Red,
-        _ => Color::Gray,
-    };
     zkvm_lines.push(Line::from(vec![
-        Span::styled("Last: ", Style::default().fg(Color::Gray)),
-        Span::styled(&metrics.last_task_status, Style::default().fg(status_color)),
+        Span::styled("Last: ", Style::default().fg(theme.label)),
+        Span::styled(
+            metrics.last_task_status.to_string(),
+            Style::default().fg(metrics.last_task_status.color(theme)),
+        ),
     ]));
 
     // Show timestamp of last successful submission instead of duration
@@ -171,15 +528,22 @@ pub fn render_zkvm_metrics(f: &mut Frame, area: ratatui::layout::Rect, state: &D
         "Never".to_string()
     };
     zkvm_lines.push(Line::from(vec![
-        Span::styled("Last Proof: ", Style::default().fg(Color::Gray)),
-        Span::styled(last_submission_text, Style::default().fg(Color::Yellow)),
+        Span::styled("Last Proof: ", Style::default().fg(theme.label)),
+        Span::styled(last_submission_text, Style::default().fg(theme.highlight)),
+    ]));
+    zkvm_lines.push(Line::from(vec![
+        Span::styled("Last Success: ", Style::default().fg(theme.label)),
+        Span::styled(
+            state.since_success.describe(now),
+            Style::default().fg(theme.value),
+        ),
     ]));
 
     let zkvm_block = Block::default()
         .title("zkVM STATS")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(Color::Cyan))
+        .border_style(Style::default().fg(theme.border))
         .padding(Padding::uniform(1));
 
     let zkvm_paragraph = Paragraph::new(zkvm_lines)
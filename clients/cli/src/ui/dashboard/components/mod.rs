@@ -2,8 +2,13 @@
 //!
 //! Contains all individual rendering components
 
+pub mod comparison;
+pub mod errors;
+pub mod first_proof;
 pub mod footer;
 pub mod header;
 pub mod info_panel;
 pub mod logs;
 pub mod metrics;
+pub mod provers;
+pub mod stats_table;
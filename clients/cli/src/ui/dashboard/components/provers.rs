@@ -0,0 +1,65 @@
+//! Dashboard prover breakdown component
+//!
+//! Renders one line per prover, so a stuck prover stands out
+
+use super::super::state::DashboardState;
+use crate::ui::metrics::ProverMetrics;
+use crate::ui::theme::Theme;
+use ratatui::Frame;
+use ratatui::prelude::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Paragraph};
+
+/// Height needed to show every prover seen so far (0 if none).
+pub fn provers_panel_height(state: &DashboardState) -> u16 {
+    if state.provers.is_empty() {
+        0
+    } else {
+        state.provers.len() as u16 + 2
+    }
+}
+
+/// Render the per-prover breakdown, ordered by prover index.
+pub fn render_provers_panel(f: &mut Frame, area: ratatui::layout::Rect, state: &DashboardState) {
+    let mut ids: Vec<_> = state.provers.keys().copied().collect();
+    ids.sort_unstable();
+    let theme = &state.theme;
+
+    let lines: Vec<Line> = ids
+        .into_iter()
+        .map(|id| prover_line(theme, id, &state.provers[&id]))
+        .collect();
+
+    let block = Block::default()
+        .title("PROVERS")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.prover));
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Format one prover as "P0 Proving 42s task-123 · 3 proved".
+fn prover_line(theme: &Theme, id: usize, prover: &ProverMetrics) -> Line<'static> {
+    let status_color = prover.last_status.color(theme);
+    let status = match prover.proving_since {
+        Some(since) => format!("{} {}s", prover.last_status, since.elapsed().as_secs()),
+        None => prover.last_status.to_string(),
+    };
+
+    let mut spans = vec![
+        Span::styled(format!("P{} ", id), Style::default().fg(theme.prover)),
+        Span::styled(status, Style::default().fg(status_color)),
+    ];
+    if let Some(task) = &prover.current_task {
+        spans.push(Span::styled(
+            format!(" {}", task),
+            Style::default().fg(theme.text),
+        ));
+    }
+    spans.push(Span::styled(
+        format!(" · {} proved", prover.tasks_proved),
+        Style::default().fg(theme.label),
+    ));
+    Line::from(spans)
+}
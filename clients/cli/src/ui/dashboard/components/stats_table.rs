@@ -0,0 +1,102 @@
+//! Dashboard statistics table component
+//!
+//! Renders min / avg / max / current of the rolling per-task metrics
+
+use super::super::state::DashboardState;
+use crate::ui::metrics::{RollingStats, format_duration};
+use ratatui::Frame;
+use ratatui::prelude::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Paragraph};
+
+/// One table row: label, statistics and how to format a value
+type StatsRow<'a> = (&'static str, &'a RollingStats, fn(f64) -> String);
+
+/// Rows with at least one sample, in pipeline order
+fn stats_rows(state: &DashboardState) -> Vec<StatsRow<'_>> {
+    let rows: [StatsRow<'_>; 3] = [
+        ("Proving", state.proving_times.stats(), |secs| {
+            format_duration(secs.round() as u64)
+        }),
+        ("Submit", state.submission_latency.stats(), |secs| {
+            format!("{:.1}s", secs)
+        }),
+        ("Retries", state.retry_stats.stats(), |retries| {
+            format!("{:.1}", retries)
+        }),
+    ];
+    rows.into_iter()
+        .filter(|(_, stats, _)| !stats.is_empty())
+        .collect()
+}
+
+/// Height needed for the table (0 until there is something to show).
+pub fn stats_table_height(state: &DashboardState) -> u16 {
+    match stats_rows(state).len() as u16 {
+        0 => 0,
+        rows => rows + 3,
+    }
+}
+
+/// Render the statistics table, one row per metric with samples.
+pub fn render_stats_table(f: &mut Frame, area: ratatui::layout::Rect, state: &DashboardState) {
+    let theme = &state.theme;
+    let mut lines = vec![Line::from(Span::styled(
+        format!("{:<10}{:>8}{:>8}{:>8}{:>8}", "", "Min", "Avg", "Max", "Now"),
+        Style::default()
+            .fg(theme.label)
+            .add_modifier(Modifier::BOLD),
+    ))];
+
+    for (label, stats, format_value) in stats_rows(state) {
+        let cell = |value: Option<f64>| value.map_or("-".to_string(), format_value);
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<10}", label), Style::default().fg(theme.label)),
+            Span::styled(
+                format!(
+                    "{:>8}{:>8}{:>8}",
+                    cell(stats.min()),
+                    cell(stats.mean()),
+                    cell(stats.max())
+                ),
+                Style::default().fg(theme.text),
+            ),
+            Span::styled(
+                format!("{:>8}", cell(stats.latest())),
+                Style::default().fg(theme.value),
+            ),
+        ]));
+    }
+
+    let block = Block::default()
+        .title("STATISTICS")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.accent));
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::dashboard::testing::test_state;
+
+    #[test]
+    fn test_table_grows_a_row_per_metric_with_samples() {
+        let mut state = test_state();
+        let now = state.start_time;
+        assert_eq!(stats_table_height(&state), 0);
+
+        state.proving_times.record(now, 90);
+        assert_eq!(stats_table_height(&state), 4);
+
+        state.retry_stats.submitted("task-1", Some(3), now);
+        let rows = stats_rows(&state);
+        assert_eq!(
+            rows.iter().map(|(label, ..)| *label).collect::<Vec<_>>(),
+            ["Proving", "Retries"]
+        );
+        assert_eq!((rows[1].2)(rows[1].1.mean().unwrap()), "2.0");
+    }
+}
@@ -0,0 +1,88 @@
+//! Orchestrator connectivity
+//!
+//! When the network drops, the task fetcher stops reporting and the dashboard
+//! would otherwise keep showing its last state. The fetcher reports something
+//! on every request, wait and state change, so a long silence while it should
+//! be working is shown as a lost connection. Proving (the fetcher is idle by
+//! design), a known backoff and a paused schedule don't count as silence.
+
+use super::state::DashboardState;
+use crate::events::ProverState;
+use std::time::{Duration, Instant};
+
+impl DashboardState {
+    /// Note an event from the task fetcher
+    pub(super) fn record_fetcher_activity(&mut self, at: Instant) {
+        self.last_fetcher_activity = self.last_fetcher_activity.max(at);
+    }
+
+    /// How long the task fetcher has been silent when it should have reported,
+    /// or `None` while it is active or has a reason to be quiet.
+    pub fn fetcher_silence(&self, now: Instant) -> Option<Duration> {
+        if self.current_prover_state() == ProverState::Proving {
+            return None;
+        }
+        if self.schedule_paused() {
+            return None;
+        }
+
+        // A backoff announced by the fetcher is silence it told us about
+        let expected_until = match self.waiting_start_info {
+            Some((start, wait_secs)) => self
+                .last_fetcher_activity
+                .max(start + Duration::from_secs(wait_secs)),
+            None => self.last_fetcher_activity,
+        };
+        let silence = now.saturating_duration_since(expected_until);
+        (silence >= self.stale_after)
+            .then(|| now.saturating_duration_since(self.last_fetcher_activity))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::events::{Event as WorkerEvent, EventType, ProofPhase};
+    use crate::logging::LogLevel;
+    use crate::ui::app::UIConfig;
+    use crate::ui::dashboard::testing::{emitted_at, test_state_with};
+    use std::time::{Duration, Instant};
+
+    fn fetcher_event(at: Instant, phase: ProofPhase) -> WorkerEvent {
+        let event = WorkerEvent::task_fetcher_with_level(
+            "Step 1 of 4: Fetching task...".to_string(),
+            EventType::Refresh,
+            LogLevel::Info,
+        );
+        emitted_at(event.with_phase(phase), at)
+    }
+
+    #[test]
+    fn test_silent_fetcher_shows_as_disconnected_until_it_reports() {
+        let mut state =
+            test_state_with(UIConfig::new(false, 1, false, None).with_stale_threshold(60));
+        let start = state.start_time;
+        let secs = |secs: u64| start + Duration::from_secs(secs);
+
+        state.add_event(fetcher_event(secs(0), ProofPhase::Requesting));
+        state.process_pending_events();
+        assert_eq!(state.fetcher_silence(secs(59)), None);
+        assert_eq!(
+            state.fetcher_silence(secs(61)),
+            Some(Duration::from_secs(61))
+        );
+
+        // Events resuming clear it
+        state.add_event(fetcher_event(secs(70), ProofPhase::Requesting));
+        state.process_pending_events();
+        assert_eq!(state.fetcher_silence(secs(71)), None);
+
+        // An announced backoff is expected silence
+        state.add_event(fetcher_event(
+            secs(80),
+            ProofPhase::Backoff { wait_secs: 300 },
+        ));
+        state.process_pending_events();
+        assert_eq!(state.fetcher_silence(secs(300)), None);
+        assert!(state.fetcher_silence(secs(441)).is_some());
+    }
+}
@@ -0,0 +1,90 @@
+//! Repeated event collapsing
+//!
+//! The task fetcher can report the same thing many times in a row ("rate
+//! limited", "ready for next task"). Consecutive identical events share one
+//! activity log entry with a repeat count, like syslog's "last message
+//! repeated N times". Only the log display is collapsed: every event is still
+//! processed by the metrics updaters and written to the event log.
+
+use crate::events::Event as WorkerEvent;
+
+/// An activity log line: an event and how many times in a row it occurred
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    /// The most recent occurrence
+    pub event: WorkerEvent,
+    /// Number of consecutive occurrences, at least 1
+    pub repeats: usize,
+}
+
+impl LogEntry {
+    pub fn new(event: WorkerEvent) -> Self {
+        Self { event, repeats: 1 }
+    }
+
+    /// Whether `event` says the same thing as this entry, from the same source
+    pub fn is_repeated_by(&self, event: &WorkerEvent) -> bool {
+        self.event.worker == event.worker
+            && self.event.event_type == event.event_type
+            && self.event.node_id == event.node_id
+            && self.event.msg == event.msg
+    }
+
+    /// Fold another occurrence into this entry, which then shows its timestamp
+    pub fn repeat(&mut self, event: WorkerEvent) {
+        self.event = event;
+        self.repeats += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::events::{Event as WorkerEvent, EventType, ProofPhase};
+    use crate::logging::LogLevel;
+    use crate::ui::dashboard::testing::test_state;
+    use std::time::Instant;
+
+    fn rate_limited(timestamp: &str) -> WorkerEvent {
+        let mut event = WorkerEvent::task_fetcher_with_level(
+            "Rate limited - retrying in 30s".to_string(),
+            EventType::Error,
+            LogLevel::Warn,
+        )
+        .with_phase(ProofPhase::Backoff { wait_secs: 30 });
+        event.timestamp = timestamp.to_string();
+        event
+    }
+
+    #[test]
+    fn test_burst_of_rate_limits_collapses_into_one_entry() {
+        let mut state = test_state();
+
+        let mut last_emitted_at = Instant::now();
+        for second in 0..12 {
+            let event = rate_limited(&format!("2025-01-01 12:00:{:02}", second));
+            last_emitted_at = event.emitted_at;
+            state.add_event(event);
+        }
+        state.update();
+
+        assert_eq!(state.activity_logs.len(), 1);
+        let entry = &state.activity_logs[0];
+        assert_eq!(entry.repeats, 12);
+        assert_eq!(entry.event.timestamp, "2025-01-01 12:00:11");
+        // Each event was still processed: the latest one started the wait
+        assert_eq!(
+            state.waiting_start_info.map(|(start, _)| start),
+            Some(last_emitted_at)
+        );
+
+        // A different message ends the run, and the next burst starts afresh
+        state.add_to_activity_log(WorkerEvent::task_fetcher_with_level(
+            "Step 1 of 4: Requesting task...".to_string(),
+            EventType::Refresh,
+            LogLevel::Info,
+        ));
+        state.add_to_activity_log(rate_limited("2025-01-01 12:01:00"));
+        let repeats: Vec<usize> = state.activity_logs.iter().map(|e| e.repeats).collect();
+        assert_eq!(repeats, [12, 1, 1]);
+    }
+}
@@ -0,0 +1,294 @@
+//! State dump for bug reports
+//!
+//! Writes a point-in-time JSON copy of the dashboard to attach to an issue:
+//! the status API snapshot, the settings in effect and the recent activity
+//! log. URLs in event messages are cut down to their host, since webhook URLs
+//! usually carry a token, and node IDs can be redacted too. Unlike the status
+//! API, the dump is written once, on request ([D] key) or at exit.
+
+use super::snapshot::DashboardSnapshot;
+use super::state::DashboardState;
+use super::toast::Toast;
+use crate::consts::cli_consts::STATE_DUMP_EVENTS;
+use crate::events::Event as WorkerEvent;
+use chrono::Local;
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// Stands in for a redacted node ID
+const REDACTED: &str = "<redacted>";
+
+/// Everything written to a state dump
+#[derive(Debug, Clone, Serialize)]
+pub struct StateDump {
+    /// CLI version that wrote the dump
+    pub version: String,
+    /// Local time the dump was taken
+    pub captured_at: String,
+    /// Whether node IDs were redacted
+    pub node_ids_redacted: bool,
+    pub snapshot: DashboardSnapshot,
+    pub settings: DumpSettings,
+    /// Most recent activity log events, oldest first
+    pub recent_events: Vec<WorkerEvent>,
+}
+
+/// Dashboard settings in effect. Outputs are only recorded as enabled or not,
+/// so no paths or URLs end up in the dump.
+#[derive(Debug, Clone, Serialize)]
+pub struct DumpSettings {
+    pub num_threads: usize,
+    pub points_per_task: u64,
+    pub fetch_timeout_secs: u64,
+    pub stuck_threshold_secs: u64,
+    pub stale_after_secs: u64,
+    pub ram_warn_pct: f32,
+    pub ram_critical_pct: f32,
+    pub cpu_temp_warn_celsius: f32,
+    pub metrics_smoothing: Option<f32>,
+    pub paused: bool,
+    pub event_log: bool,
+    pub history: bool,
+    pub task_csv: bool,
+    pub syslog: bool,
+    pub webhook: bool,
+    pub failure_notifier: bool,
+}
+
+impl DashboardState {
+    /// Copy the current state into a dump, sanitized for sharing
+    pub fn state_dump(&self, now: Instant) -> StateDump {
+        let mut snapshot = self.dashboard_snapshot(now);
+        let mut node_ids: Vec<u64> = self.node_metrics.keys().copied().collect();
+        node_ids.extend(self.node_id);
+        if self.redact_node_ids {
+            snapshot.node_id = None;
+        }
+
+        let skip = self.activity_logs.len().saturating_sub(STATE_DUMP_EVENTS);
+        let recent_events = self
+            .activity_logs
+            .iter()
+            .skip(skip)
+            .map(|entry| {
+                let mut event = entry.event.clone();
+                event.msg = shorten_urls(&event.msg);
+                if self.redact_node_ids {
+                    node_ids.extend(event.node_id.take());
+                    for id in &node_ids {
+                        event.msg = redact_number(&event.msg, *id);
+                    }
+                }
+                event
+            })
+            .collect();
+
+        StateDump {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            captured_at: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            node_ids_redacted: self.redact_node_ids,
+            snapshot,
+            settings: DumpSettings {
+                num_threads: self.num_threads,
+                points_per_task: self.points_per_task,
+                fetch_timeout_secs: self.fetch_timeout_secs,
+                stuck_threshold_secs: self.stuck_threshold_secs,
+                stale_after_secs: self.stale_after.as_secs(),
+                ram_warn_pct: self.ram_thresholds.warn_pct,
+                ram_critical_pct: self.ram_thresholds.critical_pct,
+                cpu_temp_warn_celsius: self.cpu_temp_warn_celsius,
+                metrics_smoothing: self.metrics_smoothing,
+                paused: self.paused,
+                event_log: self.event_log.is_some(),
+                history: self.history.is_some(),
+                task_csv: self.task_csv.is_some(),
+                syslog: self.syslog.is_some(),
+                webhook: self.webhook.is_some(),
+                failure_notifier: self.failure_notifier.is_some(),
+            },
+            recent_events,
+        }
+    }
+
+    /// Write a state dump to `path` as pretty-printed JSON
+    pub fn write_state_dump(&self, path: &Path, now: Instant) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.state_dump(now)).map_err(io::Error::other)?;
+        fs::write(path, json + "\n")
+    }
+
+    /// Write a state dump ([D] key) to the `--dump-state` path, or a
+    /// timestamped file in the working directory, and show where it went.
+    pub fn dump_state(&mut self, now: Instant) {
+        let path = self.dump_path.clone().unwrap_or_else(|| {
+            PathBuf::from(
+                Local::now()
+                    .format("nexus-state-%Y%m%d-%H%M%S.json")
+                    .to_string(),
+            )
+        });
+        let message = match self.write_state_dump(&path, now) {
+            Ok(()) => format!("State written to {}", path.display()),
+            Err(e) => format!("Failed to write state to {}: {}", path.display(), e),
+        };
+        // The path stays up until the next key press, so it can be copied
+        self.toast = Some(Toast {
+            message,
+            subtle: false,
+            shown_at: None,
+        });
+    }
+
+    /// Write the state dump requested with `--dump-state`, if any, returning
+    /// its path and whether it was written.
+    pub fn dump_state_on_exit(&self) -> Option<(PathBuf, io::Result<()>)> {
+        let path = self.dump_path.clone()?;
+        let written = self.write_state_dump(&path, Instant::now());
+        Some((path, written))
+    }
+}
+
+/// Cut URLs in `msg` down to their scheme and host, dropping credentials,
+/// paths and queries
+fn shorten_urls(msg: &str) -> String {
+    let mut shortened = String::with_capacity(msg.len());
+    let mut rest = msg;
+    while let Some(start) = ["http://", "https://"]
+        .iter()
+        .filter_map(|scheme| rest.find(scheme))
+        .min()
+    {
+        let (before, url) = rest.split_at(start);
+        shortened.push_str(before);
+        let end = url
+            .find(|c: char| c.is_whitespace() || c == ')')
+            .unwrap_or(url.len());
+        let (url, after) = url.split_at(end);
+        let host_start = url.find("://").map_or(0, |i| i + 3);
+        let host_end = url[host_start..]
+            .find(['/', '?', '#'])
+            .map_or(url.len(), |i| host_start + i);
+        let host = &url[host_start..host_end];
+        let host = host.rsplit_once('@').map_or(host, |(_, host)| host);
+        shortened.push_str(&url[..host_start]);
+        shortened.push_str(host);
+        if host_end < url.len() {
+            shortened.push_str("/...");
+        }
+        rest = after;
+    }
+    shortened.push_str(rest);
+    shortened
+}
+
+/// Replace `id` in `msg` where it appears as a whole number
+fn redact_number(msg: &str, id: u64) -> String {
+    let id = id.to_string();
+    let is_digit = |c: char| c.is_ascii_digit();
+    let mut redacted = String::with_capacity(msg.len());
+    let mut copied = 0;
+    for (start, _) in msg.match_indices(&id) {
+        let end = start + id.len();
+        if msg[..start].ends_with(is_digit) || msg[end..].starts_with(is_digit) {
+            continue;
+        }
+        redacted.push_str(&msg[copied..start]);
+        redacted.push_str(REDACTED);
+        copied = end;
+    }
+    redacted.push_str(&msg[copied..]);
+    redacted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventType;
+    use crate::logging::LogLevel;
+    use crate::ui::app::UIConfig;
+    use crate::ui::dashboard::testing::{log_event, test_state, test_state_with};
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_urls_are_cut_down_to_their_host() {
+        assert_eq!(
+            shorten_urls(
+                "Webhook delivery failed: error sending request for url (https://user:pw@hooks.example.com/T0/B1/secret?x=1)"
+            ),
+            "Webhook delivery failed: error sending request for url (https://hooks.example.com/...)"
+        );
+        assert_eq!(
+            shorten_urls("http://localhost:9091 and https://a.io/x"),
+            "http://localhost:9091 and https://a.io/..."
+        );
+        assert_eq!(shorten_urls("no links here"), "no links here");
+    }
+
+    #[test]
+    fn test_node_ids_are_redacted_only_as_whole_numbers() {
+        assert_eq!(
+            redact_number("node 123456 (task 1234567, 123456)", 123456),
+            "node <redacted> (task 1234567, <redacted>)"
+        );
+        assert_eq!(
+            redact_number("123456123456 and 123456", 123456),
+            "123456123456 and <redacted>"
+        );
+    }
+
+    #[test]
+    fn test_dump_redacts_node_ids_when_asked() {
+        let mut state =
+            test_state_with(UIConfig::new(false, 1, false, None).with_state_dump(None, true));
+        state.node_id = Some(123456);
+        state.add_to_activity_log(WorkerEvent::task_fetcher_with_level(
+            "Registered node 123456".to_string(),
+            EventType::Success,
+            LogLevel::Info,
+        ));
+
+        let dump = state.state_dump(Instant::now());
+        assert!(dump.node_ids_redacted);
+        assert_eq!(dump.snapshot.node_id, None);
+        assert_eq!(dump.recent_events[0].msg, "Registered node <redacted>");
+
+        state.redact_node_ids = false;
+        let dump = state.state_dump(Instant::now());
+        assert_eq!(dump.snapshot.node_id, Some(123456));
+        assert_eq!(dump.recent_events[0].msg, "Registered node 123456");
+    }
+
+    #[test]
+    fn test_dump_keeps_only_the_latest_events() {
+        let mut state = test_state();
+        for i in 0..STATE_DUMP_EVENTS + 5 {
+            state.add_to_activity_log(log_event(i));
+        }
+        let dump = state.state_dump(Instant::now());
+        assert_eq!(dump.recent_events.len(), STATE_DUMP_EVENTS);
+        assert_eq!(dump.recent_events[0].msg, "event 5");
+    }
+
+    #[test]
+    fn test_dump_is_written_on_key_and_at_exit() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        let mut state = test_state_with(
+            UIConfig::new(false, 1, false, None).with_state_dump(Some(path.clone()), false),
+        );
+
+        state.dump_state(Instant::now());
+        assert!(path.exists());
+        assert_eq!(
+            state.visible_toast().map(|toast| toast.message.clone()),
+            Some(format!("State written to {}", path.display()))
+        );
+
+        fs::remove_file(&path).unwrap();
+        let (written_to, written) = state.dump_state_on_exit().unwrap();
+        assert_eq!(written_to, path);
+        assert!(written.is_ok() && path.exists());
+    }
+}
@@ -0,0 +1,178 @@
+//! Last errors
+//!
+//! Keeps the most recent error of each worker type, so a failure reason stays
+//! on screen after its log line has scrolled away, and a fetch error doesn't
+//! hide the last prove error. Also counts the session's failures for the
+//! header badge, which flashes for a moment whenever the count goes up.
+
+use super::state::DashboardState;
+use crate::consts::cli_consts::FAILURE_BADGE_FLASH_SECS;
+use crate::events::{Event as WorkerEvent, EventType, Worker};
+use std::time::{Duration, Instant};
+
+/// Most recent error event per worker type
+#[derive(Debug, Clone, Default)]
+pub struct LastErrors {
+    pub fetcher: Option<WorkerEvent>,
+    pub prover: Option<WorkerEvent>,
+    pub submitter: Option<WorkerEvent>,
+    pub dashboard: Option<WorkerEvent>,
+}
+
+impl LastErrors {
+    /// Remember `event` if it is an error, replacing its worker type's previous one
+    pub fn record(&mut self, event: &WorkerEvent) {
+        if event.event_type != EventType::Error {
+            return;
+        }
+        let slot = match event.worker {
+            Worker::TaskFetcher => &mut self.fetcher,
+            Worker::Prover(_) => &mut self.prover,
+            Worker::ProofSubmitter => &mut self.submitter,
+            Worker::Dashboard => &mut self.dashboard,
+        };
+        *slot = Some(event.clone());
+    }
+
+    /// The remembered errors, in pipeline order, then the dashboard's own
+    pub fn iter(&self) -> impl Iterator<Item = &WorkerEvent> {
+        [
+            &self.fetcher,
+            &self.prover,
+            &self.submitter,
+            &self.dashboard,
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+}
+
+/// Failure count shown in the header
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FailureBadge {
+    count: usize,
+    /// When the count last went up
+    raised_at: Option<Instant>,
+}
+
+impl FailureBadge {
+    /// Show `count`, flashing if it went up
+    pub fn update(&mut self, count: usize, now: Instant) {
+        if count > self.count {
+            self.raised_at = Some(now);
+        }
+        self.count = count;
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Whether the count went up recently enough to draw attention to it
+    pub fn is_flashing(&self, now: Instant) -> bool {
+        self.raised_at.is_some_and(|at| {
+            now.saturating_duration_since(at) < Duration::from_secs(FAILURE_BADGE_FLASH_SECS)
+        })
+    }
+}
+
+impl DashboardState {
+    /// Forget the remembered errors ([X] key)
+    pub fn clear_last_errors(&mut self) {
+        self.last_errors = LastErrors::default();
+    }
+
+    /// Failed and dropped tasks since startup. The failure counters are
+    /// lifetime totals, so earlier sessions' share is left out.
+    pub fn session_failures(&self) -> usize {
+        let metrics = &self.zkvm_metrics;
+        let start = &self.stats_at_start;
+        metrics.proof_failures.saturating_sub(start.proof_failures)
+            + metrics
+                .submit_failures
+                .saturating_sub(start.submit_failures)
+            + metrics.dropped_tasks
+    }
+
+    /// Bring the header's failure badge up to date
+    pub(super) fn update_failure_badge(&mut self, now: Instant) {
+        let count = self.session_failures();
+        self.failure_badge.update(count, now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::consts::cli_consts::FAILURE_BADGE_FLASH_SECS;
+    use crate::events::{Event as WorkerEvent, EventType, Worker};
+    use crate::logging::LogLevel;
+    use crate::ui::dashboard::testing::test_state;
+    use std::time::Duration;
+
+    #[test]
+    fn test_last_errors_are_kept_per_worker_type_until_cleared() {
+        let mut state = test_state();
+        let prove_error = WorkerEvent::prover_with_level(
+            0,
+            "Proof generation failed for task a: out of memory".to_string(),
+            EventType::Error,
+            LogLevel::Error,
+        );
+        state.add_event(prove_error.clone());
+        state.add_event(WorkerEvent::task_fetcher_with_level(
+            "Failed to fetch task: timeout".to_string(),
+            EventType::Error,
+            LogLevel::Error,
+        ));
+        // Non-errors don't replace anything
+        state.add_event(WorkerEvent::prover_with_level(
+            0,
+            "Step 3 of 4: Proof generated for task b".to_string(),
+            EventType::Success,
+            LogLevel::Info,
+        ));
+        state.process_pending_events();
+
+        // The later fetch error doesn't hide the prove error
+        assert_eq!(state.last_errors.prover.as_ref(), Some(&prove_error));
+        let workers: Vec<_> = state.last_errors.iter().map(|e| e.worker).collect();
+        assert_eq!(workers, [Worker::TaskFetcher, Worker::Prover(0)]);
+
+        state.clear_last_errors();
+        assert!(state.last_errors.is_empty());
+    }
+
+    #[test]
+    fn test_failure_badge_counts_this_session_and_flashes_on_increase() {
+        let mut state = test_state();
+        let start = state.start_time;
+        // Failures from earlier sessions don't count
+        state.stats_at_start.proof_failures = 4;
+        state.zkvm_metrics.proof_failures = 4;
+        state.update_failure_badge(start);
+        assert_eq!(state.failure_badge.count(), 0);
+        assert!(!state.failure_badge.is_flashing(start));
+
+        state.zkvm_metrics.proof_failures = 5;
+        state.zkvm_metrics.dropped_tasks = 1;
+        state.update_failure_badge(start);
+        assert_eq!(state.failure_badge.count(), 2);
+        assert!(
+            state
+                .failure_badge
+                .is_flashing(start + Duration::from_secs(1))
+        );
+        let settled = start + Duration::from_secs(FAILURE_BADGE_FLASH_SECS);
+        assert!(!state.failure_badge.is_flashing(settled));
+
+        // Going back down (e.g. a late task turning up) doesn't flash
+        state.zkvm_metrics.dropped_tasks = 0;
+        state.update_failure_badge(settled);
+        assert_eq!(state.failure_badge.count(), 1);
+        assert!(!state.failure_badge.is_flashing(settled));
+    }
+}
@@ -0,0 +1,173 @@
+//! Activity log filtering
+//!
+//! Narrows the log panel to one kind of worker and/or to errors only. Filtering
+//! hides events from view; every retained event stays in the activity log.
+
+use super::state::DashboardState;
+use crate::events::{Event as WorkerEvent, EventType, Worker};
+
+/// Which workers' events the log panel shows
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum WorkerFilter {
+    /// Every worker
+    #[default]
+    All,
+    /// Only the task fetcher
+    Fetcher,
+    /// Only provers (any index)
+    Prover,
+    /// Only the proof submitter
+    Submitter,
+}
+
+impl WorkerFilter {
+    /// The next filter in the [W] key's cycle
+    pub fn next(self) -> Self {
+        match self {
+            WorkerFilter::All => WorkerFilter::Fetcher,
+            WorkerFilter::Fetcher => WorkerFilter::Prover,
+            WorkerFilter::Prover => WorkerFilter::Submitter,
+            WorkerFilter::Submitter => WorkerFilter::All,
+        }
+    }
+
+    /// Whether events from this worker pass the filter
+    pub fn matches(self, worker: Worker) -> bool {
+        match self {
+            WorkerFilter::All => true,
+            WorkerFilter::Fetcher => worker == Worker::TaskFetcher,
+            WorkerFilter::Prover => matches!(worker, Worker::Prover(_)),
+            WorkerFilter::Submitter => worker == Worker::ProofSubmitter,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            WorkerFilter::All => "all",
+            WorkerFilter::Fetcher => "fetcher",
+            WorkerFilter::Prover => "prover",
+            WorkerFilter::Submitter => "submitter",
+        }
+    }
+}
+
+/// Active activity log filter
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct LogFilter {
+    /// Which workers to show
+    pub worker: WorkerFilter,
+    /// Show only error events
+    pub errors_only: bool,
+}
+
+impl LogFilter {
+    /// Whether the event passes the filter
+    pub fn matches(&self, event: &WorkerEvent) -> bool {
+        self.worker.matches(event.worker)
+            && (!self.errors_only || event.event_type == EventType::Error)
+    }
+
+    /// Whether anything is being hidden
+    pub fn is_active(&self) -> bool {
+        *self != LogFilter::default()
+    }
+
+    /// Short description for the log panel title, e.g. "submitter, errors"
+    pub fn describe(&self) -> String {
+        match (self.worker, self.errors_only) {
+            (WorkerFilter::All, true) => "errors".to_string(),
+            (worker, true) => format!("{}, errors", worker.label()),
+            (worker, false) => worker.label().to_string(),
+        }
+    }
+}
+
+impl DashboardState {
+    /// Show the next worker's events (all → fetcher → prover → submitter → all)
+    pub fn cycle_worker_filter(&mut self) {
+        self.log_filter.worker = self.log_filter.worker.next();
+        self.scroll_logs_to_tail();
+    }
+
+    /// Toggle showing only errors
+    pub fn toggle_errors_only(&mut self) {
+        self.log_filter.errors_only = !self.log_filter.errors_only;
+        self.scroll_logs_to_tail();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logging::LogLevel;
+    use crate::ui::dashboard::testing::test_state;
+    use crossterm::event::KeyCode;
+
+    fn state_with_mixed_logs() -> DashboardState {
+        let mut state = test_state();
+        state.add_to_activity_log(WorkerEvent::task_fetcher_with_level(
+            "fetching".to_string(),
+            EventType::Refresh,
+            LogLevel::Info,
+        ));
+        state.add_to_activity_log(WorkerEvent::prover_with_level(
+            1,
+            "proof failed".to_string(),
+            EventType::Error,
+            LogLevel::Error,
+        ));
+        state.add_to_activity_log(WorkerEvent::proof_submitter_with_level(
+            "submitted".to_string(),
+            EventType::Success,
+            LogLevel::Info,
+        ));
+        state.add_to_activity_log(WorkerEvent::proof_submitter_with_level(
+            "submit failed".to_string(),
+            EventType::Error,
+            LogLevel::Error,
+        ));
+        state
+    }
+
+    fn visible_messages(state: &DashboardState) -> Vec<&str> {
+        state
+            .visible_logs()
+            .map(|entry| entry.event.msg.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_worker_filter_cycles_and_hides_others() {
+        let mut state = state_with_mixed_logs();
+        assert_eq!(visible_messages(&state).len(), 4);
+
+        state.handle_key(KeyCode::Char('w'));
+        assert_eq!(visible_messages(&state), ["fetching"]);
+        state.handle_key(KeyCode::Char('w'));
+        assert_eq!(visible_messages(&state), ["proof failed"]);
+        state.handle_key(KeyCode::Char('w'));
+        assert_eq!(visible_messages(&state), ["submitted", "submit failed"]);
+        assert_eq!(state.log_filter.describe(), "submitter");
+        state.handle_key(KeyCode::Char('w'));
+        assert!(!state.log_filter.is_active());
+
+        // Hidden events were never dropped
+        assert_eq!(state.activity_logs.len(), 4);
+    }
+
+    #[test]
+    fn test_errors_only_combines_with_worker_filter() {
+        let mut state = state_with_mixed_logs();
+
+        state.handle_key(KeyCode::Char('e'));
+        assert_eq!(visible_messages(&state), ["proof failed", "submit failed"]);
+        assert_eq!(state.log_filter.describe(), "errors");
+
+        state.log_filter.worker = WorkerFilter::Submitter;
+        assert_eq!(visible_messages(&state), ["submit failed"]);
+        assert_eq!(state.log_filter.describe(), "submitter, errors");
+
+        state.handle_key(KeyCode::Char('e'));
+        assert_eq!(visible_messages(&state), ["submitted", "submit failed"]);
+    }
+}
@@ -0,0 +1,104 @@
+//! Dashboard keyboard input handling
+//!
+//! Maps key presses on the dashboard screen to state changes
+
+use super::state::DashboardState;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::time::Instant;
+
+/// Whether a key press asks to quit: Esc, `q`, or Ctrl-C, which arrives as a
+/// key press rather than a signal while the terminal is in raw mode
+pub fn is_quit_key(key: &KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => true,
+        KeyCode::Char('c') | KeyCode::Char('C') => key.modifiers.contains(KeyModifiers::CONTROL),
+        _ => false,
+    }
+}
+
+impl DashboardState {
+    /// Handle a key event while the dashboard is shown. Chords with Ctrl or
+    /// Alt aren't dashboard shortcuts, so they never trigger the bare key's
+    /// binding.
+    pub fn handle_key_event(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL)
+            || key.modifiers.contains(KeyModifiers::ALT)
+        {
+            return;
+        }
+        self.handle_key(key.code);
+    }
+
+    /// Handle a key press while the dashboard is shown.
+    pub fn handle_key(&mut self, code: KeyCode) {
+        // Any key dismisses the first-proof confirmation
+        self.first_proof_at = None;
+        self.toast = None;
+        // Show the key's effect even while paused
+        self.request_redraw();
+
+        let page = self.log_page_size.get().max(1);
+        match code {
+            KeyCode::PageUp => self.scroll_logs_up(page),
+            KeyCode::PageDown => self.scroll_logs_down(page),
+            KeyCode::Home => self.scroll_logs_to_oldest(),
+            KeyCode::End => self.scroll_logs_to_tail(),
+            KeyCode::Char(' ') | KeyCode::Char('p') | KeyCode::Char('P') => self.toggle_pause(),
+            other => {
+                // Any other key resumes the live log, unless the display is paused
+                if !self.paused {
+                    self.scroll_logs_to_tail();
+                }
+                match other {
+                    KeyCode::Char('m') => self.set_mark(),
+                    KeyCode::Char('M') => self.clear_mark(),
+                    KeyCode::Char('w') | KeyCode::Char('W') => self.cycle_worker_filter(),
+                    KeyCode::Char('e') | KeyCode::Char('E') => self.toggle_errors_only(),
+                    KeyCode::Char('n') | KeyCode::Char('N') => self.toggle_node_view(),
+                    KeyCode::Char('x') | KeyCode::Char('X') => self.clear_last_errors(),
+                    KeyCode::Char('c') | KeyCode::Char('C') => self.clear_activity_log(),
+                    KeyCode::Char('y') | KeyCode::Char('Y') => self.copy_current_task(),
+                    KeyCode::Char('a') | KeyCode::Char('A') => self.cycle_average_window(),
+                    KeyCode::Char('r') | KeyCode::Char('R') => self.reset_peak_ram(Instant::now()),
+                    KeyCode::Char('d') | KeyCode::Char('D') => self.dump_state(Instant::now()),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{Event as WorkerEvent, EventType};
+    use crate::logging::LogLevel;
+    use crate::ui::dashboard::testing::test_state;
+
+    #[test]
+    fn test_ctrl_c_quits_instead_of_clearing_the_log() {
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert!(is_quit_key(&ctrl_c));
+        assert!(is_quit_key(&KeyEvent::new(
+            KeyCode::Esc,
+            KeyModifiers::NONE
+        )));
+        assert!(!is_quit_key(&KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::NONE
+        )));
+
+        let mut state = test_state();
+        state.add_event(WorkerEvent::task_fetcher_with_level(
+            "Fetching tasks".to_string(),
+            EventType::Refresh,
+            LogLevel::Info,
+        ));
+        state.process_pending_events();
+
+        state.handle_key_event(ctrl_c);
+        assert_eq!(state.activity_logs.len(), 1);
+        state.handle_key_event(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE));
+        assert!(state.activity_logs.is_empty());
+    }
+}
@@ -0,0 +1,87 @@
+//! Responsive dashboard layout
+//!
+//! Picks an arrangement for the terminal's size: panels side by side on wide
+//! terminals and stacked on narrow ones, with the lowest-priority panels left
+//! out when there aren't enough rows for them. Anything that still doesn't fit
+//! is clipped by ratatui rather than overflowing.
+
+use crate::consts::cli_consts::layout::{
+    COLUMNS_MIN_WIDTH, SIDE_PANELS_MIN_HEIGHT, SPARKLINES_MIN_HEIGHT,
+};
+use ratatui::layout::{Direction, Rect};
+
+/// How the dashboard is arranged for one terminal size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DashboardLayout {
+    /// Info panel beside the activity log and system metrics beside the zkVM
+    /// metrics; otherwise each pair is stacked
+    pub columns: bool,
+    /// Whether the comparison, prover and error panels are shown
+    pub side_panels: bool,
+    /// Whether the CPU and RAM history sparklines are shown
+    pub sparklines: bool,
+}
+
+impl DashboardLayout {
+    /// The layout for a terminal of this size
+    pub fn for_area(area: Rect) -> Self {
+        Self {
+            columns: area.width >= COLUMNS_MIN_WIDTH,
+            side_panels: area.height >= SIDE_PANELS_MIN_HEIGHT,
+            sparklines: area.height >= SPARKLINES_MIN_HEIGHT,
+        }
+    }
+
+    /// Direction paired panels are split in
+    pub fn direction(&self) -> Direction {
+        if self.columns {
+            Direction::Horizontal
+        } else {
+            Direction::Vertical
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(width: u16, height: u16) -> DashboardLayout {
+        DashboardLayout::for_area(Rect {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        })
+    }
+
+    #[test]
+    fn test_layout_breakpoints() {
+        assert_eq!(
+            layout(160, 50),
+            DashboardLayout {
+                columns: true,
+                side_panels: true,
+                sparklines: true,
+            }
+        );
+
+        // A narrow SSH window stacks, keeping everything while there's height
+        let narrow = layout(80, 50);
+        assert!(!narrow.columns && narrow.side_panels && narrow.sparklines);
+        assert_eq!(narrow.direction(), Direction::Vertical);
+
+        // Sparklines go first, then the optional side panels
+        assert!(!layout(160, 35).sparklines && layout(160, 35).side_panels);
+        assert!(!layout(160, 24).side_panels);
+
+        assert_eq!(
+            layout(1, 1),
+            DashboardLayout {
+                columns: false,
+                side_panels: false,
+                sparklines: false,
+            }
+        );
+    }
+}
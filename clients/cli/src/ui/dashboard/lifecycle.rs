@@ -0,0 +1,465 @@
+//! Task lifecycle tracking
+//!
+//! Follows each task ID through the pipeline (fetched → proving → proved →
+//! submitted, or one of the failure states) as a small state machine driven by
+//! events. Repeats of a stage are recognized so they aren't counted twice, and
+//! out-of-order transitions (e.g. submitted before proved) are reported rather
+//! than silently folded into the metrics. A task left in flight with no news
+//! for too long is marked dropped: something lost it between workers.
+
+use super::state::DashboardState;
+use crate::consts::cli_consts::TASK_DROP_TIMEOUT_SECS;
+use crate::events::{Event as WorkerEvent, EventType, ProofPhase, Worker};
+use crate::logging::LogLevel;
+use crate::ui::metrics::{TaskStatus, push_bounded};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Where a task is in the pipeline
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TaskStage {
+    /// Obtained from the orchestrator
+    Fetched,
+    /// Being proved
+    Proving,
+    /// Proof generated, awaiting submission
+    Proved,
+    /// Proof accepted by the orchestrator
+    Submitted,
+    /// Proving failed
+    ProofFailed,
+    /// Submission failed (after the submitter's own retries)
+    SubmitFailed,
+    /// In flight with no further event for too long
+    Dropped,
+}
+
+impl TaskStage {
+    /// The stage a pipeline phase moves its task to
+    pub fn from_phase(phase: &ProofPhase) -> Option<Self> {
+        match phase {
+            ProofPhase::Requesting | ProofPhase::Backoff { .. } => None,
+            ProofPhase::Fetched { .. } => Some(TaskStage::Fetched),
+            ProofPhase::Proving { .. } => Some(TaskStage::Proving),
+            ProofPhase::ProofGenerated { .. } => Some(TaskStage::Proved),
+            ProofPhase::Submitted { .. } => Some(TaskStage::Submitted),
+            ProofPhase::ProofFailed { .. } => Some(TaskStage::ProofFailed),
+            ProofPhase::SubmitFailed { .. } => Some(TaskStage::SubmitFailed),
+        }
+    }
+
+    /// The failure stage for an error reported by `worker`
+    pub fn failed_by(worker: Worker) -> Option<Self> {
+        match worker {
+            Worker::TaskFetcher | Worker::Dashboard => None,
+            Worker::Prover(_) => Some(TaskStage::ProofFailed),
+            Worker::ProofSubmitter => Some(TaskStage::SubmitFailed),
+        }
+    }
+
+    /// Whether the task's lifecycle has ended
+    pub fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            TaskStage::Submitted
+                | TaskStage::ProofFailed
+                | TaskStage::SubmitFailed
+                | TaskStage::Dropped
+        )
+    }
+
+    /// Whether a task in this stage may move on to `next`
+    fn can_advance_to(self, next: TaskStage) -> bool {
+        use TaskStage::*;
+        match (self, next) {
+            (Fetched, Proving | ProofFailed) => true,
+            (Proving, Proved | ProofFailed) => true,
+            (Proved, Submitted | SubmitFailed) => true,
+            // A finished task handed out again starts over
+            (stage, Fetched) => stage.is_terminal(),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for TaskStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            TaskStage::Fetched => "fetched",
+            TaskStage::Proving => "proving",
+            TaskStage::Proved => "proved",
+            TaskStage::Submitted => "submitted",
+            TaskStage::ProofFailed => "proof failed",
+            TaskStage::SubmitFailed => "submit failed",
+            TaskStage::Dropped => "dropped",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Outcome of moving a task to a stage
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Transition {
+    /// The task entered the stage (including tasks first seen mid-pipeline,
+    /// e.g. when the dashboard attached after the fetch)
+    Entered,
+    /// The task was already in the stage; a repeated event
+    Repeated,
+    /// The stage doesn't follow from the task's previous one. The task is
+    /// moved anyway, since the event did happen.
+    Illegal { from: TaskStage },
+}
+
+/// A tracked task's stage and the last event that moved it
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TrackedTask {
+    pub stage: TaskStage,
+    /// Worker that reported the stage
+    pub worker: Worker,
+    /// When the stage was reported
+    pub updated_at: Instant,
+}
+
+/// Lifecycle stage of recently seen tasks.
+///
+/// Bounded so a long-running dashboard doesn't grow without limit; the oldest
+/// task is forgotten first.
+#[derive(Debug, Clone)]
+pub struct TaskLifecycles {
+    /// Maximum number of tasks tracked.
+    capacity: usize,
+    /// Current stage of each tracked task.
+    stages: HashMap<String, TrackedTask>,
+    /// Tracked task IDs, oldest first.
+    order: VecDeque<String>,
+}
+
+impl TaskLifecycles {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            stages: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Move `task_id` to `next` as reported by `worker` at `at`, reporting how
+    /// that relates to its previous stage
+    pub fn advance(
+        &mut self,
+        task_id: &str,
+        next: TaskStage,
+        worker: Worker,
+        at: Instant,
+    ) -> Transition {
+        let tracked = TrackedTask {
+            stage: next,
+            worker,
+            updated_at: at,
+        };
+        let Some(task) = self.stages.get_mut(task_id) else {
+            for oldest in push_bounded(&mut self.order, self.capacity, task_id.to_string()) {
+                self.stages.remove(&oldest);
+            }
+            self.stages.insert(task_id.to_string(), tracked);
+            return Transition::Entered;
+        };
+
+        let from = std::mem::replace(task, tracked).stage;
+        if from == next {
+            Transition::Repeated
+        } else if from.can_advance_to(next) {
+            Transition::Entered
+        } else {
+            Transition::Illegal { from }
+        }
+    }
+
+    /// Mark tasks still in flight with no event for `timeout` as dropped,
+    /// except those `busy` says are still being worked on. Returns each newly
+    /// dropped task with where it was left.
+    pub fn drop_stale(
+        &mut self,
+        now: Instant,
+        timeout: Duration,
+        busy: impl Fn(&str) -> bool,
+    ) -> Vec<(String, TrackedTask)> {
+        let mut dropped = Vec::new();
+        for task_id in &self.order {
+            let Some(task) = self.stages.get_mut(task_id) else {
+                continue;
+            };
+            if task.stage.is_terminal()
+                || now.saturating_duration_since(task.updated_at) < timeout
+                || busy(task_id)
+            {
+                continue;
+            }
+            dropped.push((task_id.clone(), *task));
+            task.stage = TaskStage::Dropped;
+        }
+        dropped
+    }
+}
+
+/// Warning logged for an out-of-order transition. Not an error event: it
+/// reports on the pipeline, so it stays out of the last errors and the
+/// errors-only filter.
+pub fn illegal_transition_event(
+    event: &WorkerEvent,
+    task_id: &str,
+    from: TaskStage,
+    to: TaskStage,
+) -> WorkerEvent {
+    WorkerEvent {
+        msg: format!("Task {} went from {} to {} out of order", task_id, from, to),
+        log_level: LogLevel::Warn,
+        event_type: EventType::Refresh,
+        phase: None,
+        ..event.clone()
+    }
+}
+
+/// Error logged for a task that went missing in the pipeline
+pub fn dropped_task_event(task_id: &str, task: &TrackedTask, now: Instant) -> WorkerEvent {
+    let lost = match task.stage {
+        TaskStage::Fetched => "was fetched but never proved",
+        TaskStage::Proving => "started proving but never finished",
+        _ => "was proved but never submitted",
+    };
+    WorkerEvent::new(
+        task.worker,
+        format!(
+            "Task {} dropped: it {} (nothing heard for {}s)",
+            task_id,
+            lost,
+            now.saturating_duration_since(task.updated_at).as_secs()
+        ),
+        EventType::Error,
+        LogLevel::Error,
+    )
+}
+
+impl DashboardState {
+    /// Move the event's task to the stage the event reports, logging a warning
+    /// if that's out of order. Events that aren't about a task's progress are
+    /// `Entered`, so they're always processed.
+    pub(super) fn advance_lifecycle(&mut self, event: &WorkerEvent) -> Transition {
+        let stage = match &event.phase {
+            Some(phase) => TaskStage::from_phase(phase),
+            None if event.event_type == EventType::Error => TaskStage::failed_by(event.worker),
+            None => None,
+        };
+        let (Some(stage), Some(task_id)) = (stage, event.task_id()) else {
+            return Transition::Entered;
+        };
+
+        let transition =
+            self.task_lifecycles
+                .advance(task_id, stage, event.worker, event.emitted_at);
+        match transition {
+            // A late event for a task given up on: it wasn't lost after all
+            Transition::Illegal {
+                from: TaskStage::Dropped,
+            } => {
+                self.zkvm_metrics.dropped_tasks = self.zkvm_metrics.dropped_tasks.saturating_sub(1);
+                return Transition::Entered;
+            }
+            Transition::Illegal { from } => {
+                let warning = illegal_transition_event(event, task_id, from, stage);
+                self.raise_event(warning);
+            }
+            _ => {}
+        }
+        transition
+    }
+
+    /// Mark tasks that have been in flight too long without an event as
+    /// dropped, logging an error for each. A task a prover is still proving
+    /// is left to the stuck-prover check instead.
+    pub fn check_dropped_tasks(&mut self, now: Instant) {
+        let provers = &self.provers;
+        let dropped = self.task_lifecycles.drop_stale(
+            now,
+            Duration::from_secs(TASK_DROP_TIMEOUT_SECS),
+            |task_id| {
+                provers
+                    .values()
+                    .any(|prover| prover.current_task.as_deref() == Some(task_id))
+            },
+        );
+        for (task_id, task) in dropped {
+            self.zkvm_metrics.dropped_tasks += 1;
+            self.zkvm_metrics.last_task_status = TaskStatus::Dropped;
+            self.raise_event(dropped_task_event(&task_id, &task, now));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::dashboard::testing::{
+        emitted_at, got_task, proof_generated, proof_submitted, proving, test_state,
+    };
+    use TaskStage::*;
+
+    fn drive(stages: &[TaskStage]) -> Vec<Transition> {
+        let mut lifecycles = TaskLifecycles::new(10);
+        stages
+            .iter()
+            .map(|&stage| lifecycles.advance("task-1", stage, Worker::Prover(0), Instant::now()))
+            .collect()
+    }
+
+    #[test]
+    fn test_happy_path_enters_every_stage() {
+        let transitions = drive(&[Fetched, Proving, Proved, Submitted]);
+        assert!(transitions.iter().all(|t| *t == Transition::Entered));
+    }
+
+    #[test]
+    fn test_each_failure_branch_is_legal() {
+        for path in [
+            &[Fetched, ProofFailed][..],
+            &[Fetched, Proving, ProofFailed],
+            &[Fetched, Proving, Proved, SubmitFailed],
+        ] {
+            let transitions = drive(path);
+            assert!(
+                transitions.iter().all(|t| *t == Transition::Entered),
+                "{:?}: {:?}",
+                path,
+                transitions
+            );
+        }
+    }
+
+    #[test]
+    fn test_out_of_order_transitions_are_illegal() {
+        assert_eq!(
+            drive(&[Fetched, Submitted])[1],
+            Transition::Illegal { from: Fetched }
+        );
+        assert_eq!(
+            drive(&[Fetched, Proving, Proved, Proving])[3],
+            Transition::Illegal { from: Proved }
+        );
+        // Failures are terminal
+        assert_eq!(
+            drive(&[Fetched, Proving, ProofFailed, Proved])[3],
+            Transition::Illegal { from: ProofFailed }
+        );
+    }
+
+    #[test]
+    fn test_out_of_order_warning_is_not_an_error() {
+        let mut state = test_state();
+        state
+            .task_lifecycles
+            .advance("task-1", Fetched, Worker::TaskFetcher, Instant::now());
+        state.add_event(proof_submitted("task-1"));
+        state.process_pending_events();
+
+        let raised = state.take_raised_events();
+        assert_eq!(raised.len(), 1);
+        assert_eq!(raised[0].log_level, LogLevel::Warn);
+        assert_ne!(raised[0].event_type, EventType::Error);
+        assert!(state.last_errors.is_empty());
+    }
+
+    #[test]
+    fn test_repeats_and_refetches() {
+        assert_eq!(drive(&[Fetched, Fetched])[1], Transition::Repeated);
+        // A finished task can be handed out again
+        assert_eq!(
+            drive(&[Fetched, Proving, ProofFailed, Fetched])[3],
+            Transition::Entered
+        );
+    }
+
+    #[test]
+    fn test_oldest_tasks_are_forgotten_past_capacity() {
+        let mut lifecycles = TaskLifecycles::new(2);
+        let now = Instant::now();
+        for task_id in ["a", "b", "c"] {
+            lifecycles.advance(task_id, Fetched, Worker::TaskFetcher, now);
+        }
+        // A forgotten task is picked up wherever it is now
+        assert_eq!(
+            lifecycles.advance("a", Submitted, Worker::ProofSubmitter, now),
+            Transition::Entered
+        );
+        assert_eq!(
+            lifecycles.advance("c", Submitted, Worker::ProofSubmitter, now),
+            Transition::Illegal { from: Fetched }
+        );
+    }
+
+    #[test]
+    fn test_proved_task_is_dropped_when_its_submission_never_arrives() {
+        let mut state = test_state();
+        let start = state.start_time;
+        let secs = |secs: u64| start + Duration::from_secs(secs);
+
+        state.add_event(emitted_at(got_task("task-1"), secs(0)));
+        state.add_event(emitted_at(proving(0, "task-1"), secs(1)));
+        state.add_event(emitted_at(proof_generated("task-1"), secs(60)));
+        state.process_pending_events();
+
+        // Waiting on the submitter is fine for a while
+        state.check_dropped_tasks(secs(60 + TASK_DROP_TIMEOUT_SECS - 1));
+        assert_eq!(state.zkvm_metrics.dropped_tasks, 0);
+
+        let timeout = secs(60 + TASK_DROP_TIMEOUT_SECS);
+        state.check_dropped_tasks(timeout);
+        assert_eq!(state.zkvm_metrics.dropped_tasks, 1);
+        assert_eq!(state.zkvm_metrics.last_task_status, TaskStatus::Dropped);
+        let raised = state.take_raised_events();
+        assert_eq!(raised.len(), 1);
+        assert_eq!(raised[0].log_level, LogLevel::Error);
+        assert!(
+            raised[0]
+                .msg
+                .contains("Task task-1 dropped: it was proved but never submitted")
+        );
+
+        // Counted once
+        state.check_dropped_tasks(timeout + Duration::from_secs(60));
+        assert_eq!(state.zkvm_metrics.dropped_tasks, 1);
+
+        // A submission that turns up after all takes it back
+        state.add_event(emitted_at(
+            proof_submitted("task-1"),
+            timeout + Duration::from_secs(120),
+        ));
+        state.process_pending_events();
+        assert_eq!(state.zkvm_metrics.dropped_tasks, 0);
+        assert_eq!(state.zkvm_metrics.tasks_submitted, 1);
+        assert!(state.take_raised_events().is_empty());
+    }
+
+    #[test]
+    fn test_tasks_still_proving_or_finished_are_not_dropped() {
+        let mut lifecycles = TaskLifecycles::new(10);
+        let start = Instant::now();
+        lifecycles.advance("busy", Proving, Worker::Prover(0), start);
+        lifecycles.advance("done", Fetched, Worker::TaskFetcher, start);
+        lifecycles.advance("done", ProofFailed, Worker::Prover(0), start);
+        lifecycles.advance("lost", Fetched, Worker::TaskFetcher, start);
+
+        let later = start + Duration::from_secs(TASK_DROP_TIMEOUT_SECS);
+        let timeout = Duration::from_secs(TASK_DROP_TIMEOUT_SECS);
+        let dropped = lifecycles.drop_stale(later, timeout, |task_id| task_id == "busy");
+        let ids: Vec<&str> = dropped.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, ["lost"]);
+        assert_eq!(dropped[0].1.stage, Fetched);
+
+        // A late event is reported against the drop, for the dashboard to take back
+        assert_eq!(
+            lifecycles.advance("lost", Proving, Worker::Prover(0), later),
+            Transition::Illegal { from: Dropped }
+        );
+    }
+}
@@ -2,9 +2,28 @@
 //!
 //! Split into logical modules for better maintainability
 
+pub mod averaging;
+pub mod comparison;
 pub mod components;
+pub mod connectivity;
+pub mod dedup;
+pub mod dump;
+pub mod errors;
+pub mod filter;
+pub mod input;
+pub mod layout;
+pub mod lifecycle;
+pub mod nodes;
+pub mod pause;
+pub mod ram;
+pub mod regression;
 pub mod renderer;
+pub mod scroll;
+pub mod snapshot;
 pub mod state;
+#[cfg(test)]
+pub(crate) mod testing;
+pub mod toast;
 pub mod updaters;
 pub mod utils;
 
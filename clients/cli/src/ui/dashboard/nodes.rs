@@ -0,0 +1,109 @@
+//! Per-node metrics
+//!
+//! Events may carry the node ID they were produced for, so one dashboard can
+//! follow several node identities. The zkVM totals always cover every node;
+//! each node's share is kept alongside for the per-node view.
+
+use super::state::DashboardState;
+use crate::events::Event as WorkerEvent;
+use crate::ui::metrics::ZkVMMetrics;
+
+/// How the zkVM panel presents multiple nodes
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum NodeView {
+    /// Totals across all nodes
+    #[default]
+    Aggregate,
+    /// Totals plus one line per node
+    PerNode,
+}
+
+/// Task counters attributed to one node
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NodeMetrics {
+    pub tasks_fetched: usize,
+    pub tasks_proved: usize,
+    pub tasks_submitted: usize,
+    /// Proof and submission failures
+    pub failures: usize,
+    /// Points earned from accepted proofs
+    pub points: u64,
+}
+
+impl NodeMetrics {
+    /// The node-relevant counters of the overall zkVM metrics
+    pub fn capture(metrics: &ZkVMMetrics) -> Self {
+        Self {
+            tasks_fetched: metrics.tasks_fetched,
+            tasks_proved: metrics.tasks_proved,
+            tasks_submitted: metrics.tasks_submitted,
+            failures: metrics.proof_failures + metrics.submit_failures,
+            points: metrics.total_points,
+        }
+    }
+
+    fn add_delta(&mut self, before: Self, after: Self) {
+        self.tasks_fetched += after.tasks_fetched.saturating_sub(before.tasks_fetched);
+        self.tasks_proved += after.tasks_proved.saturating_sub(before.tasks_proved);
+        self.tasks_submitted += after.tasks_submitted.saturating_sub(before.tasks_submitted);
+        self.failures += after.failures.saturating_sub(before.failures);
+        self.points += after.points.saturating_sub(before.points);
+    }
+}
+
+impl DashboardState {
+    /// The node an event belongs to: its own node ID, else the dashboard's
+    /// (0 when neither is known)
+    pub fn event_node_id(&self, event: &WorkerEvent) -> u64 {
+        event.node_id.or(self.node_id).unwrap_or(0)
+    }
+
+    /// Credit whatever processing `event` added to the zkVM counters (since
+    /// `before`) to the event's node. Working from the deltas keeps the
+    /// per-node counters summing to the totals, duplicates and all.
+    pub(super) fn attribute_to_node(&mut self, event: &WorkerEvent, before: NodeMetrics) {
+        let after = NodeMetrics::capture(&self.zkvm_metrics);
+        if after == before {
+            return;
+        }
+        let node_id = self.event_node_id(event);
+        self.node_metrics
+            .entry(node_id)
+            .or_default()
+            .add_delta(before, after);
+    }
+
+    /// Switch between the aggregate and per-node views
+    pub fn toggle_node_view(&mut self) {
+        self.node_view = match self.node_view {
+            NodeView::Aggregate => NodeView::PerNode,
+            NodeView::PerNode => NodeView::Aggregate,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::dashboard::testing::{proof_submitted, test_state};
+
+    #[test]
+    fn test_events_are_attributed_per_node_and_sum_to_totals() {
+        let mut state = test_state();
+        state.add_event(proof_submitted("a").with_node_id(2));
+        state.add_event(proof_submitted("b").with_node_id(2));
+        // Untagged events belong to the dashboard's own node
+        state.add_event(proof_submitted("c"));
+        state.process_pending_events();
+
+        assert_eq!(state.node_metrics[&2].tasks_submitted, 2);
+        assert_eq!(state.node_metrics[&1].tasks_submitted, 1);
+        let total: usize = state.node_metrics.values().map(|m| m.tasks_submitted).sum();
+        assert_eq!(total, state.zkvm_metrics.tasks_submitted);
+
+        state.toggle_node_view();
+        assert_eq!(state.node_view, NodeView::PerNode);
+        state.toggle_node_view();
+        assert_eq!(state.node_view, NodeView::Aggregate);
+    }
+}
@@ -0,0 +1,62 @@
+//! Pausing the display
+//!
+//! Pausing holds the screen still so it can be read: the dashboard stops
+//! redrawing and the log view stays on the events it showed. Events keep being
+//! processed, so the metrics are current again as soon as it resumes.
+
+use super::state::DashboardState;
+
+impl DashboardState {
+    /// Pause or resume the display. Resuming returns to the live log.
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+        if !self.paused {
+            self.scroll_logs_to_tail();
+        }
+        self.request_redraw();
+    }
+
+    /// Redraw once even while paused, e.g. to show a key's effect or after a resize
+    pub fn request_redraw(&mut self) {
+        self.redraw_requested = true;
+    }
+
+    /// Whether the next frame should be drawn
+    pub fn should_draw(&mut self) -> bool {
+        !self.paused || std::mem::take(&mut self.redraw_requested)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::dashboard::testing::{log_event, test_state};
+    use crossterm::event::KeyCode;
+
+    #[test]
+    fn test_pause_holds_the_display_until_resumed() {
+        let mut state = test_state();
+        state.log_page_size.set(10);
+        for i in 0..20 {
+            state.add_to_activity_log(log_event(i));
+        }
+        assert!(state.should_draw());
+
+        state.handle_key(KeyCode::Char(' '));
+        assert!(state.paused);
+        // One frame to show the indicator, then nothing
+        assert!(state.should_draw());
+        assert!(!state.should_draw());
+
+        // New events don't move the log view
+        for i in 20..25 {
+            state.add_to_activity_log(log_event(i));
+        }
+        assert_eq!(state.scroll_offset, 5);
+        assert!(!state.should_draw());
+
+        state.handle_key(KeyCode::Char('p'));
+        assert!(!state.paused);
+        assert!(state.is_following_logs());
+        assert!(state.should_draw());
+    }
+}
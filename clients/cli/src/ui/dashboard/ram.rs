@@ -0,0 +1,98 @@
+//! RAM high-water marks
+//!
+//! Besides the all-time peak, the dashboard keeps the peak RAM of this session
+//! alone, for the session summary, and the peak RAM seen while each prover is
+//! proving: reset when the prover starts a task (Step 2) and captured
+//! when its proof is generated (Step 3). RAM is sampled for the whole process,
+//! so with several provers the figure covers all of them at once.
+
+use super::state::DashboardState;
+use super::toast::Toast;
+use crate::ui::metrics::format_bytes;
+use std::time::Instant;
+
+impl DashboardState {
+    /// Raise the session's high-water mark, and those of provers that are
+    /// proving, to the latest sample.
+    pub fn track_peak_ram(&mut self) {
+        let sampled = self.system_metrics.sampled_ram_bytes;
+        self.session_peak_ram_bytes = self.session_peak_ram_bytes.max(sampled);
+        for prover in self.provers.values_mut() {
+            if prover.proving_since.is_some() {
+                prover.proving_peak_ram_bytes = prover.proving_peak_ram_bytes.max(sampled);
+            }
+        }
+    }
+
+    /// Peak RAM while proving, formatted for display, once a proof has been captured.
+    pub fn format_proving_peak_ram(&self) -> Option<String> {
+        self.proving_peak_ram_bytes.map(format_bytes)
+    }
+
+    /// Restart the global and session peaks from the current reading.
+    pub fn reset_peak_ram(&mut self, now: Instant) {
+        self.system_metrics.peak_ram_bytes = self.system_metrics.sampled_ram_bytes;
+        self.session_peak_ram_bytes = self.system_metrics.sampled_ram_bytes;
+        self.toast = Some(Toast {
+            message: "Peak RAM reset".to_string(),
+            subtle: true,
+            shown_at: Some(now),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::events::Event as WorkerEvent;
+    use crate::ui::dashboard::state::DashboardState;
+    use crate::ui::dashboard::testing::{proof_generated, proving, test_state};
+    use std::time::Instant;
+
+    fn feed(state: &mut DashboardState, event: WorkerEvent) {
+        state.add_event(event);
+        state.process_pending_events();
+    }
+
+    fn sample(state: &mut DashboardState, bytes: u64) {
+        state.system_metrics.sampled_ram_bytes = bytes;
+        state.track_peak_ram();
+    }
+
+    #[test]
+    fn test_proving_peak_ram_is_scoped_to_each_proof() {
+        let mut state = test_state();
+        let proving = |task_id: &str| proving(0, task_id);
+
+        // Samples between proofs don't count
+        sample(&mut state, 900);
+        feed(&mut state, proving("task-1"));
+        sample(&mut state, 500);
+        sample(&mut state, 2000);
+        sample(&mut state, 1500);
+        feed(&mut state, proof_generated("task-1"));
+        assert_eq!(state.proving_peak_ram_bytes, Some(2000));
+
+        // The next proof starts again from the RAM at its start
+        sample(&mut state, 3000);
+        sample(&mut state, 400);
+        feed(&mut state, proving("task-2"));
+        sample(&mut state, 700);
+        feed(&mut state, proof_generated("task-2"));
+        assert_eq!(state.proving_peak_ram_bytes, Some(700));
+
+        // An unmatched Step 3 captures nothing
+        feed(&mut state, proof_generated("task-9"));
+        assert_eq!(state.proving_peak_ram_bytes, Some(700));
+    }
+
+    #[test]
+    fn test_reset_restarts_the_global_peak_from_the_current_reading() {
+        let mut state = test_state();
+        state.system_metrics.peak_ram_bytes = 8000;
+        state.system_metrics.sampled_ram_bytes = 1000;
+
+        state.reset_peak_ram(Instant::now());
+        assert_eq!(state.system_metrics.peak_ram_bytes, 1000);
+        assert!(state.visible_toast().is_some());
+    }
+}
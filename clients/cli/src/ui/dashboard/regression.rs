@@ -0,0 +1,200 @@
+//! Proving time regression alert
+//!
+//! Throttling or background load shows up as proofs slowly getting longer. The
+//! rolling average of the latest proofs is compared against a baseline, either
+//! configured or taken from the session's first proofs, and an error is raised
+//! once it is slower by more than the configured percentage. While the slowdown
+//! lasts the panel keeps flagging it, but the error repeats at most once per
+//! cooldown.
+
+use super::state::DashboardState;
+use crate::consts::cli_consts::{
+    DEFAULT_REGRESSION_PCT, REGRESSION_ALERT_COOLDOWN_SECS, REGRESSION_BASELINE_PROOFS,
+    REGRESSION_ROLLING_PROOFS,
+};
+use crate::events::{Event as WorkerEvent, EventType, Worker};
+use crate::logging::LogLevel;
+use crate::ui::metrics::format_duration;
+use std::time::{Duration, Instant};
+
+/// When proving counts as having regressed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegressionConfig {
+    /// How much slower than the baseline (in percent) the rolling average may get
+    pub threshold_pct: f64,
+    /// Baseline proving time in seconds; `None` takes it from the first proofs
+    pub baseline_secs: Option<f64>,
+}
+
+impl Default for RegressionConfig {
+    fn default() -> Self {
+        Self {
+            threshold_pct: DEFAULT_REGRESSION_PCT,
+            baseline_secs: None,
+        }
+    }
+}
+
+/// Baseline and alert state for the regression check
+#[derive(Debug, Clone)]
+pub struct ProvingRegression {
+    threshold_pct: f64,
+    baseline_secs: Option<f64>,
+    /// Durations of the session's first proofs, until the baseline is taken from them
+    first_proofs: Vec<u64>,
+    /// How much slower than the baseline the rolling average is, while past the threshold
+    slowdown_pct: Option<f64>,
+    /// When the last alert was raised
+    alerted_at: Option<Instant>,
+    /// Prover that finished the latest timed proof, which the alert is attributed to
+    last_worker: Worker,
+}
+
+impl ProvingRegression {
+    pub fn new(config: RegressionConfig) -> Self {
+        Self {
+            threshold_pct: config.threshold_pct,
+            baseline_secs: config.baseline_secs,
+            first_proofs: Vec::new(),
+            slowdown_pct: None,
+            alerted_at: None,
+            last_worker: Worker::Prover(0),
+        }
+    }
+
+    /// Note a timed proof, which counts towards the baseline until it is known
+    pub fn record(&mut self, worker: Worker, secs: u64) {
+        self.last_worker = worker;
+        if self.baseline_secs.is_some() {
+            return;
+        }
+        self.first_proofs.push(secs);
+        if self.first_proofs.len() >= REGRESSION_BASELINE_PROOFS {
+            let total: u64 = self.first_proofs.drain(..).sum();
+            self.baseline_secs = Some(total as f64 / REGRESSION_BASELINE_PROOFS as f64);
+        }
+    }
+
+    pub fn baseline_secs(&self) -> Option<f64> {
+        self.baseline_secs
+    }
+
+    /// How much slower than the baseline proving currently is, if past the threshold
+    pub fn slowdown_pct(&self) -> Option<f64> {
+        self.slowdown_pct
+    }
+
+    /// Compare the rolling `average` against the baseline. Returns the slowdown
+    /// when an alert is due: past the threshold and outside the cooldown.
+    pub fn check(&mut self, average: Option<f64>, now: Instant) -> Option<f64> {
+        self.slowdown_pct = match (average, self.baseline_secs) {
+            (Some(average), Some(baseline)) if baseline > 0.0 => {
+                let pct = (average / baseline - 1.0) * 100.0;
+                (pct > self.threshold_pct).then_some(pct)
+            }
+            _ => None,
+        };
+        let slowdown = self.slowdown_pct?;
+        let cooldown = Duration::from_secs(REGRESSION_ALERT_COOLDOWN_SECS);
+        if self
+            .alerted_at
+            .is_some_and(|at| now.saturating_duration_since(at) < cooldown)
+        {
+            return None;
+        }
+        self.alerted_at = Some(now);
+        Some(slowdown)
+    }
+}
+
+impl DashboardState {
+    /// Raise an error if the latest proofs are running slower than the baseline
+    pub fn check_proving_regression(&mut self, now: Instant) {
+        let average = self.proving_times.recent_average(REGRESSION_ROLLING_PROOFS);
+        let Some(slowdown) = self.regression.check(average, now) else {
+            return;
+        };
+        let baseline = self.regression.baseline_secs().unwrap_or_default();
+        self.raise_event(WorkerEvent::new(
+            self.regression.last_worker,
+            format!(
+                "Performance regression: the last {} proofs averaged {}, {:.0}% slower than the {} baseline",
+                REGRESSION_ROLLING_PROOFS,
+                format_duration(average.unwrap_or_default().round() as u64),
+                slowdown,
+                format_duration(baseline.round() as u64)
+            ),
+            EventType::Error,
+            LogLevel::Warn,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::dashboard::testing::test_state;
+
+    #[test]
+    fn test_progressively_slower_proofs_raise_one_alert_per_cooldown() {
+        let mut state = test_state();
+        let start = state.start_time;
+        let secs = |n: u64| start + Duration::from_secs(n);
+        let proved = |state: &mut DashboardState, at: u64, duration: u64| {
+            state.proving_times.record(secs(at), duration);
+            state.regression.record(Worker::Prover(0), duration);
+            state.check_proving_regression(secs(at));
+        };
+        let alerts = |state: &mut DashboardState| {
+            state
+                .take_raised_events()
+                .iter()
+                .filter(|event| event.msg.starts_with("Performance regression"))
+                .count()
+        };
+
+        // Five 100s proofs set the baseline
+        for i in 0..5 {
+            proved(&mut state, i * 100, 100);
+        }
+        assert_eq!(state.regression.baseline_secs(), Some(100.0));
+        assert_eq!(alerts(&mut state), 0);
+
+        // Each proof 10s slower than the last; the rolling average of five
+        // passes 125% of the baseline at the fifth (110..150 averages 130)
+        for (i, duration) in [110, 120, 130, 140].into_iter().enumerate() {
+            proved(&mut state, 500 + i as u64 * 100, duration);
+            assert_eq!(state.regression.slowdown_pct(), None);
+        }
+        proved(&mut state, 900, 150);
+        assert_eq!(state.regression.slowdown_pct().map(f64::round), Some(30.0));
+        assert_eq!(alerts(&mut state), 1);
+
+        // Still slower, but within the cooldown: flagged without another alert
+        proved(&mut state, 1000, 160);
+        state.check_proving_regression(secs(1001));
+        assert!(state.regression.slowdown_pct().is_some());
+        assert_eq!(alerts(&mut state), 0);
+
+        // After the cooldown it alerts again
+        state.check_proving_regression(secs(900 + REGRESSION_ALERT_COOLDOWN_SECS));
+        assert_eq!(alerts(&mut state), 1);
+    }
+
+    #[test]
+    fn test_configured_baseline_is_used_from_the_first_proof() {
+        let now = Instant::now();
+        let mut regression = ProvingRegression::new(RegressionConfig {
+            threshold_pct: 50.0,
+            baseline_secs: Some(60.0),
+        });
+        regression.record(Worker::Prover(0), 100);
+        assert_eq!(regression.baseline_secs(), Some(60.0));
+        assert_eq!(regression.check(Some(89.0), now), None);
+        assert!(regression.check(Some(100.0), now).is_some());
+
+        // Back under the threshold clears the flag
+        assert_eq!(regression.check(Some(70.0), now), None);
+        assert_eq!(regression.slowdown_pct(), None);
+    }
+}
@@ -1,26 +1,33 @@
 //! Dashboard main renderer
 
-use super::components::{footer, header, info_panel, logs, metrics};
+use super::components::{
+    comparison, errors, first_proof, footer, header, info_panel, logs, metrics, provers,
+    stats_table,
+};
+use super::layout::DashboardLayout;
 use super::state::DashboardState;
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::prelude::{Color, Style};
+use ratatui::prelude::Style;
 use ratatui::widgets::Block;
 
 pub fn render_dashboard(f: &mut Frame, state: &DashboardState) {
     if state.with_background_color {
         f.render_widget(
-            Block::default().style(Style::default().bg(Color::Rgb(16, 20, 24))),
+            Block::default().style(Style::default().bg(state.theme.background)),
             f.area(),
         );
     }
 
+    let layout = DashboardLayout::for_area(f.area());
+    // Stacked metrics need more rows than side-by-side ones
+    let metrics_share = if layout.columns { 35 } else { 50 };
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(4),
             Constraint::Fill(1),
-            Constraint::Percentage(35),
+            Constraint::Percentage(metrics_share),
             Constraint::Length(2),
         ])
         .margin(1)
@@ -28,13 +35,96 @@ pub fn render_dashboard(f: &mut Frame, state: &DashboardState) {
 
     header::render_header(f, main_chunks[0], state);
 
+    let content_constraints = if layout.columns {
+        [Constraint::Percentage(30), Constraint::Percentage(70)]
+    } else {
+        [Constraint::Percentage(40), Constraint::Percentage(60)]
+    };
     let content_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+        .direction(layout.direction())
+        .constraints(content_constraints)
         .split(main_chunks[1]);
 
-    info_panel::render_info_panel(f, content_chunks[0], state);
+    // Left column (or top, when stacked): info panel, then the optional
+    // comparison, statistics, prover and error panels if there's room for them
+    let side_height = |height: u16| if layout.side_panels { height } else { 0 };
+    let comparison_height = side_height(if state.mark.is_some() { 6 } else { 0 });
+    let stats_height = side_height(stats_table::stats_table_height(state));
+    let provers_height = side_height(provers::provers_panel_height(state));
+    let errors_height = side_height(errors::errors_panel_height(state));
+    let left_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Fill(1),
+            Constraint::Length(comparison_height),
+            Constraint::Length(stats_height),
+            Constraint::Length(provers_height),
+            Constraint::Length(errors_height),
+        ])
+        .split(content_chunks[0]);
+    info_panel::render_info_panel(f, left_chunks[0], state);
+    if comparison_height > 0 {
+        comparison::render_comparison_panel(f, left_chunks[1], state);
+    }
+    if stats_height > 0 {
+        stats_table::render_stats_table(f, left_chunks[2], state);
+    }
+    if provers_height > 0 && !state.provers.is_empty() {
+        provers::render_provers_panel(f, left_chunks[3], state);
+    }
+    if errors_height > 0 && !state.last_errors.is_empty() {
+        errors::render_errors_panel(f, left_chunks[4], state);
+    }
     logs::render_logs_panel(f, content_chunks[1], state);
-    metrics::render_metrics_section(f, main_chunks[2], state);
-    footer::render_footer(f, main_chunks[3]);
+    metrics::render_metrics_section(f, main_chunks[2], state, &layout);
+    footer::render_footer(f, main_chunks[3], state);
+    first_proof::render_first_proof_banner(f, f.area(), state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{Event as WorkerEvent, EventType};
+    use crate::logging::LogLevel;
+    use crate::ui::app::UIConfig;
+    use crate::ui::dashboard::testing::{got_task, proof_submitted, proving, test_state_with};
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    #[test]
+    fn test_rendering_survives_resizes_down_to_nothing_mid_proof() {
+        let mut state = test_state_with(UIConfig::new(true, 1, false, None));
+        let now = state.start_time;
+        // A proof submitted, the next one in progress and every optional
+        // panel showing
+        for task_id in ["task-1", "task-2"] {
+            state.add_event(got_task(task_id));
+            state.add_event(proving(0, task_id));
+        }
+        state.add_event(WorkerEvent::proof_submitter_with_level(
+            "Failed to submit proof".to_string(),
+            EventType::Error,
+            LogLevel::Error,
+        ));
+        state.add_event(proof_submitted("task-1"));
+        state.process_pending_events();
+        state.proving_times.record(now, 90);
+        state.set_mark();
+
+        let mut terminal = Terminal::new(TestBackend::new(160, 50)).unwrap();
+        for (width, height) in [
+            (160, 50),
+            (80, 24),
+            (40, 10),
+            (12, 4),
+            (1, 1),
+            (0, 0),
+            (200, 3),
+        ] {
+            terminal.backend_mut().resize(width, height);
+            terminal.autoresize().unwrap();
+            terminal.clear().unwrap();
+            terminal.draw(|f| render_dashboard(f, &state)).unwrap();
+        }
+    }
 }
@@ -0,0 +1,187 @@
+//! Activity log scrollback
+//!
+//! The log panel normally follows the live tail. Scrolling back pins the view
+//! to the events on screen: new events don't move it, and evicting old events
+//! only clamps it to what is still retained.
+
+use super::dedup::LogEntry;
+use super::filter::LogFilter;
+use super::state::DashboardState;
+
+impl DashboardState {
+    /// Events shown in the activity log panel after filtering, oldest first
+    pub fn visible_logs(&self) -> impl DoubleEndedIterator<Item = &LogEntry> {
+        self.activity_logs
+            .iter()
+            .filter(|entry| entry.event.should_display() && self.log_filter.matches(&entry.event))
+    }
+
+    /// Whether the log panel is following the newest events
+    pub fn is_following_logs(&self) -> bool {
+        self.scroll_offset == 0
+    }
+
+    /// Furthest the log can be scrolled back while still filling a page
+    fn max_scroll_offset(&self) -> usize {
+        self.visible_logs()
+            .count()
+            .saturating_sub(self.log_page_size.get().max(1))
+    }
+
+    /// Scroll back towards older events
+    pub fn scroll_logs_up(&mut self, lines: usize) {
+        self.scroll_offset = (self.scroll_offset + lines).min(self.max_scroll_offset());
+    }
+
+    /// Scroll forward towards the live tail
+    pub fn scroll_logs_down(&mut self, lines: usize) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(lines);
+    }
+
+    /// Jump to the oldest retained events
+    pub fn scroll_logs_to_oldest(&mut self) {
+        self.scroll_offset = self.max_scroll_offset();
+    }
+
+    /// Resume following the live tail
+    pub fn scroll_logs_to_tail(&mut self) {
+        self.scroll_offset = 0;
+    }
+
+    /// Empty the activity log and reset the view onto it (scrollback and
+    /// filters). Only the display is cleared: metrics and the current task
+    /// come from processed events, not from the log.
+    pub fn clear_activity_log(&mut self) {
+        self.activity_logs.clear();
+        self.log_filter = LogFilter::default();
+        self.scroll_logs_to_tail();
+    }
+
+    /// Keep a scrolled-back or paused view anchored as the log changes
+    pub(super) fn track_log_scroll(&mut self, added_visible: bool) {
+        if self.is_following_logs() && !self.paused {
+            return;
+        }
+        if added_visible {
+            self.scroll_offset += 1;
+        }
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll_offset());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{Event as WorkerEvent, EventType, ProofPhase};
+    use crate::logging::LogLevel;
+    use crate::ui::app::UIConfig;
+    use crate::ui::dashboard::testing::{log_event, test_state_with};
+    use crossterm::event::KeyCode;
+
+    fn state_with_logs(count: usize, capacity: usize) -> DashboardState {
+        let ui_config = UIConfig::new(false, 1, false, None).with_activity_log_capacity(capacity);
+        let mut state = test_state_with(ui_config);
+        state.log_page_size.set(10);
+        for i in 0..count {
+            state.add_to_activity_log(log_event(i));
+        }
+        state
+    }
+
+    #[test]
+    fn test_page_keys_scroll_and_clamp() {
+        let mut state = state_with_logs(30, 100);
+
+        state.handle_key(KeyCode::PageUp);
+        assert_eq!(state.scroll_offset, 10);
+        state.handle_key(KeyCode::PageUp);
+        state.handle_key(KeyCode::PageUp);
+        assert_eq!(state.scroll_offset, 20); // A full page of the oldest events
+
+        state.handle_key(KeyCode::PageDown);
+        assert_eq!(state.scroll_offset, 10);
+
+        state.handle_key(KeyCode::Home);
+        assert_eq!(state.scroll_offset, 20);
+        state.handle_key(KeyCode::End);
+        assert!(state.is_following_logs());
+    }
+
+    #[test]
+    fn test_other_keys_resume_the_live_tail() {
+        let mut state = state_with_logs(30, 100);
+        state.handle_key(KeyCode::PageUp);
+
+        state.handle_key(KeyCode::Char('x'));
+        assert!(state.is_following_logs());
+    }
+
+    #[test]
+    fn test_scrolled_view_stays_put_as_events_arrive() {
+        let mut state = state_with_logs(30, 100);
+        state.handle_key(KeyCode::PageUp);
+
+        state.add_to_activity_log(log_event(30));
+        assert_eq!(state.scroll_offset, 11);
+
+        // A live view keeps following
+        state.scroll_logs_to_tail();
+        state.add_to_activity_log(log_event(31));
+        assert!(state.is_following_logs());
+    }
+
+    #[test]
+    fn test_eviction_clamps_the_scroll_position() {
+        let mut state = state_with_logs(20, 20);
+        state.handle_key(KeyCode::Home);
+        assert_eq!(state.scroll_offset, 10);
+
+        // The oldest page is evicted as newer events push in
+        for i in 20..25 {
+            state.add_to_activity_log(log_event(i));
+        }
+        assert_eq!(state.activity_logs.len(), 20);
+        assert_eq!(state.scroll_offset, 10);
+    }
+
+    #[test]
+    fn test_clearing_the_log_keeps_metrics_and_task_tracking() {
+        let mut state = state_with_logs(30, 100);
+        state.add_event(
+            WorkerEvent::task_fetcher_with_level(
+                "Step 1 of 4: Got task task-1".to_string(),
+                EventType::Success,
+                LogLevel::Info,
+            )
+            .with_phase(ProofPhase::Fetched {
+                task_id: "task-1".to_string(),
+            }),
+        );
+        state.process_pending_events();
+        state.handle_key(KeyCode::Char('e'));
+        state.handle_key(KeyCode::PageUp);
+
+        state.handle_key(KeyCode::Char('c'));
+        assert!(state.activity_logs.is_empty());
+        assert!(state.is_following_logs());
+        assert!(!state.log_filter.is_active());
+        assert_eq!(state.zkvm_metrics.tasks_fetched, 1);
+        assert_eq!(state.current_task.as_deref(), Some("task-1"));
+
+        // Events keep being tracked against the empty log
+        state.add_event(
+            WorkerEvent::task_fetcher_with_level(
+                "Step 1 of 4: Got task task-2".to_string(),
+                EventType::Success,
+                LogLevel::Info,
+            )
+            .with_phase(ProofPhase::Fetched {
+                task_id: "task-2".to_string(),
+            }),
+        );
+        state.process_pending_events();
+        assert_eq!(state.activity_logs.len(), 1);
+        assert_eq!(state.zkvm_metrics.tasks_fetched, 2);
+        assert_eq!(state.last_task.as_deref(), Some("task-1"));
+    }
+}
@@ -0,0 +1,301 @@
+//! Dashboard snapshot
+//!
+//! A stable, serializable view of the dashboard for the status API. Internal
+//! state can change freely; this struct is the contract scripts rely on, so
+//! fields are only ever added.
+
+use super::state::{DashboardState, FetchingState};
+use crate::events::ProverState;
+use crate::ui::metrics::{SystemMetrics, TaskStatus, ZkVMMetrics};
+use serde::Serialize;
+use std::time::Instant;
+
+/// What the task fetcher is doing
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum FetchingStatus {
+    /// Not fetching
+    #[default]
+    Idle,
+    /// Waiting on the orchestrator for a task
+    Active { elapsed_secs: u64 },
+    /// The last fetch took longer than the timeout
+    Timeout,
+}
+
+/// One prover's progress
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProverSnapshot {
+    /// Index in `Worker::Prover`
+    pub id: usize,
+    /// Task currently being proved, if any
+    pub current_task: Option<String>,
+    /// Status of the prover's last task
+    pub status: TaskStatus,
+    /// Proofs generated this session
+    pub tasks_proved: usize,
+    /// Total proving time in seconds
+    pub runtime_secs: u64,
+    /// Seconds the current proof has been running, when its start was observed
+    pub proving_secs: Option<u64>,
+}
+
+/// Task counters, copied out of the dashboard's zkVM metrics
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ZkvmSnapshot {
+    /// Tasks obtained from the orchestrator
+    pub tasks_fetched: usize,
+    /// Tasks a proof was generated for
+    pub tasks_proved: usize,
+    /// Proofs accepted by the orchestrator
+    pub tasks_submitted: usize,
+    /// Proof generation failures
+    pub proof_failures: usize,
+    /// Proof submission failures
+    pub submit_failures: usize,
+    /// Total zkVM runtime in seconds
+    pub zkvm_runtime_secs: u64,
+    /// Status of the last task
+    pub last_task_status: TaskStatus,
+    /// Proofs whose duration is missing from `zkvm_runtime_secs`
+    pub untimed_proofs: usize,
+    /// Points earned from submitted proofs
+    pub total_points: u64,
+    /// Serialized size of this session's sized proofs, in bytes
+    pub total_proof_bytes: u64,
+    /// Proofs counted in `total_proof_bytes`
+    pub sized_proofs: usize,
+    /// This session's tasks that went missing in flight
+    pub dropped_tasks: usize,
+}
+
+impl From<&ZkVMMetrics> for ZkvmSnapshot {
+    fn from(metrics: &ZkVMMetrics) -> Self {
+        Self {
+            tasks_fetched: metrics.tasks_fetched,
+            tasks_proved: metrics.tasks_proved,
+            tasks_submitted: metrics.tasks_submitted,
+            proof_failures: metrics.proof_failures,
+            submit_failures: metrics.submit_failures,
+            zkvm_runtime_secs: metrics.zkvm_runtime_secs,
+            last_task_status: metrics.last_task_status,
+            untimed_proofs: metrics.untimed_proofs,
+            total_points: metrics.total_points,
+            total_proof_bytes: metrics.total_proof_bytes,
+            sized_proofs: metrics.sized_proofs,
+            dropped_tasks: metrics.dropped_tasks,
+        }
+    }
+}
+
+impl Default for ZkvmSnapshot {
+    fn default() -> Self {
+        Self::from(&ZkVMMetrics::default())
+    }
+}
+
+/// Host readings, copied out of the dashboard's system metrics
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SystemSnapshot {
+    /// CPU usage percentage
+    pub cpu_percent: f32,
+    /// Process RAM usage in bytes
+    pub ram_bytes: u64,
+    /// Peak process RAM usage in bytes
+    pub peak_ram_bytes: u64,
+    /// Total system RAM in bytes
+    pub total_ram_bytes: u64,
+    /// Process RAM usage as a percentage of total RAM
+    pub ram_usage_pct: f32,
+    /// GPU utilization percentage, when available
+    pub gpu_utilization_pct: Option<f32>,
+    /// GPU memory in use in bytes, when available
+    pub gpu_memory_used_bytes: Option<u64>,
+    /// Total GPU memory in bytes, when available
+    pub gpu_memory_total_bytes: Option<u64>,
+    /// CPU temperature in °C, when the platform exposes one
+    pub cpu_temp_celsius: Option<f32>,
+    /// Bytes received across all network interfaces
+    pub network_rx_bytes: u64,
+    /// Bytes sent across all network interfaces
+    pub network_tx_bytes: u64,
+    /// Receive rate in bytes per second
+    pub network_rx_bytes_per_sec: f64,
+    /// Send rate in bytes per second
+    pub network_tx_bytes_per_sec: f64,
+}
+
+impl From<&SystemMetrics> for SystemSnapshot {
+    fn from(metrics: &SystemMetrics) -> Self {
+        Self {
+            cpu_percent: metrics.cpu_percent,
+            ram_bytes: metrics.ram_bytes,
+            peak_ram_bytes: metrics.peak_ram_bytes,
+            total_ram_bytes: metrics.total_ram_bytes,
+            ram_usage_pct: metrics.ram_usage_pct,
+            gpu_utilization_pct: metrics.gpu_utilization_pct,
+            gpu_memory_used_bytes: metrics.gpu_memory_used_bytes,
+            gpu_memory_total_bytes: metrics.gpu_memory_total_bytes,
+            cpu_temp_celsius: metrics.cpu_temp_celsius,
+            network_rx_bytes: metrics.network_rx_bytes,
+            network_tx_bytes: metrics.network_tx_bytes,
+            network_rx_bytes_per_sec: metrics.network_rx_bytes_per_sec,
+            network_tx_bytes_per_sec: metrics.network_tx_bytes_per_sec,
+        }
+    }
+}
+
+/// Point-in-time copy of the dashboard, as served by the status API
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DashboardSnapshot {
+    /// Node being proved for, if known
+    pub node_id: Option<u64>,
+    /// Environment the node runs against
+    pub environment: String,
+    /// Seconds since the session started
+    pub uptime_secs: u64,
+    /// Seconds since the last successful submission this session, if any
+    pub secs_since_last_success: Option<u64>,
+    /// Task currently being worked on, if any
+    pub current_task: Option<String>,
+    /// Last task fetched, if any
+    pub last_task: Option<String>,
+    /// Overall pipeline state
+    pub prover_state: ProverState,
+    /// Whether the schedule is holding proving back, so no submissions are due
+    pub paused: bool,
+    /// Task fetcher state
+    pub fetching: FetchingStatus,
+    /// Per-prover progress, ordered by ID
+    pub provers: Vec<ProverSnapshot>,
+    /// Submissions per hour over the recent throughput window
+    pub tasks_per_hour: f64,
+    /// Estimated points per hour, once the estimate has warmed up
+    pub points_per_hour: Option<f64>,
+    /// Average seconds from proof generation to submission, once one is matched
+    pub submission_latency_secs: Option<f64>,
+    /// Average retries before success over recent submissions, once one is made
+    pub avg_retries_before_success: Option<f64>,
+    /// Retries so far of the current task, when it is being tracked
+    pub current_task_retries: Option<u32>,
+    /// zkVM task metrics
+    pub zkvm: ZkvmSnapshot,
+    /// System metrics (CPU, RAM, network, ...)
+    pub system: SystemSnapshot,
+}
+
+impl DashboardState {
+    /// Copy the current state into a snapshot
+    pub fn dashboard_snapshot(&self, now: Instant) -> DashboardSnapshot {
+        let fetching = match self.fetching_state() {
+            FetchingState::Idle => FetchingStatus::Idle,
+            FetchingState::Active { started_at } => FetchingStatus::Active {
+                elapsed_secs: now.saturating_duration_since(*started_at).as_secs(),
+            },
+            FetchingState::Timeout => FetchingStatus::Timeout,
+        };
+        let mut provers: Vec<ProverSnapshot> = self
+            .provers
+            .iter()
+            .map(|(&id, prover)| ProverSnapshot {
+                id,
+                current_task: prover.current_task.clone(),
+                status: prover.last_status,
+                tasks_proved: prover.tasks_proved,
+                runtime_secs: prover.runtime_secs,
+                proving_secs: prover
+                    .proving_since
+                    .map(|since| now.saturating_duration_since(since).as_secs()),
+            })
+            .collect();
+        provers.sort_by_key(|prover| prover.id);
+
+        DashboardSnapshot {
+            node_id: self.node_id,
+            environment: self.environment.to_string(),
+            uptime_secs: now.saturating_duration_since(self.start_time).as_secs(),
+            secs_since_last_success: self
+                .since_success
+                .elapsed(now)
+                .map(|elapsed| elapsed.as_secs()),
+            current_task: self.current_task.clone(),
+            last_task: self.last_task.clone(),
+            prover_state: self.current_prover_state(),
+            paused: self.schedule_paused(),
+            fetching,
+            provers,
+            tasks_per_hour: self.throughput.tasks_per_hour(now),
+            points_per_hour: self.throughput.points_per_hour(now),
+            submission_latency_secs: self
+                .submission_latency
+                .average()
+                .map(|latency| latency.as_secs_f64()),
+            avg_retries_before_success: self.retry_stats.average(),
+            current_task_retries: self
+                .current_task
+                .as_deref()
+                .and_then(|task_id| self.retry_stats.retries(task_id)),
+            zkvm: ZkvmSnapshot::from(&self.zkvm_metrics),
+            system: SystemSnapshot::from(&self.system_metrics),
+        }
+    }
+
+    /// Publish a snapshot to the status API, if it's enabled. The API only
+    /// ever reads the latest published copy, so it never holds up updates.
+    pub fn publish_snapshot(&self) {
+        if let Some(publisher) = &self.snapshot_publisher {
+            publisher.send_replace(self.dashboard_snapshot(Instant::now()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::app::UIConfig;
+    use crate::ui::dashboard::testing::{test_state, test_state_with};
+    use crate::ui::metrics::ProverMetrics;
+    use std::time::Duration;
+
+    #[test]
+    fn test_snapshot_reflects_state_without_internal_types() {
+        let mut state = test_state_with(UIConfig::new(false, 2, false, None));
+        let start = state.start_time;
+        state.current_task = Some("task-1".to_string());
+        state.set_fetching_state(FetchingState::Active { started_at: start });
+        for id in [1, 0] {
+            state.provers.insert(id, ProverMetrics::default());
+        }
+        state.zkvm_metrics.tasks_submitted = 3;
+
+        let snapshot = state.dashboard_snapshot(start + Duration::from_secs(90));
+        assert_eq!(snapshot.node_id, Some(1));
+        assert_eq!(snapshot.uptime_secs, 90);
+        assert_eq!(
+            snapshot.fetching,
+            FetchingStatus::Active { elapsed_secs: 90 }
+        );
+        assert_eq!(
+            snapshot.provers.iter().map(|p| p.id).collect::<Vec<_>>(),
+            [0, 1]
+        );
+
+        let value: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&snapshot).unwrap()).unwrap();
+        assert_eq!(value["current_task"], "task-1");
+        assert_eq!(value["fetching"]["state"], "active");
+        assert_eq!(value["zkvm"]["tasks_submitted"], 3);
+    }
+
+    #[test]
+    fn test_snapshot_reports_a_schedule_pause() {
+        let mut state = test_state();
+        let start = state.start_time;
+        // Holding the display still doesn't stop proving
+        state.toggle_pause();
+        assert!(!state.dashboard_snapshot(start).paused);
+
+        state.schedule = Some("00:00-00:00=paused".parse().unwrap());
+        assert!(state.dashboard_snapshot(start).paused);
+    }
+}
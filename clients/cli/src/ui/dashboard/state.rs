@@ -2,15 +2,47 @@
 //!
 //! Contains the main dashboard state struct and related enums
 
-use crate::consts::cli_consts::MAX_ACTIVITY_LOGS;
+use super::comparison::MetricsMark;
+use super::dedup::LogEntry;
+use super::errors::{FailureBadge, LastErrors};
+use super::filter::LogFilter;
+use super::lifecycle::TaskLifecycles;
+use super::nodes::{NodeMetrics, NodeView};
+use super::regression::ProvingRegression;
+use super::snapshot::DashboardSnapshot;
+use super::toast::Toast;
+use crate::clipboard::Clipboard;
+use crate::consts::cli_consts::{
+    FIRST_PROOF_BANNER_SECS, PROVING_TIMES_CAPACITY, RETRY_STATS_CAPACITY,
+    SUBMISSION_LATENCY_SAMPLES, SUBMISSION_PENDING_TIMEOUT_SECS, SYSTEM_HISTORY_SAMPLES,
+    TASK_LIFECYCLE_CAPACITY, THROUGHPUT_WINDOW_SECS,
+};
 use crate::environment::Environment;
-use crate::events::{Event as WorkerEvent, ProverState};
+use crate::event_log::EventLog;
+use crate::events::{Event as WorkerEvent, EventType, ProverState};
+use crate::history::History;
+use crate::journal::{Syslog, syslog_disabled_event};
+use crate::logging::LogLevel;
+use crate::notifier::FailureNotifier;
+use crate::schedule::Schedule;
+use crate::stats::LifetimeStats;
+use crate::task_csv::TaskCsv;
 use crate::ui::app::UIConfig;
-use crate::ui::metrics::{SystemMetrics, TaskFetchInfo, ZkVMMetrics};
+use crate::ui::metrics::{
+    AverageWindow, DailyTarget, GpuMonitor, MetricsSnapshot, ProverMetrics, ProvingTimes,
+    RamThresholds, RetryStats, SinceSuccess, SubmissionLatency, SystemMetrics, TaskFetchInfo,
+    ThroughputWindow, ZkVMMetrics, push_bounded,
+};
+use crate::ui::theme::Theme;
+use crate::webhook::{Webhook, WebhookClient};
 
-use std::collections::VecDeque;
-use std::time::Instant;
-use sysinfo::System;
+use chrono::Local;
+use std::cell::Cell;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use sysinfo::{Components, Networks, System};
+use tokio::sync::watch;
 
 /// State for tracking fetching operations
 #[derive(Debug, Clone)]
@@ -20,6 +52,39 @@ pub enum FetchingState {
     Timeout,
 }
 
+/// Time spent in each fetching state
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FetchingTimes {
+    pub idle: Duration,
+    pub active: Duration,
+    pub timeout: Duration,
+}
+
+impl FetchingTimes {
+    fn add(&mut self, state: &FetchingState, elapsed: Duration) {
+        match state {
+            FetchingState::Idle => self.idle += elapsed,
+            FetchingState::Active { .. } => self.active += elapsed,
+            FetchingState::Timeout => self.timeout += elapsed,
+        }
+    }
+
+    /// Total time tracked
+    pub fn total(&self) -> Duration {
+        self.idle + self.active + self.timeout
+    }
+
+    /// Share of the tracked time spent actively fetching, as a percentage
+    pub fn active_pct(&self) -> f64 {
+        let total = self.total().as_secs_f64();
+        if total == 0.0 {
+            0.0
+        } else {
+            self.active.as_secs_f64() / total * 100.0
+        }
+    }
+}
+
 /// Enhanced dashboard state with real-time metrics and animations.
 #[derive(Debug)]
 pub struct DashboardState {
@@ -39,36 +104,153 @@ pub struct DashboardState {
     pub num_threads: usize,
     /// Queue of events waiting to be processed
     pub pending_events: VecDeque<WorkerEvent>,
-    /// Activity logs for display (last 50 events)
-    pub activity_logs: VecDeque<WorkerEvent>,
+    /// Activity logs for display (most recent `activity_log_capacity` entries),
+    /// with consecutive identical events collapsed
+    pub activity_logs: VecDeque<LogEntry>,
+    /// Maximum number of entries kept in `activity_logs`
+    pub activity_log_capacity: usize,
+    /// Events raised by the dashboard itself, until taken for headless output
+    raised_events: VecDeque<WorkerEvent>,
+    /// How many displayed events the log view is scrolled back from the newest (0 = live)
+    pub scroll_offset: usize,
+    /// Log lines that fit on the last render, used as the scroll page size
+    pub log_page_size: Cell<usize>,
+    /// Which events the log panel shows
+    pub log_filter: LogFilter,
     /// Whether a new version is available.
     pub update_available: bool,
     /// The latest version string, if known.
     pub latest_version: Option<String>,
     /// Whether to enable background colors
     pub with_background_color: bool,
+    /// Proving schedule, if one was configured
+    pub schedule: Option<Schedule>,
+    /// Whether to confirm the session's first submitted proof
+    pub show_first_proof: bool,
+    /// When the session's first proof was submitted, while its confirmation is pending
+    pub first_proof_at: Option<Instant>,
+    /// Message shown in the footer, if any
+    pub toast: Option<Toast>,
+    /// System clipboard, for copying the task ID
+    pub clipboard: Clipboard,
+    /// Whether the display is held still (events are still processed)
+    pub paused: bool,
+    /// Whether a frame should be drawn despite the pause
+    pub(super) redraw_requested: bool,
+    /// Points credited for each submitted proof
+    pub points_per_task: u64,
+    /// Seconds a fetch may take before it is shown as timed out
+    pub fetch_timeout_secs: u64,
+    /// Seconds a proof may take before its prover is flagged as stuck
+    pub stuck_threshold_secs: u64,
+    /// Lifetime counters restored from earlier sessions
+    pub stats_at_start: LifetimeStats,
 
     /// System metrics (CPU, RAM, etc.)
     pub system_metrics: SystemMetrics,
+    /// When the system metrics were last sampled
+    pub last_system_sample: Option<Instant>,
+    /// RAM usage percentages at which the RAM gauge changes color
+    pub ram_thresholds: RamThresholds,
+    /// CPU temperature (°C) from which it is shown in red
+    pub cpu_temp_warn_celsius: f32,
+    /// Colors to draw with
+    pub theme: Theme,
+    /// Weight of each new CPU and RAM sample in the displayed averages (`None` = raw)
+    pub metrics_smoothing: Option<f32>,
+    /// Recent CPU usage samples (percent), oldest first
+    pub cpu_history: VecDeque<f32>,
+    /// Recent RAM usage samples (percent of total), oldest first
+    pub ram_history: VecDeque<f32>,
     /// zkVM task metrics
     pub zkvm_metrics: ZkVMMetrics,
     /// Task fetch information for accurate timing
     pub task_fetch_info: TaskFetchInfo,
+    /// Recent submissions, for throughput over the last hour
+    pub throughput: ThroughputWindow,
+    /// Today's submissions against the daily target, if one is set
+    pub daily_target: Option<DailyTarget>,
+    /// Recent proof durations, for min/max proving time
+    pub proving_times: ProvingTimes,
+    /// Baseline and alert state for proving time regressions
+    pub regression: ProvingRegression,
+    /// Window the displayed throughput and proving time are averaged over
+    pub average_window: AverageWindow,
+    /// Time from proof generation to successful submission
+    pub submission_latency: SubmissionLatency,
+    /// Retries tasks needed before they were submitted
+    pub retry_stats: RetryStats,
     /// Animation tick counter
     pub tick: usize,
 
     /// Timestamp of last successful proof submission
     last_submission_timestamp: Option<String>,
+    /// Time and events since the last successful submission
+    pub since_success: SinceSuccess,
     /// Current fetching state (active, timeout, idle)
     fetching_state: FetchingState,
+    /// Time spent in earlier fetching states
+    fetching_times: FetchingTimes,
+    /// When the current fetching state was entered
+    fetching_since: Instant,
     /// Persistent system info instance for accurate CPU measurements
     sysinfo: System,
+    /// Network interface counters, for bandwidth
+    networks: Networks,
+    /// Hardware sensors, for the CPU temperature
+    components: Components,
+    /// GPU metrics source (reports nothing without the `gpu` feature)
+    pub gpu_monitor: GpuMonitor,
     /// Current prover state from state events
     current_prover_state: ProverState,
-    /// Track when Step 2 started for current task
-    pub step2_start_time: Option<Instant>,
+    /// Per-prover progress, keyed by the index in `Worker::Prover`
+    pub provers: HashMap<usize, ProverMetrics>,
+    /// Peak process RAM during the last proof whose start was observed
+    pub proving_peak_ram_bytes: Option<u64>,
+    /// Peak process RAM this session, unlike the restored all-time peak
+    pub session_peak_ram_bytes: u64,
+    /// Pipeline stage of recently seen tasks
+    pub task_lifecycles: TaskLifecycles,
+    /// Each node's share of the zkVM counters, keyed by node ID
+    pub node_metrics: BTreeMap<u64, NodeMetrics>,
+    /// Whether the zkVM panel shows totals only or a per-node breakdown
+    pub node_view: NodeView,
+    /// Most recent error of each worker type, until cleared
+    pub last_errors: LastErrors,
+    /// Failures since startup, as shown in the header
+    pub failure_badge: FailureBadge,
     /// Track the start time and original wait duration for current waiting period
     pub waiting_start_info: Option<(Instant, u64)>, // (start_time, original_wait_secs)
+    /// When the task fetcher last reported anything
+    pub(super) last_fetcher_activity: Instant,
+    /// Fetcher silence after which the connection is shown as lost
+    pub stale_after: Duration,
+    /// User-set mark for before/after comparison, if any
+    pub mark: Option<MetricsMark>,
+    /// Where to publish metrics snapshots for exporters, if anywhere
+    metrics_publisher: Option<watch::Sender<MetricsSnapshot>>,
+    /// Where to publish dashboard snapshots for the status API, if anywhere
+    pub(super) snapshot_publisher: Option<watch::Sender<DashboardSnapshot>>,
+    /// Where lifetime statistics are persisted, if anywhere
+    stats_path: Option<PathBuf>,
+    /// When lifetime statistics were last saved
+    pub last_stats_save: Instant,
+    /// Desktop notifications for repeated failures, if enabled
+    pub failure_notifier: Option<FailureNotifier>,
+    /// Where submitted proofs are reported, if anywhere
+    pub webhook: Option<WebhookClient>,
+    /// File every activity log event is appended to, while it's writable
+    pub event_log: Option<EventLog>,
+    /// Proving history database, if enabled and it could be opened
+    pub history: Option<History>,
+    /// CSV file of completed tasks, while it's writable
+    pub task_csv: Option<TaskCsv>,
+    /// System log every event is mirrored to, while it's reachable
+    pub syslog: Option<Syslog>,
+    /// Where state dumps go, also written at exit when set
+    pub dump_path: Option<PathBuf>,
+    /// Whether state dumps leave out node IDs
+    pub redact_node_ids: bool,
 }
 
 impl DashboardState {
@@ -79,7 +261,43 @@ impl DashboardState {
         start_time: Instant,
         ui_config: UIConfig,
     ) -> Self {
-        Self {
+        // Pick up lifetime totals from earlier sessions
+        let mut system_metrics = SystemMetrics::default();
+        let mut zkvm_metrics = ZkVMMetrics::default();
+        if let Some(path) = &ui_config.stats_path {
+            let stats = LifetimeStats::load(path);
+            stats.restore(&mut zkvm_metrics, &mut system_metrics);
+            // Files saved before the points total was kept only have the
+            // submission count to go by
+            if stats.total_points.is_none() {
+                zkvm_metrics.total_points =
+                    (zkvm_metrics.tasks_submitted as u64) * ui_config.points_per_task;
+            }
+        }
+
+        // A path that can't be opened is reported once the state exists
+        let (event_log, event_log_error) = match ui_config.event_log.clone().map(EventLog::open) {
+            Some(Ok(event_log)) => (Some(event_log), None),
+            Some(Err(e)) => (None, Some(e)),
+            None => (None, None),
+        };
+        let (history, history_error) = match ui_config.history_db.as_deref().map(History::open) {
+            Some(Ok(history)) => (Some(history), None),
+            Some(Err(e)) => (None, Some(e)),
+            None => (None, None),
+        };
+        let (task_csv, task_csv_error) = match ui_config.task_csv.as_deref().map(TaskCsv::open) {
+            Some(Ok(task_csv)) => (Some(task_csv), None),
+            Some(Err(e)) => (None, Some(e)),
+            None => (None, None),
+        };
+        let (syslog, syslog_error) = match ui_config.syslog.then(Syslog::connect) {
+            Some(Ok(syslog)) => (Some(syslog), None),
+            Some(Err(e)) => (None, Some(e)),
+            None => (None, None),
+        };
+
+        let mut state = Self {
             node_id,
             environment,
             start_time,
@@ -89,21 +307,112 @@ impl DashboardState {
             num_threads: ui_config.num_threads,
             pending_events: VecDeque::new(),
             activity_logs: VecDeque::new(),
+            activity_log_capacity: ui_config.activity_log_capacity,
+            raised_events: VecDeque::new(),
+            scroll_offset: 0,
+            log_page_size: Cell::new(0),
+            log_filter: LogFilter::default(),
             update_available: ui_config.update_available,
             latest_version: ui_config.latest_version,
             with_background_color: ui_config.with_background_color,
+            schedule: ui_config.schedule,
+            show_first_proof: ui_config.show_first_proof,
+            first_proof_at: None,
+            toast: None,
+            clipboard: Clipboard::default(),
+            paused: false,
+            redraw_requested: false,
+            points_per_task: ui_config.points_per_task,
+            fetch_timeout_secs: ui_config.fetch_timeout_secs,
+            stuck_threshold_secs: ui_config.stuck_threshold_secs,
+            stats_at_start: LifetimeStats::capture(&zkvm_metrics, &system_metrics),
 
-            system_metrics: SystemMetrics::default(),
-            zkvm_metrics: ZkVMMetrics::default(),
+            system_metrics,
+            last_system_sample: None,
+            ram_thresholds: ui_config.ram_thresholds,
+            cpu_temp_warn_celsius: ui_config.cpu_temp_warn_celsius,
+            theme: ui_config.theme,
+            metrics_smoothing: ui_config.metrics_smoothing,
+            cpu_history: VecDeque::with_capacity(SYSTEM_HISTORY_SAMPLES),
+            ram_history: VecDeque::with_capacity(SYSTEM_HISTORY_SAMPLES),
+            zkvm_metrics,
             task_fetch_info: TaskFetchInfo::default(),
+            throughput: ThroughputWindow::new(
+                Duration::from_secs(THROUGHPUT_WINDOW_SECS),
+                start_time,
+            ),
+            daily_target: ui_config
+                .daily_target
+                .map(|target| DailyTarget::new(target, Local::now().date_naive())),
+            proving_times: ProvingTimes::new(PROVING_TIMES_CAPACITY),
+            regression: ProvingRegression::new(ui_config.regression),
+            average_window: AverageWindow::default(),
+            submission_latency: SubmissionLatency::new(
+                SUBMISSION_LATENCY_SAMPLES,
+                Duration::from_secs(SUBMISSION_PENDING_TIMEOUT_SECS),
+            ),
+            retry_stats: RetryStats::new(RETRY_STATS_CAPACITY),
             tick: 0,
             last_submission_timestamp: None,
+            since_success: SinceSuccess::default(),
             fetching_state: FetchingState::Idle,
+            fetching_times: FetchingTimes::default(),
+            fetching_since: start_time,
             sysinfo: System::new_all(), // Initialize with all data for first refresh
+            networks: Networks::new_with_refreshed_list(),
+            components: Components::new_with_refreshed_list(),
+            gpu_monitor: GpuMonitor::new(),
             current_prover_state: ProverState::Waiting,
-            step2_start_time: None,
+            provers: HashMap::new(),
+            proving_peak_ram_bytes: None,
+            session_peak_ram_bytes: 0,
+            task_lifecycles: TaskLifecycles::new(TASK_LIFECYCLE_CAPACITY),
+            node_metrics: BTreeMap::new(),
+            node_view: NodeView::default(),
+            last_errors: LastErrors::default(),
+            failure_badge: FailureBadge::default(),
             waiting_start_info: None,
+            last_fetcher_activity: start_time,
+            stale_after: Duration::from_secs(ui_config.stale_threshold_secs),
+            mark: None,
+            metrics_publisher: ui_config.metrics_publisher,
+            snapshot_publisher: ui_config.snapshot_publisher,
+            stats_path: ui_config.stats_path,
+            last_stats_save: Instant::now(),
+            failure_notifier: ui_config.failure_notifier,
+            webhook: ui_config.webhook.as_ref().map(Webhook::subscribe),
+            event_log,
+            history,
+            task_csv,
+            syslog,
+            dump_path: ui_config.dump_state.clone(),
+            redact_node_ids: ui_config.redact_node_ids,
+        };
+        if let (Some(e), Some(config)) = (event_log_error, &ui_config.event_log) {
+            state.raise_event(WorkerEvent::dashboard_with_level(
+                format!("Event log {} disabled: {}", config.path.display(), e),
+                EventType::Error,
+                LogLevel::Error,
+            ));
+        }
+        if let (Some(e), Some(path)) = (history_error, &ui_config.history_db) {
+            state.raise_event(WorkerEvent::dashboard_with_level(
+                format!("History database {} disabled: {}", path.display(), e),
+                EventType::Error,
+                LogLevel::Error,
+            ));
         }
+        if let (Some(e), Some(path)) = (task_csv_error, &ui_config.task_csv) {
+            state.raise_event(WorkerEvent::dashboard_with_level(
+                format!("Task CSV {} disabled: {}", path.display(), e),
+                EventType::Error,
+                LogLevel::Error,
+            ));
+        }
+        if let Some(e) = syslog_error {
+            state.raise_event(syslog_disabled_event(&e));
+        }
+        state
     }
     // Getter methods for private fields
     pub fn fetching_state(&self) -> &FetchingState {
@@ -114,9 +423,27 @@ impl DashboardState {
         &self.last_submission_timestamp
     }
 
+    /// Time spent in each fetching state so far, including the current one
+    pub fn fetching_times(&self, now: Instant) -> FetchingTimes {
+        let mut times = self.fetching_times;
+        times.add(
+            &self.fetching_state,
+            now.saturating_duration_since(self.fetching_since),
+        );
+        times
+    }
+
     // Setter methods for private fields (for updaters)
     pub fn set_fetching_state(&mut self, state: FetchingState) {
+        self.set_fetching_state_at(state, Instant::now());
+    }
+
+    /// Enter a fetching state at `now`, crediting the time spent in the previous one
+    pub fn set_fetching_state_at(&mut self, state: FetchingState, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.fetching_since);
+        self.fetching_times.add(&self.fetching_state, elapsed);
         self.fetching_state = state;
+        self.fetching_since = now;
     }
 
     pub fn current_prover_state(&self) -> ProverState {
@@ -127,20 +454,125 @@ impl DashboardState {
         self.current_prover_state = state;
     }
 
+    /// How long `current_task` has been proving, timed from its own prover's
+    /// Step 2. `None` before its proof starts (so a new task never inherits the
+    /// previous one's start) and once its proof is generated.
+    pub fn current_task_elapsed(&self, now: Instant) -> Option<Duration> {
+        let task_id = self.current_task.as_deref()?;
+        self.provers
+            .values()
+            .filter(|prover| prover.current_task.as_deref() == Some(task_id))
+            .filter_map(|prover| prover.proving_since)
+            .min()
+            .map(|since| now.saturating_duration_since(since))
+    }
+
+    /// Whether the schedule is holding proving back right now. Pausing the
+    /// display doesn't count: proving carries on behind a held screen.
+    pub fn schedule_paused(&self) -> bool {
+        self.schedule
+            .as_ref()
+            .and_then(|schedule| schedule.current_setting())
+            .is_some_and(|setting| setting.is_paused())
+    }
+
+    /// Record the wall-clock time of the last submission, for display only
     pub fn set_last_submission_timestamp(&mut self, timestamp: Option<String>) {
         self.last_submission_timestamp = timestamp;
     }
 
-    pub fn get_sysinfo_mut(&mut self) -> &mut System {
-        &mut self.sysinfo
+    /// Whether the first-proof confirmation should currently be shown
+    pub fn first_proof_banner_visible(&self) -> bool {
+        self.first_proof_at
+            .is_some_and(|at| at.elapsed().as_secs() < FIRST_PROOF_BANNER_SECS)
+    }
+
+    /// Copy of the current metrics
+    pub fn metrics_snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            zkvm: self.zkvm_metrics.clone(),
+            system: self.system_metrics.clone(),
+        }
+    }
+
+    /// Publish the current metrics to exporters, if any are attached
+    pub fn publish_metrics(&self) {
+        if let Some(publisher) = &self.metrics_publisher {
+            publisher.send_replace(self.metrics_snapshot());
+        }
+    }
+
+    /// Save the cumulative counters, if a stats file is configured
+    pub fn save_stats(&mut self) {
+        self.last_stats_save = Instant::now();
+        if let Some(path) = &self.stats_path {
+            // Best effort: a failed save is retried on the next interval
+            let _ = LifetimeStats::capture(&self.zkvm_metrics, &self.system_metrics).save(path);
+        }
+    }
+
+    /// Zero the lifetime counters and delete the stats file, as `--reset-stats`
+    /// would on startup. The session summary then counts from the reset.
+    pub fn reset_stats(&mut self) -> std::io::Result<()> {
+        let zero = LifetimeStats::default();
+        zero.restore(&mut self.zkvm_metrics, &mut self.system_metrics);
+        self.zkvm_metrics.total_points = 0;
+        self.stats_at_start = zero;
+        match &self.stats_path {
+            Some(path) => LifetimeStats::clear(path),
+            None => Ok(()),
+        }
+    }
+
+    /// Get mutable access to the system, network and sensor info sampled together
+    pub fn get_system_sources_mut(&mut self) -> (&mut System, &mut Networks, &mut Components) {
+        (&mut self.sysinfo, &mut self.networks, &mut self.components)
     }
 
-    /// Add an event to activity logs with size limit
+    /// Add an event to activity logs with size limit, collapsing it into the
+    /// previous entry if it repeats that
     pub fn add_to_activity_log(&mut self, event: WorkerEvent) {
-        if self.activity_logs.len() >= MAX_ACTIVITY_LOGS {
-            self.activity_logs.pop_front();
+        if let Some(event_log) = &self.event_log {
+            event_log.record(&event);
+        }
+        if let Some(syslog) = &mut self.syslog {
+            // One warning rather than a failure per event
+            if let Err(e) = syslog.forward(&event) {
+                self.syslog = None;
+                self.raise_event(syslog_disabled_event(&e));
+            }
         }
-        self.activity_logs.push_back(event);
+        self.last_errors.record(&event);
+        if let Some(last) = self.activity_logs.back_mut() {
+            if last.is_repeated_by(&event) {
+                last.repeat(event);
+                self.track_log_scroll(false);
+                return;
+            }
+        }
+        let visible = event.should_display() && self.log_filter.matches(&event);
+        push_bounded(
+            &mut self.activity_logs,
+            self.activity_log_capacity,
+            LogEntry::new(event),
+        );
+        self.track_log_scroll(visible);
+    }
+
+    /// Log an event raised by the dashboard itself rather than a worker. It
+    /// bypasses event processing, so it never counts towards the failure metrics.
+    pub fn raise_event(&mut self, event: WorkerEvent) {
+        push_bounded(
+            &mut self.raised_events,
+            self.activity_log_capacity,
+            event.clone(),
+        );
+        self.add_to_activity_log(event);
+    }
+
+    /// Take the events raised since the last call
+    pub fn take_raised_events(&mut self) -> Vec<WorkerEvent> {
+        self.raised_events.drain(..).collect()
     }
 
     /// Add an event to the processing queue
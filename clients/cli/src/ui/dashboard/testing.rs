@@ -0,0 +1,86 @@
+//! Test helpers
+//!
+//! A dashboard with default settings and builders for the events workers send,
+//! shared by the dashboard modules' tests.
+
+use super::state::DashboardState;
+use crate::environment::Environment;
+use crate::events::{Event as WorkerEvent, EventType, ProofPhase, ProverState, Worker};
+use crate::logging::LogLevel;
+use crate::ui::app::UIConfig;
+use std::time::Instant;
+
+/// A dashboard for node 1 with one prover and default settings
+pub(crate) fn test_state() -> DashboardState {
+    test_state_with(UIConfig::new(false, 1, false, None))
+}
+
+/// A dashboard for node 1 with the given settings
+pub(crate) fn test_state_with(ui_config: UIConfig) -> DashboardState {
+    DashboardState::new(Some(1), Environment::Production, Instant::now(), ui_config)
+}
+
+/// A numbered fetcher log line, for filling the activity log
+pub(crate) fn log_event(i: usize) -> WorkerEvent {
+    WorkerEvent::task_fetcher_with_level(format!("event {}", i), EventType::Refresh, LogLevel::Info)
+}
+
+/// Step 1: the fetcher got a task
+pub(crate) fn got_task(task_id: &str) -> WorkerEvent {
+    WorkerEvent::task_fetcher_with_level(
+        format!("Step 1 of 4: Got task {}", task_id),
+        EventType::Success,
+        LogLevel::Info,
+    )
+    .with_phase(ProofPhase::Fetched {
+        task_id: task_id.to_string(),
+    })
+}
+
+/// Step 2: a prover started proving a task
+pub(crate) fn proving(prover: usize, task_id: &str) -> WorkerEvent {
+    WorkerEvent::state_change(
+        ProverState::Proving,
+        format!("Step 2 of 4: Proving task {}", task_id),
+    )
+    .with_phase(ProofPhase::Proving {
+        task_id: task_id.to_string(),
+    })
+    .with_worker(Worker::Prover(prover))
+}
+
+/// Step 3: prover 0 generated a proof of unknown size
+pub(crate) fn proof_generated(task_id: &str) -> WorkerEvent {
+    WorkerEvent::prover_with_level(
+        0,
+        format!("Step 3 of 4: Proof generated for task {}", task_id),
+        EventType::Success,
+        LogLevel::Info,
+    )
+    .with_phase(ProofPhase::ProofGenerated {
+        task_id: task_id.to_string(),
+        proof_bytes: None,
+    })
+}
+
+/// Step 4: the submitter had a proof accepted, attempts unreported
+pub(crate) fn proof_submitted(task_id: &str) -> WorkerEvent {
+    WorkerEvent::proof_submitter_with_level(
+        format!(
+            "Step 4 of 4: Proof submitted successfully for task {}\n",
+            task_id
+        ),
+        EventType::Success,
+        LogLevel::Info,
+    )
+    .with_phase(ProofPhase::Submitted {
+        task_id: task_id.to_string(),
+        attempts: None,
+    })
+}
+
+/// `event` as if it had been emitted at `at`
+pub(crate) fn emitted_at(mut event: WorkerEvent, at: Instant) -> WorkerEvent {
+    event.emitted_at = at;
+    event
+}
@@ -0,0 +1,86 @@
+//! Footer messages and copying the task ID
+//!
+//! Short confirmations (e.g. "copied") take over the footer for a few seconds.
+//! Text the user needs to read or select stays until the next key press.
+
+use super::state::DashboardState;
+use crate::consts::cli_consts::TOAST_SECS;
+use std::time::Instant;
+
+/// A message shown in place of the footer's key help
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Toast {
+    pub message: String,
+    /// Incidental messages are drawn muted
+    pub subtle: bool,
+    /// When a timed message was shown; `None` keeps it until the next key press
+    pub shown_at: Option<Instant>,
+}
+
+impl Toast {
+    /// Whether the message should still be shown
+    pub fn is_visible(&self) -> bool {
+        self.shown_at
+            .is_none_or(|at| at.elapsed().as_secs() < TOAST_SECS)
+    }
+}
+
+impl DashboardState {
+    /// The footer message currently showing, if any
+    pub fn visible_toast(&self) -> Option<&Toast> {
+        self.toast.as_ref().filter(|toast| toast.is_visible())
+    }
+
+    /// Copy the current task's ID to the clipboard. Without a clipboard the ID
+    /// is shown in the footer instead, until the next key press.
+    pub fn copy_current_task(&mut self) {
+        let Some(task_id) = self.current_task.clone() else {
+            self.toast = Some(Toast {
+                message: "No current task to copy".to_string(),
+                subtle: true,
+                shown_at: Some(Instant::now()),
+            });
+            return;
+        };
+
+        self.toast = Some(match self.clipboard.copy(&task_id) {
+            Ok(()) => Toast {
+                message: format!("Copied task {} to the clipboard", task_id),
+                subtle: false,
+                shown_at: Some(Instant::now()),
+            },
+            Err(e) => Toast {
+                message: format!("Task {} (clipboard unavailable: {})", task_id, e),
+                subtle: false,
+                shown_at: None,
+            },
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::dashboard::testing::test_state;
+    use crossterm::event::KeyCode;
+
+    #[test]
+    fn test_copy_shows_the_task_id_and_ignores_no_task() {
+        let mut state = test_state();
+
+        state.handle_key(KeyCode::Char('y'));
+        let toast = state.visible_toast().unwrap();
+        assert!(toast.subtle);
+        assert!(toast.shown_at.is_some());
+
+        // Copied, or failing that shown for the user to select
+        state.current_task = Some("task-42".to_string());
+        state.handle_key(KeyCode::Char('y'));
+        let toast = state.visible_toast().unwrap();
+        assert!(toast.message.contains("task-42"));
+        assert!(!toast.subtle);
+
+        // Any other key dismisses it
+        state.handle_key(KeyCode::Char('w'));
+        assert!(state.visible_toast().is_none());
+    }
+}
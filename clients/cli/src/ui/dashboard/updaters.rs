@@ -2,49 +2,188 @@
 //!
 //! Contains all methods for updating dashboard state from events
 
+use super::lifecycle::Transition;
+use super::nodes::NodeMetrics;
 use super::state::{DashboardState, FetchingState};
 
-use crate::events::{Event as WorkerEvent, EventType, Worker};
-use crate::ui::metrics::{SystemMetrics, TaskFetchInfo};
+use crate::consts::cli_consts::{
+    STATS_SAVE_INTERVAL_SECS, SYSTEM_HISTORY_SAMPLES, SYSTEM_SAMPLE_INTERVAL_MS,
+};
+use crate::events::{Event as WorkerEvent, EventType, ProofPhase, Worker};
+use crate::logging::LogLevel;
+use crate::ui::metrics::{SystemMetrics, TaskFetchInfo, TaskStatus, push_bounded};
+use crate::webhook::SubmissionRecord;
 
-use std::time::Instant;
+use chrono::Local;
+use std::time::{Duration, Instant};
 
 impl DashboardState {
     /// Update the dashboard state with new tick and metrics.
     pub fn update(&mut self) {
         self.tick += 1;
+        self.sample_system_metrics(Instant::now());
+
+        self.process_pending_events();
+        self.log_webhook_failures();
+        self.check_event_log();
+        self.check_history();
+        self.check_task_csv();
+        self.throughput.prune(Instant::now());
+        if let Some(daily_target) = &mut self.daily_target {
+            daily_target.roll_over(Local::now().date_naive());
+        }
+
+        // Handle timeout logic (doesn't need events)
+        self.check_fetching_timeout();
+        self.check_stuck_provers(Instant::now());
+        self.check_dropped_tasks(Instant::now());
+        self.check_proving_regression(Instant::now());
+        self.update_failure_badge(Instant::now());
+
+        // Update task fetch info based on current state
+        self.update_task_fetch_countdown();
+
+        self.publish_metrics();
+        self.publish_snapshot();
+
+        if self.last_stats_save.elapsed().as_secs() >= STATS_SAVE_INTERVAL_SECS {
+            self.save_stats();
+        }
+    }
+
+    /// Sample system metrics if the sampling interval has passed. Redraws can be
+    /// far more frequent, and sampling every one of them would cost more CPU
+    /// than the dashboard is worth.
+    pub fn sample_system_metrics(&mut self, now: Instant) {
+        let interval = Duration::from_millis(SYSTEM_SAMPLE_INTERVAL_MS);
+        if self
+            .last_system_sample
+            .is_some_and(|at| now.saturating_duration_since(at) < interval)
+        {
+            return;
+        }
+        self.last_system_sample = Some(now);
 
         // Update system metrics using persistent sysinfo instance for accurate CPU measurements
         let previous_peak = self.system_metrics.peak_ram_bytes;
         let previous_metrics = self.system_metrics.clone();
+        let gpu = self.gpu_monitor.sample();
+        let smoothing = self.metrics_smoothing;
+        let (sysinfo, networks, components) = self.get_system_sources_mut();
         self.system_metrics = SystemMetrics::update(
-            self.get_sysinfo_mut(),
+            sysinfo,
+            networks,
+            components,
             previous_peak,
             Some(&previous_metrics),
+            gpu,
+            smoothing,
         );
+        self.record_system_history();
+        self.track_peak_ram();
+        if let Some(history) = &mut self.history {
+            history.sample_system(now, &self.system_metrics);
+        }
+    }
 
-        // Process all queued events one by one
-        while let Some(event) = self.pending_events.pop_front() {
-            // Add to activity logs for display
-            self.add_to_activity_log(event.clone());
+    /// Append the current CPU and RAM usage to their sparkline histories
+    pub fn record_system_history(&mut self) {
+        let cpu = self.system_metrics.cpu_percent;
+        let ram = (self.system_metrics.ram_ratio() * 100.0) as f32;
+        for (history, sample) in [(&mut self.cpu_history, cpu), (&mut self.ram_history, ram)] {
+            push_bounded(history, SYSTEM_HISTORY_SAMPLES, sample);
+        }
+    }
 
-            // Process the event for state updates
+    /// Apply only the events queued since the last tick to the running metrics.
+    /// Returns how many events were processed, so per-tick work is O(new events).
+    pub fn process_pending_events(&mut self) -> usize {
+        let mut processed = 0;
+        while let Some(event) = self.pending_events.pop_front() {
+            // Process the event for state updates, then keep it for display
             self.process_event(&event);
+            self.add_to_activity_log(event);
+            processed += 1;
         }
+        processed
+    }
 
-        // Handle timeout logic (doesn't need events)
-        self.check_fetching_timeout();
+    /// Raise webhook delivery failures as errors (not counted as submit failures)
+    pub fn log_webhook_failures(&mut self) {
+        let Some(webhook) = &mut self.webhook else {
+            return;
+        };
+        for msg in webhook.failures() {
+            self.raise_event(WorkerEvent::dashboard_with_level(
+                msg,
+                EventType::Error,
+                LogLevel::Error,
+            ));
+        }
+    }
 
-        // Update task fetch info based on current state
-        self.update_task_fetch_countdown();
+    /// Stop file logging once its writer has failed, reporting why
+    fn check_event_log(&mut self) {
+        let Some(failure) = self.event_log.as_ref().and_then(|log| log.failure()) else {
+            return;
+        };
+        self.event_log = None;
+        self.raise_event(WorkerEvent::dashboard_with_level(
+            failure,
+            EventType::Error,
+            LogLevel::Error,
+        ));
+    }
+
+    /// Stop recording history once its writer has failed, reporting why
+    fn check_history(&mut self) {
+        let Some(failure) = self.history.as_ref().and_then(|history| history.failure()) else {
+            return;
+        };
+        self.history = None;
+        self.raise_event(WorkerEvent::dashboard_with_level(
+            failure,
+            EventType::Error,
+            LogLevel::Error,
+        ));
+    }
+
+    /// Stop writing the task CSV once its writer has failed, reporting why
+    fn check_task_csv(&mut self) {
+        let Some(failure) = self.task_csv.as_ref().and_then(|csv| csv.failure()) else {
+            return;
+        };
+        self.task_csv = None;
+        self.raise_event(WorkerEvent::dashboard_with_level(
+            failure,
+            EventType::Error,
+            LogLevel::Error,
+        ));
     }
 
     /// Process a single event and update relevant state
     fn process_event(&mut self, event: &WorkerEvent) {
-        match event.worker {
-            Worker::TaskFetcher => self.handle_task_fetcher_event(event),
-            Worker::Prover(_) => self.handle_prover_event(event),
-            Worker::ProofSubmitter => self.handle_proof_submitter_event(event),
+        let counters_before = NodeMetrics::capture(&self.zkvm_metrics);
+        self.since_success.record_event();
+        if event.worker == Worker::TaskFetcher {
+            self.record_fetcher_activity(event.emitted_at);
+        }
+
+        // Pipeline progress comes from the structured phase, never from the message
+        // text; a repeat of the stage the task is already in isn't counted again
+        let transition = self.advance_lifecycle(event);
+        if let Some(phase) = &event.phase {
+            if transition != Transition::Repeated {
+                self.handle_phase(phase, event);
+            }
+        }
+
+        if event.event_type == EventType::Error {
+            self.handle_error(event);
+        }
+
+        if let Some(notifier) = &mut self.failure_notifier {
+            notifier.record(event, event.emitted_at);
         }
 
         // Handle state changes regardless of worker
@@ -53,90 +192,228 @@ impl DashboardState {
                 self.set_current_prover_state(state);
             }
         }
+
+        self.attribute_to_node(event, counters_before);
     }
 
-    /// Handle TaskFetcher events
-    fn handle_task_fetcher_event(&mut self, event: &WorkerEvent) {
-        // Handle task ID extraction from "Got task" success events
-        if matches!(event.event_type, EventType::Success)
-            && event.msg.contains("Step 1 of 4: Got task")
-        {
-            if let Some(task_id) = Self::extract_task_id(&event.msg) {
+    /// Handle a pipeline phase transition. Durations are measured from the
+    /// event's monotonic `emitted_at`; its wall-clock `timestamp` is only
+    /// passed on for display and records.
+    fn handle_phase(&mut self, phase: &ProofPhase, event: &WorkerEvent) {
+        let at = event.emitted_at;
+        match phase {
+            ProofPhase::Requesting => {
+                if !matches!(self.fetching_state(), FetchingState::Active { .. }) {
+                    self.set_fetching_state(FetchingState::Active { started_at: at });
+                }
+            }
+            ProofPhase::Backoff { wait_secs } => {
+                self.set_fetching_state(FetchingState::Idle);
+
+                // Every backoff event is its own waiting period, timed from when it was
+                // emitted, even when consecutive periods have the same length; a repeat
+                // of the same event leaves the countdown where it is
+                self.waiting_start_info = Some((at, *wait_secs));
+            }
+            ProofPhase::Fetched { task_id } => {
+                self.set_fetching_state(FetchingState::Idle);
                 self.last_task = self.current_task.clone();
-                self.current_task = Some(task_id);
+                self.current_task = Some(task_id.clone());
 
-                // Count this as a task fetch if we haven't seen this task before
                 self.zkvm_metrics.tasks_fetched += 1;
-                // Track Step 2 start (proving begins at the end of Step 1)
-                self.step2_start_time = Some(Instant::now());
+                self.retry_stats.fetched(task_id);
+                if let Some(history) = &mut self.history {
+                    history.task_fetched(task_id, &event.timestamp);
+                }
+                if let Some(csv) = &mut self.task_csv {
+                    csv.task_fetched(task_id, &event.timestamp);
+                }
             }
-        }
+            ProofPhase::Proving { task_id } => {
+                // Covers a missed fetch event (dashboard attached between steps)
+                if self.current_task.as_ref() != Some(task_id) {
+                    self.last_task = self.current_task.replace(task_id.clone());
+                }
 
-        // Handle fetching state changes
-        if Self::is_completion_event(event) {
-            self.set_fetching_state(FetchingState::Idle);
-        } else if Self::is_fetching_start_event(event)
-            && !matches!(self.fetching_state(), FetchingState::Active { .. })
-        {
-            self.set_fetching_state(FetchingState::Active {
-                started_at: Instant::now(),
-            });
-        }
+                // Time each prover on its own, so overlapping proofs don't clobber each other
+                if let Worker::Prover(id) = event.worker {
+                    let prover = self.provers.entry(id).or_default();
+                    prover.current_task = Some(task_id.clone());
+                    prover.last_status = TaskStatus::Proving;
+                    prover.proving_since = Some(at);
+                    prover.last_proved = None;
+                    prover.proving_peak_ram_bytes = self.system_metrics.sampled_ram_bytes;
+                }
+            }
+            ProofPhase::ProofGenerated {
+                task_id,
+                proof_bytes,
+            } => {
+                self.submission_latency.generated(task_id, at);
+                let elapsed = self.handle_proof_generated(event.worker, task_id, *proof_bytes, at);
+                if let Some(history) = &mut self.history {
+                    history.task_proved(task_id, &event.timestamp, elapsed);
+                }
+                if let Some(csv) = &mut self.task_csv {
+                    csv.task_proved(task_id, elapsed);
+                }
+            }
+            ProofPhase::Submitted { task_id, attempts } => {
+                let latency = self.submission_latency.submitted(task_id, at);
+                let points = self.points_per_task;
+                self.retry_stats.submitted(task_id, *attempts, at);
+                if let Some(history) = &mut self.history {
+                    history.task_submitted(task_id, &event.timestamp);
+                }
+                if let Some(csv) = &mut self.task_csv {
+                    csv.task_submitted(task_id, latency, points);
+                }
+                self.zkvm_metrics.tasks_submitted += 1;
+                self.throughput.record(at, points);
+                if let Some(daily_target) = &mut self.daily_target {
+                    daily_target.record(Local::now().naive_local());
+                }
+                if self.zkvm_metrics.tasks_submitted == self.stats_at_start.tasks_submitted + 1
+                    && self.show_first_proof
+                {
+                    self.first_proof_at = Some(at);
+                }
+
+                self.zkvm_metrics.last_task_status = TaskStatus::Success;
+                self.set_last_submission_timestamp(Some(event.timestamp.clone()));
+                self.since_success.succeeded(at);
 
-        // Handle waiting messages for task fetch info
-        if event.msg.contains("ready for next task") {
-            if let Some(seconds) = Self::extract_wait_seconds(&event.msg) {
-                let is_same_message = match &self.waiting_start_info {
-                    Some((_, prev_wait)) => *prev_wait == seconds,
-                    None => false,
-                };
+                self.zkvm_metrics.total_points += points;
 
-                if !is_same_message {
-                    self.waiting_start_info = Some((Instant::now(), seconds));
+                if let Some(webhook) = &mut self.webhook {
+                    webhook.send(SubmissionRecord {
+                        task_id: task_id.clone(),
+                        timestamp: event.timestamp.clone(),
+                        total_points: self.zkvm_metrics.total_points,
+                    });
                 }
             }
+            // Failures are error events, counted by `handle_error`
+            ProofPhase::ProofFailed { .. } | ProofPhase::SubmitFailed { .. } => {}
         }
     }
 
-    /// Handle Prover events
-    fn handle_prover_event(&mut self, event: &WorkerEvent) {
-        if matches!(event.event_type, EventType::Success) {
-            // Track Step 3 completion (proof generated)
-            if event.msg.contains("Step 3 of 4: Proof generated for task") {
-                if let Some(start_time) = self.step2_start_time {
-                    self.zkvm_metrics.zkvm_runtime_secs += start_time.elapsed().as_secs();
-                    self.zkvm_metrics.last_task_status = "Proved".to_string();
-                    self.step2_start_time = None;
-                }
+    /// Count a generated proof (reported at `at`), timing it against the start
+    /// of the same prover's proof. Returns how long it took, when that was observed.
+    fn handle_proof_generated(
+        &mut self,
+        worker: Worker,
+        task_id: &str,
+        proof_bytes: Option<u64>,
+        at: Instant,
+    ) -> Option<Duration> {
+        let mut elapsed = None;
+        if let Worker::Prover(id) = worker {
+            let prover = self.provers.entry(id).or_default();
+            // A repeat of the proof this prover just finished is not a new proof
+            if prover.last_proved.as_deref() == Some(task_id) {
+                return None;
+            }
+
+            // Only the task this prover started consumes its timer; an out-of-order
+            // Step 3 stays untimed instead of ending another proof's timer
+            if prover.current_task.as_deref() == Some(task_id) {
+                elapsed = prover
+                    .proving_since
+                    .take()
+                    .map(|start_time| at.saturating_duration_since(start_time));
+                prover.current_task = None;
+                self.proving_peak_ram_bytes = Some(
+                    prover
+                        .proving_peak_ram_bytes
+                        .max(self.system_metrics.sampled_ram_bytes),
+                );
+            }
+            if prover.current_task.is_none() {
+                prover.last_status = TaskStatus::Proved;
             }
-        } else if matches!(event.event_type, EventType::Error) {
-            self.zkvm_metrics.last_task_status = "Proof Failed".to_string();
-            self.step2_start_time = None; // Clear timing for failed proof
+            prover.runtime_secs += elapsed.map_or(0, |elapsed| elapsed.as_secs());
+            prover.tasks_proved += 1;
+            prover.last_proved = Some(task_id.to_string());
+        }
+
+        if let Some(elapsed) = elapsed {
+            self.zkvm_metrics.zkvm_runtime_secs += elapsed.as_secs();
+            self.proving_times.record(at, elapsed.as_secs());
+            self.regression.record(worker, elapsed.as_secs());
+        } else {
+            // The start of this task was never observed (e.g. the dashboard
+            // attached mid-proof), so count the proof but flag its timing as missing
+            self.zkvm_metrics.untimed_proofs += 1;
         }
+        // Events from older provers carry no size and leave the average alone
+        if let Some(proof_bytes) = proof_bytes {
+            self.zkvm_metrics.total_proof_bytes += proof_bytes;
+            self.zkvm_metrics.sized_proofs += 1;
+        }
+        self.zkvm_metrics.tasks_proved += 1;
+        self.zkvm_metrics.last_task_status = TaskStatus::Proved;
+        elapsed
     }
 
-    /// Handle ProofSubmitter events
-    fn handle_proof_submitter_event(&mut self, event: &WorkerEvent) {
-        if matches!(event.event_type, EventType::Success)
-            && event
-                .msg
-                .contains("Step 4 of 4: Proof submitted successfully")
-        {
-            // If we see a Step 4 completion but have fewer fetched tasks,
-            // it means we missed earlier events (dashboard started after task began)
-            self.zkvm_metrics.tasks_submitted += 1;
-            self.zkvm_metrics.tasks_fetched = self
-                .zkvm_metrics
-                .tasks_fetched
-                .max(self.zkvm_metrics.tasks_submitted);
+    /// Handle an error reported by any worker
+    fn handle_error(&mut self, event: &WorkerEvent) {
+        match (event.worker, event.task_id()) {
+            (Worker::TaskFetcher, _) => self.retry_stats.fetch_failed(),
+            (_, Some(task_id)) => self.retry_stats.failed(task_id),
+            (_, None) => {}
+        }
+        match event.worker {
+            Worker::TaskFetcher => self.set_fetching_state(FetchingState::Idle),
+            Worker::Prover(id) => {
+                self.zkvm_metrics.proof_failures += 1;
+                self.zkvm_metrics.last_task_status = TaskStatus::ProofFailed;
 
-            self.zkvm_metrics.last_task_status = "Success".to_string();
-            self.set_last_submission_timestamp(Some(event.timestamp.clone()));
+                // Clear timing for the failed proof, unless the error names an
+                // earlier task than the one this prover is working on now
+                let prover = self.provers.entry(id).or_default();
+                let failed_task = event.task_id();
+                if failed_task.is_none() || failed_task == prover.current_task.as_deref() {
+                    prover.proving_since = None;
+                    prover.current_task = None;
+                }
+                prover.last_status = TaskStatus::ProofFailed;
+            }
+            Worker::ProofSubmitter => {
+                self.zkvm_metrics.submit_failures += 1;
+                self.zkvm_metrics.last_task_status = TaskStatus::SubmitFailed;
+            }
+            Worker::Dashboard => {}
+        }
+    }
 
-            // Update total points
-            self.zkvm_metrics._total_points = (self.zkvm_metrics.tasks_submitted as u64) * 300;
-        } else if matches!(event.event_type, EventType::Error) {
-            self.zkvm_metrics.last_task_status = "Submit Failed".to_string();
+    /// Flag provers whose current proof has run past the stuck threshold,
+    /// raising one error per stuck proof. The timer keeps running, so a proof
+    /// that does finish is still timed.
+    pub fn check_stuck_provers(&mut self, now: Instant) {
+        let threshold = Duration::from_secs(self.stuck_threshold_secs);
+        let mut stuck = Vec::new();
+        for (&id, prover) in self.provers.iter_mut() {
+            let Some(since) = prover.proving_since else {
+                continue;
+            };
+            let elapsed = now.saturating_duration_since(since);
+            if elapsed >= threshold && prover.last_status != TaskStatus::Stuck {
+                prover.last_status = TaskStatus::Stuck;
+                stuck.push(WorkerEvent::prover_with_level(
+                    id,
+                    format!(
+                        "Prover stuck: task {} has been proving for {}s",
+                        prover.current_task.as_deref().unwrap_or("unknown"),
+                        elapsed.as_secs()
+                    ),
+                    EventType::Error,
+                    LogLevel::Error,
+                ));
+            }
+        }
+        for event in stuck {
+            self.raise_event(event);
         }
     }
 
@@ -169,49 +446,833 @@ impl DashboardState {
     /// Check for fetching timeout (doesn't need events)
     fn check_fetching_timeout(&mut self) {
         if let FetchingState::Active { started_at } = self.fetching_state() {
-            if started_at.elapsed().as_secs() > 5 {
+            if started_at.elapsed().as_secs() > self.fetch_timeout_secs {
                 self.set_fetching_state(FetchingState::Timeout);
             }
         }
     }
 }
 
-// Helper functions for event parsing
-impl DashboardState {
-    /// Extract task ID from message. Expected format: "Step 1 of 4: Got task TASK_ID"
-    fn extract_task_id(msg: &str) -> Option<String> {
-        let pattern = "Got task ";
-        let start = msg.find(pattern)?;
-        let task_id_start = start + pattern.len();
-        let remaining = &msg[task_id_start..];
-
-        // Find end of task ID (space, newline, or end of string)
-        if let Some(end) = remaining.find(|c: char| c.is_whitespace() || c == '\n') {
-            Some(remaining[..end].to_string())
-        } else if !remaining.is_empty() {
-            Some(remaining.to_string())
-        } else {
-            None
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_log::{EventLogConfig, LogFormat};
+    use crate::events::ProverState;
+    use crate::logging::LogLevel;
+    use crate::stats::LifetimeStats;
+    use crate::ui::app::UIConfig;
+    use crate::ui::dashboard::testing::{
+        got_task, proof_generated, proof_submitted, proving, test_state, test_state_with,
+    };
+    use crate::webhook::Webhook;
+    use crossterm::event::KeyCode;
+
+    #[test]
+    fn test_proof_without_observed_start_is_flagged() {
+        let mut state = test_state();
+
+        state.process_event(&proof_generated("task-1"));
+
+        assert_eq!(state.zkvm_metrics.untimed_proofs, 1);
+        assert_eq!(state.zkvm_metrics.zkvm_runtime_secs, 0);
+        assert_eq!(state.zkvm_metrics.last_task_status, TaskStatus::Proved);
+    }
+
+    #[test]
+    fn test_only_proofs_with_a_size_count_towards_the_average() {
+        let mut state = test_state();
+        let sized = |task_id: &str, proof_bytes: u64| {
+            let mut event = proof_generated(task_id);
+            event.phase = Some(ProofPhase::ProofGenerated {
+                task_id: task_id.to_string(),
+                proof_bytes: Some(proof_bytes),
+            });
+            event
+        };
+
+        state.process_event(&proof_generated("task-1"));
+        assert_eq!(state.zkvm_metrics.avg_proof_bytes(), None);
+
+        state.process_event(&sized("task-2", 1000));
+        state.process_event(&proof_generated("task-3"));
+        state.process_event(&sized("task-4", 3000));
+
+        assert_eq!(state.zkvm_metrics.tasks_proved, 4);
+        assert_eq!(state.zkvm_metrics.sized_proofs, 2);
+        assert_eq!(state.zkvm_metrics.total_proof_bytes, 4000);
+        assert_eq!(state.zkvm_metrics.avg_proof_bytes(), Some(2000));
+    }
+
+    #[test]
+    fn test_proof_with_observed_start_is_timed() {
+        let mut state = test_state();
+
+        state.process_event(&got_task("task-1"));
+        state.process_event(&proving(0, "task-1"));
+        state.process_event(&proof_generated("task-1"));
+
+        assert_eq!(state.zkvm_metrics.untimed_proofs, 0);
+        assert!(state.provers[&0].proving_since.is_none());
+        assert_eq!(state.zkvm_metrics.last_task_status, TaskStatus::Proved);
+    }
+
+    #[test]
+    fn test_first_submission_shows_confirmation_once() {
+        let mut state = test_state();
+
+        state.process_event(&proof_submitted("task-1"));
+        assert!(state.first_proof_banner_visible());
+
+        state.handle_key(KeyCode::Enter);
+        assert!(!state.first_proof_banner_visible());
+
+        state.process_event(&proof_submitted("task-2"));
+        assert!(!state.first_proof_banner_visible());
+    }
+
+    #[test]
+    fn test_first_submission_confirmation_can_be_suppressed() {
+        let mut state =
+            test_state_with(UIConfig::new(false, 1, false, None).with_first_proof_banner(false));
+
+        state.process_event(&proof_submitted("task-1"));
+        assert!(!state.first_proof_banner_visible());
+    }
+
+    #[test]
+    fn test_requesting_phase_starts_fetching() {
+        let mut state = test_state();
+
+        state.process_event(
+            &WorkerEvent::task_fetcher_with_level(
+                "Asking the orchestrator for work".to_string(),
+                EventType::Refresh,
+                LogLevel::Info,
+            )
+            .with_phase(ProofPhase::Requesting),
+        );
+
+        assert!(matches!(
+            state.fetching_state(),
+            FetchingState::Active { .. }
+        ));
+    }
+
+    #[test]
+    fn test_fetch_timeout_is_configurable() {
+        let slow_fetch = FetchingState::Active {
+            started_at: Instant::now() - Duration::from_secs(10),
+        };
+
+        let mut state = test_state();
+        state.set_fetching_state(slow_fetch.clone());
+        state.check_fetching_timeout();
+        assert!(matches!(state.fetching_state(), FetchingState::Timeout));
+
+        let mut state =
+            test_state_with(UIConfig::new(false, 1, false, None).with_fetch_timeout(30));
+        state.set_fetching_state(slow_fetch);
+        state.check_fetching_timeout();
+        assert!(matches!(
+            state.fetching_state(),
+            FetchingState::Active { .. }
+        ));
+    }
+
+    #[test]
+    fn test_backoff_phase_starts_countdown() {
+        let mut state = test_state();
+
+        state.process_event(
+            &WorkerEvent::task_fetcher_with_level(
+                "Taking a short break".to_string(),
+                EventType::Waiting,
+                LogLevel::Info,
+            )
+            .with_phase(ProofPhase::Backoff { wait_secs: 42 }),
+        );
+
+        assert_eq!(state.waiting_start_info.map(|(_, secs)| secs), Some(42));
+        assert!(matches!(state.fetching_state(), FetchingState::Idle));
+    }
+
+    #[test]
+    fn test_identical_backoffs_each_restart_the_countdown() {
+        let mut state = test_state();
+        let backoff = |emitted_at: Instant| {
+            let mut event = WorkerEvent::task_fetcher_with_level(
+                "Step 1 of 4: Waiting - ready for next task (30) seconds".to_string(),
+                EventType::Waiting,
+                LogLevel::Info,
+            )
+            .with_phase(ProofPhase::Backoff { wait_secs: 30 });
+            // Only the monotonic time matters, even if the wall clock stepped back
+            event.timestamp = "2025-01-01 12:00:00".to_string();
+            event.emitted_at = emitted_at;
+            event
+        };
+
+        // A first period that has nearly run out
+        let first = backoff(Instant::now() - Duration::from_secs(29));
+        state.process_event(&first);
+
+        // The same event again doesn't restart the period
+        state.process_event(&first);
+        state.update_task_fetch_countdown();
+        assert_eq!(state.task_fetch_info.time_since_last_fetch_secs, 29);
+
+        // A second, identical backoff 30 seconds later starts a fresh countdown
+        state.process_event(&backoff(Instant::now()));
+        state.update_task_fetch_countdown();
+        assert_eq!(state.task_fetch_info.backoff_duration_secs, 30);
+        assert_eq!(state.task_fetch_info.time_since_last_fetch_secs, 0);
+        assert!(!state.task_fetch_info.can_fetch_now);
+    }
+
+    #[test]
+    fn test_retries_before_success_span_fetch_and_submission() {
+        let mut state = test_state();
+        let rate_limited = || {
+            WorkerEvent::task_fetcher_with_level(
+                "Failed to fetch task: Rate limited".to_string(),
+                EventType::Error,
+                LogLevel::Warn,
+            )
+        };
+
+        // Two throttled fetches, then one extra submission attempt
+        state.process_event(&rate_limited());
+        state.process_event(&rate_limited());
+        state.process_event(&got_task("task-1"));
+        assert_eq!(state.retry_stats.retries("task-1"), Some(2));
+        state.process_event(&proving(0, "task-1"));
+        state.process_event(&proof_generated("task-1"));
+        let mut submitted = proof_submitted("task-1");
+        submitted.phase = Some(ProofPhase::Submitted {
+            task_id: "task-1".to_string(),
+            attempts: Some(2),
+        });
+        state.process_event(&submitted);
+        assert_eq!(state.retry_stats.average(), Some(3.0));
+        assert_eq!(state.retry_stats.retries("task-1"), None);
+
+        // A task that went through first time halves the average
+        state.process_event(&got_task("task-2"));
+        state.process_event(&proof_submitted("task-2"));
+        assert_eq!(state.retry_stats.average(), Some(1.5));
+    }
+
+    #[test]
+    fn test_full_pipeline_via_phases() {
+        let mut state = test_state();
+
+        state.process_event(&got_task("task-1"));
+        assert_eq!(state.current_task.as_deref(), Some("task-1"));
+        assert_eq!(state.zkvm_metrics.tasks_fetched, 1);
+
+        state.process_event(&proving(0, "task-1"));
+        assert_eq!(state.current_prover_state(), ProverState::Proving);
+        assert_eq!(state.current_task.as_deref(), Some("task-1"));
+        assert!(state.provers[&0].proving_since.is_some());
+
+        state.process_event(&proof_generated("task-1"));
+        assert_eq!(state.zkvm_metrics.last_task_status, TaskStatus::Proved);
+
+        state.process_event(&proof_submitted("task-1"));
+        assert_eq!(state.zkvm_metrics.tasks_submitted, 1);
+        assert_eq!(state.zkvm_metrics.last_task_status, TaskStatus::Success);
+        assert!(state.last_submission_timestamp().is_some());
+        // The submitter's event is matched to the prover's by task ID
+        assert!(state.submission_latency.average().is_some());
+        assert_eq!(state.submission_latency.pending(), 0);
+    }
+
+    #[test]
+    fn test_events_since_success_reset_on_each_success() {
+        let mut state = test_state();
+        assert_eq!(state.since_success.describe(Instant::now()), "none yet");
+
+        state.process_event(&got_task("task-1"));
+        state.process_event(&proof_submitted("task-1"));
+        assert_eq!(state.since_success.events(), 0);
+
+        state.process_event(&got_task("task-2"));
+        state.process_event(&proving(0, "task-2"));
+        state.process_event(&proof_generated("task-2"));
+        assert_eq!(state.since_success.events(), 3);
+
+        state.process_event(&proof_submitted("task-2"));
+        assert_eq!(state.since_success.events(), 0);
+        assert!(
+            state
+                .since_success
+                .describe(Instant::now())
+                .ends_with("(0 events since)")
+        );
+    }
+
+    #[test]
+    fn test_current_task_elapsed_follows_the_current_task() {
+        let mut state = test_state();
+        let secs = Duration::from_secs;
+
+        state.process_event(&got_task("task-1"));
+        assert_eq!(state.current_task_elapsed(Instant::now()), None);
+
+        state.process_event(&proving(0, "task-1"));
+        let task_one_start = state.provers[&0].proving_since.unwrap();
+        assert_eq!(
+            state.current_task_elapsed(task_one_start + secs(30)),
+            Some(secs(30))
+        );
+
+        // Another prover's task becomes current and is timed from its own start
+        state.process_event(&proving(1, "task-2"));
+        let task_two_start = state.provers[&1].proving_since.unwrap();
+        assert_eq!(
+            state.current_task_elapsed(task_two_start + secs(5)),
+            Some(secs(5))
+        );
+
+        // A newly fetched task has no start yet, rather than an old one
+        state.process_event(&got_task("task-3"));
+        assert_eq!(state.current_task_elapsed(task_two_start + secs(60)), None);
+
+        // Once the current task's proof is generated the timer clears
+        state.process_event(&proving(0, "task-3"));
+        assert!(state.current_task_elapsed(Instant::now()).is_some());
+        state.process_event(&proof_generated("task-3"));
+        assert_eq!(state.current_task_elapsed(Instant::now()), None);
+    }
+
+    #[test]
+    fn test_overlapping_proofs_are_timed_per_prover() {
+        let mut state = test_state();
+
+        state.process_event(&proving(0, "task-1"));
+        state.process_event(&proving(1, "task-2"));
+        let task_two_start = state.provers[&1].proving_since;
+
+        // Prover 0 finishing doesn't touch prover 1's timer
+        state.process_event(&proof_generated("task-1"));
+        assert_eq!(state.provers[&0].tasks_proved, 1);
+        assert_eq!(state.provers[&0].current_task, None);
+        assert_eq!(state.provers[&1].proving_since, task_two_start);
+        assert_eq!(state.provers[&1].current_task.as_deref(), Some("task-2"));
+        assert_eq!(state.provers[&1].last_status, TaskStatus::Proving);
+
+        // A failure on prover 1 is attributed to it alone
+        state.process_event(&WorkerEvent::prover_with_level(
+            1,
+            "boom".to_string(),
+            EventType::Error,
+            LogLevel::Error,
+        ));
+        assert_eq!(state.provers[&0].last_status, TaskStatus::Proved);
+        assert_eq!(state.provers[&1].last_status, TaskStatus::ProofFailed);
+        assert_eq!(state.zkvm_metrics.untimed_proofs, 0);
+    }
+
+    #[test]
+    fn test_interleaved_provers_are_timed_against_their_own_start() {
+        let mut state = test_state();
+        let generated = |prover: usize, task_id: &str| {
+            WorkerEvent::prover_with_level(
+                prover,
+                format!("Step 3 of 4: Proof generated for task {}", task_id),
+                EventType::Success,
+                LogLevel::Info,
+            )
+            .with_phase(ProofPhase::ProofGenerated {
+                task_id: task_id.to_string(),
+                proof_bytes: None,
+            })
+        };
+
+        state.process_event(&proving(0, "task-a"));
+        state.process_event(&proving(1, "task-b"));
+        state.process_event(&generated(1, "task-b"));
+        state.process_event(&generated(0, "task-a"));
+        assert_eq!(state.zkvm_metrics.tasks_proved, 2);
+        assert_eq!(state.zkvm_metrics.untimed_proofs, 0);
+        assert_eq!(state.provers[&0].tasks_proved, 1);
+        assert_eq!(state.provers[&1].tasks_proved, 1);
+
+        // A repeated Step 3 is not counted again
+        state.process_event(&generated(0, "task-a"));
+        assert_eq!(state.zkvm_metrics.tasks_proved, 2);
+        assert_eq!(state.provers[&0].tasks_proved, 1);
+
+        // A Step 3 with no matching Step 2 leaves the running proof's timer alone
+        state.process_event(&proving(0, "task-c"));
+        state.process_event(&generated(0, "task-z"));
+        assert!(state.provers[&0].proving_since.is_some());
+        assert_eq!(state.provers[&0].current_task.as_deref(), Some("task-c"));
+        assert_eq!(state.provers[&0].last_status, TaskStatus::Proving);
+        assert_eq!(state.zkvm_metrics.untimed_proofs, 1);
+
+        state.process_event(&generated(0, "task-c"));
+        assert!(state.provers[&0].proving_since.is_none());
+        assert_eq!(state.zkvm_metrics.untimed_proofs, 1);
+    }
+
+    #[test]
+    fn test_proving_phase_tracks_task_when_fetch_was_missed() {
+        let mut state = test_state();
+
+        state.process_event(
+            &WorkerEvent::state_change(ProverState::Proving, String::new()).with_phase(
+                ProofPhase::Proving {
+                    task_id: "task-7".to_string(),
+                },
+            ),
+        );
+
+        assert_eq!(state.current_task.as_deref(), Some("task-7"));
+        assert_eq!(state.zkvm_metrics.tasks_fetched, 0);
+    }
+
+    #[test]
+    fn test_metrics_ignore_message_wording() {
+        let mut state = test_state();
+
+        // Old wording without a phase no longer drives metrics
+        state.process_event(&WorkerEvent::proof_submitter_with_level(
+            "Step 4 of 4: Proof submitted successfully for task task-1\n".to_string(),
+            EventType::Success,
+            LogLevel::Info,
+        ));
+        assert_eq!(state.zkvm_metrics.tasks_submitted, 0);
+
+        // Any wording with a phase does
+        state.process_event(
+            &WorkerEvent::proof_submitter_with_level(
+                "Done!".to_string(),
+                EventType::Success,
+                LogLevel::Info,
+            )
+            .with_phase(ProofPhase::Submitted {
+                task_id: "task-1".to_string(),
+                attempts: None,
+            }),
+        );
+        assert_eq!(state.zkvm_metrics.tasks_submitted, 1);
+    }
+
+    #[test]
+    fn test_errors_update_status_by_worker() {
+        let mut state = test_state();
+
+        state.process_event(&got_task("task-1"));
+        state.process_event(&proving(0, "task-1"));
+        state.process_event(&WorkerEvent::prover_with_level(
+            0,
+            "boom".to_string(),
+            EventType::Error,
+            LogLevel::Error,
+        ));
+        assert_eq!(state.zkvm_metrics.last_task_status, TaskStatus::ProofFailed);
+        assert!(state.provers[&0].proving_since.is_none());
+        assert_eq!(state.provers[&0].last_status, TaskStatus::ProofFailed);
+
+        state.process_event(&WorkerEvent::proof_submitter_with_level(
+            "boom".to_string(),
+            EventType::Error,
+            LogLevel::Error,
+        ));
+        assert_eq!(
+            state.zkvm_metrics.last_task_status,
+            TaskStatus::SubmitFailed
+        );
+    }
+
+    #[test]
+    fn test_points_per_task_sets_displayed_total() {
+        let mut state = test_state();
+        state.process_event(&proof_submitted("task-1"));
+        state.process_event(&proof_submitted("task-2"));
+        assert_eq!(state.zkvm_metrics.total_points, 600);
+        assert_eq!(state.zkvm_metrics.format_points(), "600");
+
+        let mut state =
+            test_state_with(UIConfig::new(false, 1, false, None).with_points_per_task(750));
+        state.process_event(&proof_submitted("task-1"));
+        state.process_event(&proof_submitted("task-2"));
+        assert_eq!(state.zkvm_metrics.total_points, 1500);
+        assert_eq!(state.zkvm_metrics.format_points(), "1,500");
+    }
+
+    #[test]
+    fn test_lifetime_stats_are_restored_and_saved() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stats.json");
+        LifetimeStats {
+            tasks_fetched: 5,
+            tasks_submitted: 4,
+            zkvm_runtime_secs: 120,
+            peak_ram_bytes: 2048,
+            ..LifetimeStats::default()
+        }
+        .save(&path)
+        .unwrap();
+
+        let ui_config = UIConfig::new(false, 1, false, None).with_stats_path(Some(path.clone()));
+        let mut state = test_state_with(ui_config);
+        assert_eq!(state.zkvm_metrics.tasks_submitted, 4);
+        assert_eq!(state.zkvm_metrics.total_points, 1200);
+        assert_eq!(state.system_metrics.peak_ram_bytes, 2048);
+
+        // The first proof of this session is still confirmed
+        state.process_event(&proof_submitted("task-5"));
+        assert!(state.first_proof_banner_visible());
+
+        state.save_stats();
+        let saved = LifetimeStats::load(&path);
+        assert_eq!(saved.tasks_submitted, 5);
+        assert_eq!(saved.total_points, Some(1500));
+        assert_eq!(saved.zkvm_runtime_secs, 120);
+    }
+
+    #[test]
+    fn test_counters_stay_honest_when_fetches_outpace_submissions() {
+        let mut state = test_state();
+
+        for i in 0..10 {
+            state.process_event(&got_task(&format!("task-{}", i)));
         }
+        for i in 0..5 {
+            state.process_event(&proof_generated(&format!("task-{}", i)));
+        }
+        for i in 0..3 {
+            state.process_event(&proof_submitted(&format!("task-{}", i)));
+        }
+        state.process_event(&WorkerEvent::prover_with_level(
+            0,
+            "boom".to_string(),
+            EventType::Error,
+            LogLevel::Error,
+        ));
+        state.process_event(&WorkerEvent::proof_submitter_with_level(
+            "boom".to_string(),
+            EventType::Error,
+            LogLevel::Error,
+        ));
+
+        let metrics = &state.zkvm_metrics;
+        assert_eq!(metrics.tasks_fetched, 10);
+        assert_eq!(metrics.tasks_proved, 5);
+        assert_eq!(metrics.tasks_submitted, 3);
+        assert_eq!(metrics.proof_failures, 1);
+        assert_eq!(metrics.submit_failures, 1);
     }
 
-    /// Extract wait seconds from message. Expected format: "...ready for next task (30) seconds"
-    fn extract_wait_seconds(msg: &str) -> Option<u64> {
-        let start = msg.find("(")?;
-        let end = msg[start..].find(") seconds")?;
-        msg[start + 1..start + end].parse().ok()
+    #[test]
+    fn test_repeated_stage_is_not_counted_again() {
+        let mut state = test_state();
+
+        state.process_event(&got_task("task-1"));
+        state.process_event(&got_task("task-1"));
+        state.process_event(&proving(0, "task-1"));
+        state.process_event(&proof_generated("task-1"));
+        state.process_event(&proof_submitted("task-1"));
+        state.process_event(&proof_submitted("task-1"));
+
+        assert_eq!(state.zkvm_metrics.tasks_fetched, 1);
+        assert_eq!(state.zkvm_metrics.tasks_submitted, 1);
+        assert!(state.take_raised_events().is_empty());
     }
 
-    /// Check if event indicates task completion or error (not Step 1)
-    fn is_completion_event(event: &WorkerEvent) -> bool {
-        matches!(event.worker, Worker::TaskFetcher)
-            && matches!(event.event_type, EventType::Success | EventType::Error)
-            && !event.msg.contains("Step 1 of 4")
+    #[test]
+    fn test_out_of_order_stage_is_counted_and_warned_about() {
+        let mut state = test_state();
+
+        state.process_event(&got_task("task-1"));
+        state.process_event(&proof_submitted("task-1"));
+
+        // The submission did happen, so it counts, but the skipped proof is flagged
+        assert_eq!(state.zkvm_metrics.tasks_submitted, 1);
+        let raised = state.take_raised_events();
+        assert_eq!(raised.len(), 1);
+        assert_eq!(raised[0].log_level, LogLevel::Warn);
+        assert!(raised[0].msg.contains("task-1"));
     }
 
-    /// Check if event indicates fetching activity start
-    fn is_fetching_start_event(event: &WorkerEvent) -> bool {
-        matches!(event.worker, Worker::TaskFetcher)
-            && event.msg.contains("Step 1 of 4: Requesting task...")
+    #[test]
+    fn test_submission_without_observed_fetch_does_not_inflate_fetches() {
+        let mut state = test_state();
+
+        state.process_event(&proof_submitted("task-1"));
+
+        assert_eq!(state.zkvm_metrics.tasks_fetched, 0);
+        assert_eq!(state.zkvm_metrics.tasks_submitted, 1);
+        assert_eq!(state.zkvm_metrics.success_rate(), 100.0);
+    }
+
+    #[test]
+    fn test_submission_is_reported_to_webhook() {
+        let (webhook, mut records) = Webhook::channel(1);
+        let mut state = test_state_with(
+            UIConfig::new(false, 1, false, None)
+                .with_points_per_task(100)
+                .with_webhook(Some(webhook)),
+        );
+
+        let submitted = proof_submitted("task-7");
+        state.process_event(&submitted);
+
+        let record = records.try_recv().unwrap();
+        assert_eq!(record.task_id, "task-7");
+        assert_eq!(record.timestamp, submitted.timestamp);
+        assert_eq!(record.total_points, 100);
+
+        // A full queue is logged as an error without counting as a submit failure
+        state.process_event(&proof_submitted("task-8"));
+        state.process_event(&proof_submitted("task-9"));
+        state.log_webhook_failures();
+        let failures = state.take_raised_events();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].event_type, EventType::Error);
+        assert_eq!(state.activity_logs.len(), 1);
+        assert_eq!(state.zkvm_metrics.submit_failures, 0);
+    }
+
+    #[test]
+    fn test_success_rate_counts_failures_of_finished_tasks() {
+        let mut state = test_state();
+        assert_eq!(state.zkvm_metrics.success_rate(), 0.0);
+
+        // In-flight tasks don't drag the rate down
+        for i in 0..8 {
+            state.process_event(&got_task(&format!("task-{}", i)));
+        }
+        for i in 0..3 {
+            state.process_event(&proof_submitted(&format!("task-{}", i)));
+        }
+        assert_eq!(state.zkvm_metrics.success_rate(), 100.0);
+
+        state.process_event(&WorkerEvent::prover_with_level(
+            0,
+            "boom".to_string(),
+            EventType::Error,
+            LogLevel::Error,
+        ));
+        assert_eq!(state.zkvm_metrics.success_rate(), 75.0);
+
+        state.process_event(&WorkerEvent::proof_submitter_with_level(
+            "boom".to_string(),
+            EventType::Error,
+            LogLevel::Error,
+        ));
+        assert_eq!(state.zkvm_metrics.success_rate(), 60.0);
+    }
+
+    #[test]
+    fn test_failure_phase_names_the_failed_task() {
+        let mut state = test_state();
+        state.process_event(&proving(0, "task-1"));
+
+        // The message names no task; the typed phase does
+        state.process_event(
+            &WorkerEvent::prover_with_level(
+                0,
+                "Proof generation failed: out of memory".to_string(),
+                EventType::Error,
+                LogLevel::Error,
+            )
+            .with_phase(ProofPhase::ProofFailed {
+                task_id: "task-1".to_string(),
+            }),
+        );
+
+        assert_eq!(state.zkvm_metrics.proof_failures, 1);
+        assert_eq!(state.provers[&0].current_task, None);
+        assert_eq!(state.provers[&0].proving_since, None);
+        assert_eq!(state.provers[&0].last_status, TaskStatus::ProofFailed);
+    }
+
+    #[test]
+    fn test_prover_past_threshold_is_flagged_stuck_once() {
+        let mut state =
+            test_state_with(UIConfig::new(false, 1, false, None).with_stuck_threshold(60));
+        state.process_event(&proving(0, "task-1"));
+        let started = state.provers[&0].proving_since.unwrap();
+
+        state.check_stuck_provers(started + Duration::from_secs(59));
+        assert_eq!(state.provers[&0].last_status, TaskStatus::Proving);
+
+        state.check_stuck_provers(started + Duration::from_secs(61));
+        assert_eq!(state.provers[&0].last_status, TaskStatus::Stuck);
+        let raised = state.take_raised_events();
+        assert_eq!(raised.len(), 1);
+        assert_eq!(raised[0].event_type, EventType::Error);
+        assert!(raised[0].msg.contains("task-1"));
+        assert_eq!(state.zkvm_metrics.proof_failures, 0);
+
+        // Only reported once per proof
+        state.check_stuck_provers(started + Duration::from_secs(120));
+        assert!(state.take_raised_events().is_empty());
+
+        // The proof finishing late clears the flag
+        state.process_event(&proof_generated("task-1"));
+        assert_eq!(state.provers[&0].last_status, TaskStatus::Proved);
+    }
+
+    #[test]
+    fn test_unwritable_event_log_is_reported_once_and_disabled() {
+        let dir = tempfile::tempdir().unwrap();
+        // A directory can't be opened as the log file
+        let config = EventLogConfig::new(dir.path().to_path_buf(), LogFormat::Text);
+        let mut state =
+            test_state_with(UIConfig::new(false, 1, false, None).with_event_log(Some(config)));
+
+        assert!(state.event_log.is_none());
+        let raised = state.take_raised_events();
+        assert_eq!(raised.len(), 1);
+        assert_eq!(raised[0].event_type, EventType::Error);
+        // The dashboard's own problem, not the fetcher's
+        assert_eq!(raised[0].worker, Worker::Dashboard);
+        assert!(state.last_errors.fetcher.is_none());
+
+        // Later events are neither written nor reported again
+        state.process_event(&got_task("task-1"));
+        state.update();
+        assert!(state.take_raised_events().is_empty());
+    }
+
+    #[test]
+    fn test_time_is_accumulated_per_fetching_state() {
+        let mut state = test_state();
+        let t0 = state.start_time;
+        let secs = Duration::from_secs;
+        state.set_fetching_state_at(FetchingState::Active { started_at: t0 }, t0 + secs(30));
+        state.set_fetching_state_at(FetchingState::Timeout, t0 + secs(40));
+        state.set_fetching_state_at(FetchingState::Idle, t0 + secs(50));
+
+        // The current (idle) period counts up to now
+        let times = state.fetching_times(t0 + secs(100));
+        assert_eq!(times.idle, secs(80));
+        assert_eq!(times.active, secs(10));
+        assert_eq!(times.timeout, secs(10));
+        assert_eq!(times.total(), secs(100));
+        assert_eq!(times.active_pct(), 10.0);
+    }
+
+    #[test]
+    fn test_system_metrics_are_sampled_less_often_than_redraws() {
+        let mut state = test_state();
+        let t0 = Instant::now();
+        state.sample_system_metrics(t0);
+        state.sample_system_metrics(t0 + Duration::from_millis(100));
+        assert_eq!(state.cpu_history.len(), 1);
+
+        state.sample_system_metrics(t0 + Duration::from_millis(SYSTEM_SAMPLE_INTERVAL_MS));
+        assert_eq!(state.cpu_history.len(), 2);
+    }
+
+    #[test]
+    fn test_system_history_is_capped() {
+        let mut state = test_state();
+        for i in 0..SYSTEM_HISTORY_SAMPLES + 5 {
+            state.system_metrics.cpu_percent = i as f32;
+            state.record_system_history();
+        }
+
+        assert_eq!(state.cpu_history.len(), SYSTEM_HISTORY_SAMPLES);
+        assert_eq!(state.ram_history.len(), SYSTEM_HISTORY_SAMPLES);
+        assert_eq!(state.cpu_history.front(), Some(&5.0));
+        assert_eq!(
+            state.cpu_history.back(),
+            Some(&((SYSTEM_HISTORY_SAMPLES + 4) as f32))
+        );
+    }
+
+    #[test]
+    fn test_metrics_survive_activity_log_eviction() {
+        let mut state =
+            test_state_with(UIConfig::new(false, 1, false, None).with_activity_log_capacity(10));
+
+        for i in 0..50 {
+            state.add_event(got_task(&format!("task-{}", i)));
+            state.add_event(proof_submitted(&format!("task-{}", i)));
+        }
+        state.update();
+
+        assert_eq!(state.activity_logs.len(), 10);
+        assert_eq!(state.zkvm_metrics.tasks_fetched, 50);
+        assert_eq!(state.zkvm_metrics.tasks_submitted, 50);
+        assert_eq!(state.zkvm_metrics.total_points, 50 * 300);
+    }
+
+    /// One fetch→prove→submit cycle, plus a failed proof every tenth task
+    fn task_events(i: usize) -> Vec<WorkerEvent> {
+        let task_id = format!("task-{}", i);
+        let mut events = vec![got_task(&task_id)];
+        if i % 10 == 0 {
+            events.push(WorkerEvent::prover_with_level(
+                0,
+                "boom".to_string(),
+                EventType::Error,
+                LogLevel::Error,
+            ));
+        } else {
+            events.push(proof_generated(&task_id));
+            events.push(proof_submitted(&task_id));
+        }
+        events
+    }
+
+    #[test]
+    fn test_incremental_processing_matches_single_pass() {
+        let stream: Vec<WorkerEvent> = (0..1_000).flat_map(task_events).collect();
+
+        let mut single_pass = test_state();
+        single_pass.pending_events.extend(stream.iter().cloned());
+        assert_eq!(single_pass.process_pending_events(), stream.len());
+
+        let mut incremental = test_state();
+        for chunk in stream.chunks(7) {
+            incremental.pending_events.extend(chunk.iter().cloned());
+            assert_eq!(incremental.process_pending_events(), chunk.len());
+        }
+
+        let (a, b) = (&single_pass.zkvm_metrics, &incremental.zkvm_metrics);
+        assert_eq!(a.tasks_fetched, b.tasks_fetched);
+        assert_eq!(a.tasks_proved, b.tasks_proved);
+        assert_eq!(a.tasks_submitted, b.tasks_submitted);
+        assert_eq!(a.proof_failures, b.proof_failures);
+        assert_eq!(a.total_points, b.total_points);
+        assert_eq!(a.last_task_status, b.last_task_status);
+        assert_eq!(single_pass.current_task, incremental.current_task);
+    }
+
+    #[test]
+    fn test_per_tick_work_is_proportional_to_new_events() {
+        let mut state = test_state();
+
+        // Build up a long history of 100k events
+        let mut total = 0;
+        let mut i = 0;
+        while total < 100_000 {
+            for event in task_events(i) {
+                state.add_event(event);
+                total += 1;
+            }
+            i += 1;
+            if i % 1_000 == 0 {
+                state.process_pending_events();
+            }
+        }
+        state.process_pending_events();
+        assert_eq!(state.zkvm_metrics.tasks_fetched, i);
+
+        // With nothing new, a tick does no event work regardless of history
+        assert_eq!(state.process_pending_events(), 0);
+
+        // A tick after a handful of new events touches only those
+        for event in task_events(1) {
+            state.add_event(event);
+        }
+        assert_eq!(state.process_pending_events(), 3);
+        assert_eq!(state.zkvm_metrics.tasks_fetched, i + 1);
     }
 }
@@ -2,18 +2,68 @@
 //!
 //! Contains helper functions used across dashboard components
 
-use crate::events::Worker;
+use crate::events::{EventType, Worker};
+use crate::logging::LogLevel;
+use crate::ui::theme::Theme;
 use ratatui::prelude::Color;
 
 /// Get a ratatui color for a worker based on its type
-pub fn get_worker_color(worker: &Worker) -> Color {
+pub fn get_worker_color(theme: &Theme, worker: &Worker) -> Color {
     match worker {
-        Worker::TaskFetcher => Color::Cyan,
-        Worker::Prover(_) => Color::Yellow,
-        Worker::ProofSubmitter => Color::Green,
+        Worker::TaskFetcher => theme.fetcher,
+        Worker::Prover(_) => theme.prover,
+        Worker::ProofSubmitter => theme.submitter,
+        Worker::Dashboard => theme.label,
     }
 }
 
+/// Marker shown before an event in the activity log: the glyph's shape tells
+/// the worker and its color the outcome. Without colors the markers are ASCII,
+/// a worker letter followed by an outcome sign, so both still show.
+pub fn event_marker(
+    theme: &Theme,
+    worker: &Worker,
+    event_type: EventType,
+    log_level: LogLevel,
+) -> (&'static str, Color) {
+    if theme.is_monochrome() {
+        let marker = match (worker, event_type, log_level) {
+            (Worker::TaskFetcher, EventType::Success, _) => "F+",
+            (Worker::TaskFetcher, EventType::Error, LogLevel::Warn) => "F?",
+            (Worker::TaskFetcher, EventType::Error, _) => "F!",
+            (Worker::TaskFetcher, _, _) => "F-",
+            (Worker::Prover(_), EventType::Success, _) => "P+",
+            (Worker::Prover(_), EventType::Error, LogLevel::Warn) => "P?",
+            (Worker::Prover(_), EventType::Error, _) => "P!",
+            (Worker::Prover(_), _, _) => "P-",
+            (Worker::ProofSubmitter, EventType::Success, _) => "S+",
+            (Worker::ProofSubmitter, EventType::Error, LogLevel::Warn) => "S?",
+            (Worker::ProofSubmitter, EventType::Error, _) => "S!",
+            (Worker::ProofSubmitter, _, _) => "S-",
+            (Worker::Dashboard, EventType::Success, _) => "D+",
+            (Worker::Dashboard, EventType::Error, LogLevel::Warn) => "D?",
+            (Worker::Dashboard, EventType::Error, _) => "D!",
+            (Worker::Dashboard, _, _) => "D-",
+        };
+        return (marker, Color::Reset);
+    }
+
+    let glyph = match worker {
+        Worker::TaskFetcher => "▼",
+        Worker::Prover(_) => "◆",
+        Worker::ProofSubmitter => "▲",
+        Worker::Dashboard => "●",
+    };
+    let color = match (event_type, log_level) {
+        (EventType::Success, _) => theme.success,
+        (EventType::Error, LogLevel::Warn) => theme.warning,
+        (EventType::Error, _) => theme.error,
+        (EventType::StateChange, _) => theme.active,
+        (EventType::Refresh | EventType::Waiting, _) => get_worker_color(theme, worker),
+    };
+    (glyph, color)
+}
+
 /// Format compact timestamp with date and time from full timestamp
 pub fn format_compact_timestamp(timestamp: &str) -> String {
     // Extract from "YYYY-MM-DD HH:MM:SS" format
@@ -48,3 +98,55 @@ pub fn clean_http_error_message(msg: &str) -> String {
     // Return original message if no HTTP error pattern detected
     msg.to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markers_tell_worker_and_outcome_apart_without_color() {
+        let workers = [
+            Worker::TaskFetcher,
+            Worker::Prover(0),
+            Worker::ProofSubmitter,
+            Worker::Dashboard,
+        ];
+        let outcomes = [
+            (EventType::Success, LogLevel::Info),
+            (EventType::Error, LogLevel::Error),
+            (EventType::Error, LogLevel::Warn),
+            (EventType::Refresh, LogLevel::Info),
+        ];
+
+        let theme = Theme::monochrome();
+        let mut markers = Vec::new();
+        for worker in &workers {
+            for (event_type, log_level) in outcomes {
+                let (marker, color) = event_marker(&theme, worker, event_type, log_level);
+                assert!(marker.is_ascii());
+                assert_eq!(color, Color::Reset);
+                markers.push(marker);
+            }
+        }
+        markers.sort();
+        markers.dedup();
+        assert_eq!(markers.len(), workers.len() * outcomes.len());
+
+        // With colors, the shape is the worker's and the color the outcome's
+        let theme = Theme::dark();
+        let (fetched, success) = event_marker(
+            &theme,
+            &Worker::TaskFetcher,
+            EventType::Success,
+            LogLevel::Info,
+        );
+        let (failed, error) = event_marker(
+            &theme,
+            &Worker::TaskFetcher,
+            EventType::Error,
+            LogLevel::Error,
+        );
+        assert_eq!(fetched, failed);
+        assert_eq!((success, error), (theme.success, theme.error));
+    }
+}
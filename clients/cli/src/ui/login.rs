@@ -1,17 +1,18 @@
 //! Login screen module
 
+use crate::ui::theme::Theme;
 use ratatui::Frame;
-use ratatui::prelude::{Color, Style};
+use ratatui::prelude::Style;
 use ratatui::widgets::{Block, Borders, Paragraph};
 
 /// Renders the login screen with a simple message and instructions.
-pub fn render_login(f: &mut Frame) {
+pub fn render_login(f: &mut Frame, theme: &Theme) {
     let size = f.area();
 
     let block = Block::default()
         .title("Login")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(Style::default().fg(theme.border));
 
     let paragraph = Paragraph::new("Press Enter to login\nPress Esc to exit").block(block);
 
@@ -1,20 +1,53 @@
 //! System metrics collection and display.
 
-use std::time::Instant;
-use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+use crate::consts::cli_consts::{DEFAULT_RAM_CRITICAL_PCT, DEFAULT_RAM_WARN_PCT};
+use crate::ui::theme::Theme;
+use chrono::{NaiveDate, NaiveDateTime, Timelike};
+use ratatui::prelude::Color;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::time::{Duration, Instant};
+use sysinfo::{Components, Networks, Pid, ProcessRefreshKind, ProcessesToUpdate, System};
 
 /// System metrics for display in the dashboard.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SystemMetrics {
     /// CPU usage percentage (0.0 to 100.0).
     pub cpu_percent: f32,
     /// Current process RAM usage in bytes.
     pub ram_bytes: u64,
-    /// Peak process RAM usage in bytes since startup.
+    /// Peak process RAM usage in bytes since startup (or the last reset).
     pub peak_ram_bytes: u64,
+    /// Unsmoothed process RAM from the latest sample, which the peaks track.
+    #[serde(skip)]
+    pub sampled_ram_bytes: u64,
     /// Total system RAM in bytes.
     pub total_ram_bytes: u64,
+    /// Current process RAM usage as a percentage of total RAM (0.0 to 100.0).
+    pub ram_usage_pct: f32,
+    /// GPU utilization percentage, when GPU metrics are available.
+    pub gpu_utilization_pct: Option<f32>,
+    /// GPU memory in use in bytes, when GPU metrics are available.
+    pub gpu_memory_used_bytes: Option<u64>,
+    /// Total GPU memory in bytes, when GPU metrics are available.
+    pub gpu_memory_total_bytes: Option<u64>,
+    /// CPU temperature in °C, when the platform exposes a CPU sensor.
+    pub cpu_temp_celsius: Option<f32>,
+    /// Bytes received across all network interfaces (host-wide, so this
+    /// includes traffic besides task fetches and submissions).
+    pub network_rx_bytes: u64,
+    /// Bytes sent across all network interfaces (host-wide).
+    pub network_tx_bytes: u64,
+    /// Receive rate since the previous sample, in bytes per second.
+    pub network_rx_bytes_per_sec: f64,
+    /// Send rate since the previous sample, in bytes per second.
+    pub network_tx_bytes_per_sec: f64,
+    /// Last time network counters were sampled, for rate calculation
+    #[serde(skip)]
+    pub last_network_update: Option<Instant>,
     /// Last time CPU was updated for proper refresh timing
+    #[serde(skip)]
     pub last_cpu_update: Option<Instant>,
 }
 
@@ -24,11 +57,22 @@ impl Default for SystemMetrics {
             cpu_percent: 0.0,
             ram_bytes: 0,
             peak_ram_bytes: 0,
+            sampled_ram_bytes: 0,
             total_ram_bytes: {
                 let mut sys = System::new();
                 sys.refresh_memory();
                 sys.total_memory()
             },
+            ram_usage_pct: 0.0,
+            gpu_utilization_pct: None,
+            gpu_memory_used_bytes: None,
+            gpu_memory_total_bytes: None,
+            cpu_temp_celsius: None,
+            network_rx_bytes: 0,
+            network_tx_bytes: 0,
+            network_rx_bytes_per_sec: 0.0,
+            network_tx_bytes_per_sec: 0.0,
+            last_network_update: None,
             last_cpu_update: None,
         }
     }
@@ -37,10 +81,18 @@ impl Default for SystemMetrics {
 impl SystemMetrics {
     /// Update metrics from system information, tracking peak memory over time.
     /// Uses proper CPU refresh timing according to sysinfo documentation.
+    /// GPU fields stay `None` when no GPU sample is available.
+    ///
+    /// With `smoothing`, CPU and RAM are exponential moving averages with that
+    /// weight for the new sample; the peak always tracks the raw readings.
     pub fn update(
         sysinfo: &mut System,
+        networks: &mut Networks,
+        components: &mut Components,
         previous_peak: u64,
         previous_metrics: Option<&SystemMetrics>,
+        gpu: Option<GpuSample>,
+        smoothing: Option<f32>,
     ) -> Self {
         let now = Instant::now();
 
@@ -108,154 +160,1068 @@ impl SystemMetrics {
 
         // Track peak process RAM usage over application lifetime
         let peak_ram = previous_peak.max(ram_total);
+        let total_ram = sysinfo.total_memory();
+
+        // Network counters are cumulative per interface; rates come from the
+        // change since the previous sample
+        networks.refresh(true);
+        let (rx_total, tx_total) = networks.values().fold((0, 0), |(rx, tx), data| {
+            (rx + data.total_received(), tx + data.total_transmitted())
+        });
+        let since_last = previous_metrics
+            .and_then(|m| m.last_network_update)
+            .map(|at| now.saturating_duration_since(at));
+        let previous_rx = previous_metrics.map_or(0, |m| m.network_rx_bytes);
+        let previous_tx = previous_metrics.map_or(0, |m| m.network_tx_bytes);
+
+        // Sensors come and go with drivers; one that can't be read is just absent
+        components.refresh(true);
+        let cpu_temp_celsius = cpu_temperature(
+            components
+                .iter()
+                .map(|component| (component.label(), component.temperature())),
+        );
+
+        // Averages start from the first real sample, not the zeroed defaults
+        let sampled = previous_metrics.filter(|m| m.last_network_update.is_some());
+        let cpu_percent = smooth(
+            sampled.map(|m| f64::from(m.cpu_percent)),
+            f64::from(cpu_total),
+            smoothing,
+        ) as f32;
+        let ram_bytes = smooth(
+            sampled.map(|m| m.ram_bytes as f64),
+            ram_total as f64,
+            smoothing,
+        )
+        .round() as u64;
 
         Self {
-            cpu_percent: cpu_total,
-            ram_bytes: ram_total,
+            cpu_percent,
+            ram_bytes,
             peak_ram_bytes: peak_ram,
-            total_ram_bytes: sysinfo.total_memory(),
+            sampled_ram_bytes: ram_total,
+            total_ram_bytes: total_ram,
+            ram_usage_pct: usage_pct(ram_bytes, total_ram),
+            gpu_utilization_pct: gpu.map(|g| g.utilization_pct),
+            gpu_memory_used_bytes: gpu.map(|g| g.memory_used_bytes),
+            gpu_memory_total_bytes: gpu.map(|g| g.memory_total_bytes),
+            cpu_temp_celsius,
+            network_rx_bytes: rx_total,
+            network_tx_bytes: tx_total,
+            network_rx_bytes_per_sec: bytes_per_sec(previous_rx, rx_total, since_last),
+            network_tx_bytes_per_sec: bytes_per_sec(previous_tx, tx_total, since_last),
+            last_network_update: Some(now),
             last_cpu_update,
         }
     }
 
+    /// Whether GPU metrics are available for display.
+    pub fn has_gpu(&self) -> bool {
+        self.gpu_utilization_pct.is_some()
+    }
+
+    /// Get GPU memory usage as a ratio (0.0 to 1.0).
+    pub fn gpu_memory_ratio(&self) -> f64 {
+        match (self.gpu_memory_used_bytes, self.gpu_memory_total_bytes) {
+            (Some(used), Some(total)) if total > 0 => used as f64 / total as f64,
+            _ => 0.0,
+        }
+    }
+
+    /// Format GPU memory usage as human-readable string.
+    pub fn format_gpu_memory(&self) -> String {
+        let gb = |bytes: u64| bytes as f64 / (1024.0 * 1024.0 * 1024.0);
+        format!(
+            "{:.1} / {:.1}GB",
+            gb(self.gpu_memory_used_bytes.unwrap_or(0)),
+            gb(self.gpu_memory_total_bytes.unwrap_or(0))
+        )
+    }
+
     /// Get RAM usage as a ratio (0.0 to 1.0).
     pub fn ram_ratio(&self) -> f64 {
-        if self.total_ram_bytes == 0 {
-            0.0
-        } else {
-            (self.ram_bytes as f64) / (self.total_ram_bytes as f64)
-        }
+        f64::from(usage_pct(self.ram_bytes, self.total_ram_bytes)) / 100.0
     }
 
-    /// Get peak RAM usage as a ratio (0.0 to 1.0).
-    pub fn peak_ram_ratio(&self) -> f64 {
-        if self.total_ram_bytes == 0 {
-            0.0
-        } else {
-            (self.peak_ram_bytes as f64) / (self.total_ram_bytes as f64)
-        }
+    /// Get peak RAM usage as a percentage of total RAM (0.0 to 100.0).
+    pub fn peak_ram_pct(&self) -> f32 {
+        usage_pct(self.peak_ram_bytes, self.total_ram_bytes)
     }
 
     /// Format RAM usage as human-readable string.
     pub fn format_ram(&self) -> String {
-        let mb = self.ram_bytes as f64 / (1024.0 * 1024.0);
-        if mb >= 1024.0 {
-            format!("{:.1} GB", mb / 1024.0)
-        } else {
-            format!("{:.1} MB", mb)
-        }
+        format_bytes(self.ram_bytes)
     }
 
     /// Format peak RAM usage as human-readable string.
     pub fn format_peak_ram(&self) -> String {
-        let mb = self.peak_ram_bytes as f64 / (1024.0 * 1024.0);
-        if mb >= 1024.0 {
-            format!("{:.1} GB", mb / 1024.0)
-        } else {
-            format!("{:.1} MB", mb)
-        }
+        format_bytes(self.peak_ram_bytes)
+    }
+
+    /// Format network rates and totals, e.g. "↓ 1.2 KB/s (10.0 MB)  ↑ 300 B/s (2.0 MB)".
+    pub fn format_network(&self) -> String {
+        format!(
+            "↓ {}/s ({})  ↑ {}/s ({})",
+            format_bytes(self.network_rx_bytes_per_sec.round() as u64),
+            format_bytes(self.network_rx_bytes),
+            format_bytes(self.network_tx_bytes_per_sec.round() as u64),
+            format_bytes(self.network_tx_bytes)
+        )
     }
 
     /// Get CPU gauge color based on usage.
-    pub fn cpu_color(&self) -> ratatui::prelude::Color {
-        use ratatui::prelude::Color;
+    pub fn cpu_color(&self, theme: &Theme) -> Color {
         if self.cpu_percent >= 80.0 {
-            Color::Red
+            theme.error
         } else if self.cpu_percent >= 60.0 {
-            Color::Yellow
+            theme.warning
         } else {
-            Color::Green
+            theme.success
         }
     }
 
-    /// Get RAM gauge color based on usage.
-    pub fn ram_color(&self) -> ratatui::prelude::Color {
-        use ratatui::prelude::Color;
-        let ratio = self.ram_ratio();
-        if ratio >= 0.8 {
-            Color::Red
-        } else if ratio >= 0.6 {
-            Color::Yellow
+    /// Color for the CPU temperature: red from `warn_celsius`.
+    pub fn cpu_temp_color(&self, warn_celsius: f32, theme: &Theme) -> Color {
+        match self.cpu_temp_celsius {
+            Some(celsius) if celsius >= warn_celsius => theme.error,
+            _ => theme.label,
+        }
+    }
+
+    /// Get RAM gauge color based on usage and the configured thresholds.
+    pub fn ram_color(&self, thresholds: RamThresholds, theme: &Theme) -> Color {
+        let pct = self.ram_usage_pct;
+        if pct >= thresholds.critical_pct {
+            theme.error
+        } else if pct >= thresholds.warn_pct {
+            theme.warning
         } else {
-            Color::Green
+            theme.success
+        }
+    }
+}
+
+/// RAM usage percentages at which the RAM gauge changes color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RamThresholds {
+    /// Yellow from this percentage of total RAM.
+    pub warn_pct: f32,
+    /// Red from this percentage of total RAM.
+    pub critical_pct: f32,
+}
+
+impl Default for RamThresholds {
+    fn default() -> Self {
+        Self {
+            warn_pct: DEFAULT_RAM_WARN_PCT,
+            critical_pct: DEFAULT_RAM_CRITICAL_PCT,
+        }
+    }
+}
+
+/// The hottest CPU sensor among `(label, temperature)` readings, if any.
+/// Sensor names vary by platform and driver ("coretemp Package id 0",
+/// "k10temp Tctl", "CPU", ...); drive, GPU and other sensors are ignored, as
+/// are readings that aren't plausible temperatures.
+fn cpu_temperature<'a>(sensors: impl IntoIterator<Item = (&'a str, Option<f32>)>) -> Option<f32> {
+    const CPU_LABELS: [&str; 5] = ["cpu", "package", "core", "tctl", "tdie"];
+    sensors
+        .into_iter()
+        .filter(|(label, _)| {
+            let label = label.to_lowercase();
+            CPU_LABELS.iter().any(|cpu| label.contains(cpu))
+        })
+        .filter_map(|(_, celsius)| celsius.filter(|c| c.is_finite() && *c > 0.0))
+        .reduce(f32::max)
+}
+
+/// `used` as a percentage of `total`, clamped to [0, 100] so a transient
+/// reading above the total (or no total at all) can't break the gauges.
+fn usage_pct(used: u64, total: u64) -> f32 {
+    if total == 0 {
+        0.0
+    } else {
+        (used as f64 / total as f64 * 100.0).clamp(0.0, 100.0) as f32
+    }
+}
+
+/// Exponential moving average step: move `previous` towards `sample` by
+/// `alpha` of the difference. Without a previous value or `alpha`, the
+/// sample itself.
+fn smooth(previous: Option<f64>, sample: f64, alpha: Option<f32>) -> f64 {
+    match (previous, alpha) {
+        (Some(previous), Some(alpha)) => previous + f64::from(alpha) * (sample - previous),
+        _ => sample,
+    }
+}
+
+/// One reading of GPU load and memory, summed over all devices.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GpuSample {
+    /// Average utilization percentage across devices (0.0 to 100.0).
+    pub utilization_pct: f32,
+    /// GPU memory in use in bytes.
+    pub memory_used_bytes: u64,
+    /// Total GPU memory in bytes.
+    pub memory_total_bytes: u64,
+}
+
+/// Reads GPU metrics through NVML when built with the `gpu` feature.
+///
+/// Without the feature, or when NVML can't be initialized (e.g. no NVIDIA
+/// driver), every sample is `None` and the dashboard shows no GPU rows.
+pub struct GpuMonitor {
+    #[cfg(feature = "gpu")]
+    nvml: Option<nvml_wrapper::Nvml>,
+}
+
+impl GpuMonitor {
+    /// Initialize NVML, falling back to no GPU metrics on failure.
+    pub fn new() -> Self {
+        Self {
+            #[cfg(feature = "gpu")]
+            nvml: nvml_wrapper::Nvml::init().ok(),
+        }
+    }
+
+    /// Sample utilization and memory across all GPUs, if available.
+    #[cfg(feature = "gpu")]
+    pub fn sample(&self) -> Option<GpuSample> {
+        let nvml = self.nvml.as_ref()?;
+        let count = nvml.device_count().ok().filter(|&n| n > 0)?;
+
+        let mut utilization_total = 0u32;
+        let mut sample = GpuSample {
+            utilization_pct: 0.0,
+            memory_used_bytes: 0,
+            memory_total_bytes: 0,
+        };
+        for index in 0..count {
+            let device = nvml.device_by_index(index).ok()?;
+            utilization_total += device.utilization_rates().ok()?.gpu;
+            let memory = device.memory_info().ok()?;
+            sample.memory_used_bytes += memory.used;
+            sample.memory_total_bytes += memory.total;
         }
+        sample.utilization_pct = utilization_total as f32 / count as f32;
+        Some(sample)
+    }
+
+    /// Sample utilization and memory across all GPUs, if available.
+    #[cfg(not(feature = "gpu"))]
+    pub fn sample(&self) -> Option<GpuSample> {
+        None
+    }
+}
+
+impl Default for GpuMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for GpuMonitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GpuMonitor")
+            .field("enabled", &cfg!(feature = "gpu"))
+            .finish()
+    }
+}
+
+/// Point-in-time copy of the dashboard metrics, shared with exporters.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MetricsSnapshot {
+    /// zkVM task metrics
+    pub zkvm: ZkVMMetrics,
+    /// System metrics (CPU, RAM, etc.)
+    pub system: SystemMetrics,
+}
+
+/// Outcome of the last task, overall or for one prover.
+///
+/// Serialized as the displayed text, so JSON consumers see the same values as
+/// the dashboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum TaskStatus {
+    /// No task has finished yet
+    None,
+    /// Waiting for a task
+    Idle,
+    /// A proof is in progress
+    Proving,
+    /// A proof was generated and awaits submission
+    Proved,
+    /// The proof was accepted by the orchestrator
+    Success,
+    #[serde(rename = "Proof Failed")]
+    ProofFailed,
+    #[serde(rename = "Submit Failed")]
+    SubmitFailed,
+    /// A proof has run past the stuck threshold
+    Stuck,
+    /// A task went missing between workers
+    Dropped,
+}
+
+impl TaskStatus {
+    /// Color the status is shown in
+    pub fn color(self, theme: &Theme) -> Color {
+        match self {
+            TaskStatus::Proving => theme.value,
+            TaskStatus::Proved | TaskStatus::Success => theme.success,
+            TaskStatus::ProofFailed
+            | TaskStatus::SubmitFailed
+            | TaskStatus::Stuck
+            | TaskStatus::Dropped => theme.error,
+            TaskStatus::None | TaskStatus::Idle => theme.label,
+        }
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            TaskStatus::None => "None",
+            TaskStatus::Idle => "Idle",
+            TaskStatus::Proving => "Proving",
+            TaskStatus::Proved => "Proved",
+            TaskStatus::Success => "Success",
+            TaskStatus::ProofFailed => "Proof Failed",
+            TaskStatus::SubmitFailed => "Submit Failed",
+            TaskStatus::Stuck => "Stuck",
+            TaskStatus::Dropped => "Dropped",
+        };
+        f.write_str(text)
     }
 }
 
 /// zkVM task metrics for display.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ZkVMMetrics {
-    /// Total number of tasks executed.
+    /// Number of tasks obtained from the orchestrator.
     pub tasks_fetched: usize,
-    /// Number of tasks successfully proved.
+    /// Number of tasks a proof was generated for.
+    pub tasks_proved: usize,
+    /// Number of proofs accepted by the orchestrator.
     pub tasks_submitted: usize,
+    /// Number of proof generation failures.
+    pub proof_failures: usize,
+    /// Number of proof submission failures.
+    pub submit_failures: usize,
     /// Total zkVM runtime in seconds.
     pub zkvm_runtime_secs: u64,
     /// Status of the last task.
-    pub last_task_status: String,
-    /// Total points earned from successful proofs (300 points each).
-    pub _total_points: u64,
+    pub last_task_status: TaskStatus,
+    /// Number of proofs whose start was never observed, so their duration is
+    /// missing from `zkvm_runtime_secs` (a data-quality indicator).
+    pub untimed_proofs: usize,
+    /// Total points credited for successful proofs, at the rate of the time.
+    pub total_points: u64,
+    /// Total serialized size of this session's proofs that reported one, in bytes.
+    pub total_proof_bytes: u64,
+    /// Number of proofs counted in `total_proof_bytes`.
+    pub sized_proofs: usize,
+    /// Number of this session's tasks that went missing in flight (no
+    /// terminal event before the timeout).
+    pub dropped_tasks: usize,
 }
 
 impl Default for ZkVMMetrics {
     fn default() -> Self {
         Self {
             tasks_fetched: 0,
+            tasks_proved: 0,
             tasks_submitted: 0,
+            proof_failures: 0,
+            submit_failures: 0,
             zkvm_runtime_secs: 0,
-            last_task_status: "None".to_string(),
-            _total_points: 0,
+            last_task_status: TaskStatus::None,
+            untimed_proofs: 0,
+            total_points: 0,
+            total_proof_bytes: 0,
+            sized_proofs: 0,
+            dropped_tasks: 0,
         }
     }
 }
 
 impl ZkVMMetrics {
-    /// Calculate success rate as a percentage.
+    /// Calculate success rate as a percentage of finished tasks:
+    /// submitted / (submitted + proof failures + submit failures).
+    ///
+    /// Tasks still in flight don't count either way. 0% until a task finishes.
     pub fn success_rate(&self) -> f64 {
-        if self.tasks_fetched == 0 {
+        let finished = self.tasks_submitted + self.proof_failures + self.submit_failures;
+        if finished == 0 {
             0.0
         } else {
-            (self.tasks_submitted as f64 / self.tasks_fetched as f64) * 100.0
+            self.tasks_submitted as f64 / finished as f64 * 100.0
         }
     }
 
     /// Format total points with commas for better readability.
-    pub fn _format_points(&self) -> String {
-        let points = self._total_points;
-        if points >= 1_000_000 {
-            format!("{:.1}M", points as f64 / 1_000_000.0)
-        } else if points >= 1_000 {
-            format!("{},{:03}", points / 1_000, points % 1_000)
-        } else {
-            points.to_string()
-        }
+    pub fn format_points(&self) -> String {
+        format_point_count(self.total_points)
     }
 
     /// Get success rate color based on performance.
-    pub fn success_rate_color(&self) -> ratatui::prelude::Color {
-        use ratatui::prelude::Color;
+    pub fn success_rate_color(&self, theme: &Theme) -> Color {
         let rate = self.success_rate();
         if rate >= 75.0 {
-            Color::Green
+            theme.success
         } else if rate >= 50.0 {
-            Color::Yellow
+            theme.warning
         } else {
-            Color::Red
+            theme.error
         }
     }
 
     /// Format zkVM runtime as human-readable string.
     pub fn format_runtime(&self) -> String {
-        let hours = self.zkvm_runtime_secs / 3600;
-        let minutes = (self.zkvm_runtime_secs % 3600) / 60;
-        let seconds = self.zkvm_runtime_secs % 60;
-
-        if hours > 0 {
-            format!("{}h {}m {}s", hours, minutes, seconds)
-        } else if minutes > 0 {
-            format!("{}m {}s", minutes, seconds)
+        format_duration(self.zkvm_runtime_secs)
+    }
+
+    /// Average proving time per task in seconds, over proofs whose duration is
+    /// known (untimed proofs add nothing to the runtime). `None` before the first.
+    pub fn avg_proving_secs(&self) -> Option<f64> {
+        let timed = self.tasks_proved.saturating_sub(self.untimed_proofs);
+        (timed > 0).then(|| self.zkvm_runtime_secs as f64 / timed as f64)
+    }
+
+    /// Average serialized proof size in bytes, over proofs that reported one.
+    /// `None` before the first.
+    pub fn avg_proof_bytes(&self) -> Option<u64> {
+        (self.sized_proofs > 0).then(|| self.total_proof_bytes / self.sized_proofs as u64)
+    }
+}
+
+/// Rate of a cumulative counter since the previous sample. Without a previous
+/// sample (or elapsed time) there's no baseline, so the rate is zero rather
+/// than the whole counter at once; a counter that went backwards (e.g. an
+/// interface went away) also reads as zero.
+fn bytes_per_sec(previous: u64, current: u64, elapsed: Option<Duration>) -> f64 {
+    match elapsed {
+        Some(elapsed) if !elapsed.is_zero() => {
+            current.saturating_sub(previous) as f64 / elapsed.as_secs_f64()
+        }
+        _ => 0.0,
+    }
+}
+
+/// Format a byte count with a binary unit (B, KB, MB, GB).
+pub fn format_bytes(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    let bytes_f = bytes as f64;
+    if bytes_f >= KB * KB * KB {
+        format!("{:.1} GB", bytes_f / (KB * KB * KB))
+    } else if bytes_f >= KB * KB {
+        format!("{:.1} MB", bytes_f / (KB * KB))
+    } else if bytes_f >= KB {
+        format!("{:.1} KB", bytes_f / KB)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+/// Format a point count with commas (or in millions) for readability.
+pub fn format_point_count(points: u64) -> String {
+    if points >= 1_000_000 {
+        format!("{:.1}M", points as f64 / 1_000_000.0)
+    } else if points >= 1_000 {
+        format!("{},{:03}", points / 1_000, points % 1_000)
+    } else {
+        points.to_string()
+    }
+}
+
+/// Format an earnings estimate, or "—" while there isn't one yet.
+pub fn format_points_estimate(points: Option<f64>) -> String {
+    match points {
+        Some(points) => format_point_count(points.round() as u64),
+        None => "—".to_string(),
+    }
+}
+
+/// Format seconds as human-readable string, e.g. "1h 2m 3s".
+pub fn format_duration(secs: u64) -> String {
+    let hours = secs / 3600;
+    let minutes = (secs % 3600) / 60;
+    let seconds = secs % 60;
+
+    if hours > 0 {
+        format!("{}h {}m {}s", hours, minutes, seconds)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+/// Append `item` to a bounded buffer, removing the oldest entries past
+/// `capacity` (at least one is always kept). The removed entries are returned
+/// so callers can drop whatever they index by them.
+pub fn push_bounded<T>(
+    buffer: &mut VecDeque<T>,
+    capacity: usize,
+    item: T,
+) -> std::collections::vec_deque::Drain<'_, T> {
+    buffer.push_back(item);
+    let excess = buffer.len().saturating_sub(capacity.max(1));
+    buffer.drain(..excess)
+}
+
+/// Min, mean, max and latest of a metric's most recent samples.
+///
+/// Bounded like the other trackers, evicting the oldest sample past capacity.
+/// The mean comes from a running sum that is adjusted on eviction, so it
+/// doesn't walk the buffer. Samples keep the time they were recorded, for
+/// averages over a time window.
+#[derive(Debug, Clone)]
+pub struct RollingStats {
+    /// Maximum number of samples retained.
+    capacity: usize,
+    /// When each retained sample was recorded and its value, oldest first.
+    samples: VecDeque<(Instant, f64)>,
+    /// Sum of the retained samples.
+    sum: f64,
+}
+
+impl RollingStats {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            samples: VecDeque::new(),
+            sum: 0.0,
+        }
+    }
+
+    /// Record a sample taken at `at`, evicting the oldest past capacity.
+    pub fn record(&mut self, at: Instant, value: f64) {
+        for (_, evicted) in push_bounded(&mut self.samples, self.capacity, (at, value)) {
+            self.sum -= evicted;
+        }
+        self.sum += value;
+    }
+
+    /// Number of retained samples.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    fn values(&self) -> impl DoubleEndedIterator<Item = f64> + '_ {
+        self.samples.iter().map(|&(_, value)| value)
+    }
+
+    /// Smallest retained sample.
+    pub fn min(&self) -> Option<f64> {
+        self.values().reduce(f64::min)
+    }
+
+    /// Mean of the retained samples.
+    pub fn mean(&self) -> Option<f64> {
+        (!self.samples.is_empty()).then(|| self.sum / self.samples.len() as f64)
+    }
+
+    /// Largest retained sample.
+    pub fn max(&self) -> Option<f64> {
+        self.values().reduce(f64::max)
+    }
+
+    /// Most recent sample.
+    pub fn latest(&self) -> Option<f64> {
+        self.samples.back().map(|&(_, value)| value)
+    }
+
+    /// Mean of the latest `count` samples, or `None` until that many have
+    /// been retained.
+    pub fn mean_of_latest(&self, count: usize) -> Option<f64> {
+        if count == 0 || self.samples.len() < count {
+            return None;
+        }
+        Some(self.values().rev().take(count).sum::<f64>() / count as f64)
+    }
+
+    /// Mean of the samples recorded within `window` of `now`, or `None` if
+    /// there were none.
+    pub fn mean_over(&self, now: Instant, window: Duration) -> Option<f64> {
+        let (count, total) = self
+            .samples
+            .iter()
+            .filter(|&&(at, _)| now.saturating_duration_since(at) < window)
+            .fold((0usize, 0.0), |(count, total), &(_, value)| {
+                (count + 1, total + value)
+            });
+        (count > 0).then(|| total / count as f64)
+    }
+}
+
+/// Durations of the most recent proofs, for min/max proving time.
+///
+/// Bounded so a long-running dashboard doesn't grow without limit; the
+/// extremes describe the retained proofs, which track current hardware.
+#[derive(Debug, Clone)]
+pub struct ProvingTimes {
+    /// Durations in seconds, each recorded when its proof finished.
+    stats: RollingStats,
+}
+
+impl ProvingTimes {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            stats: RollingStats::new(capacity),
+        }
+    }
+
+    /// Record a proof that finished at `at`, evicting the oldest past capacity.
+    pub fn record(&mut self, at: Instant, secs: u64) {
+        self.stats.record(at, secs as f64);
+    }
+
+    /// Statistics of the retained proving times, in seconds.
+    pub fn stats(&self) -> &RollingStats {
+        &self.stats
+    }
+
+    /// Shortest retained proving time in seconds.
+    pub fn min(&self) -> Option<u64> {
+        self.stats.min().map(|secs| secs as u64)
+    }
+
+    /// Longest retained proving time in seconds.
+    pub fn max(&self) -> Option<u64> {
+        self.stats.max().map(|secs| secs as u64)
+    }
+
+    /// Duration of the most recent proof in seconds.
+    pub fn latest(&self) -> Option<u64> {
+        self.stats.latest().map(|secs| secs as u64)
+    }
+
+    /// Mean proving time of the latest `count` proofs, or `None` until that
+    /// many have been retained.
+    pub fn recent_average(&self, count: usize) -> Option<f64> {
+        self.stats.mean_of_latest(count)
+    }
+
+    /// Mean proving time of the retained proofs that finished within `window`
+    /// of `now`, or `None` if there were none.
+    pub fn average_over(&self, now: Instant, window: Duration) -> Option<f64> {
+        self.stats.mean_over(now, window)
+    }
+}
+
+/// Time proofs spend between generation and successful submission.
+///
+/// Generated proofs wait in a pending map keyed by task ID until their
+/// submission arrives; proofs that are never submitted expire from it after
+/// `pending_timeout`, so it can't grow without limit.
+#[derive(Debug, Clone)]
+pub struct SubmissionLatency {
+    /// How long a generated proof may wait for its submission.
+    pending_timeout: Duration,
+    /// When each not-yet-submitted proof was generated.
+    pending: HashMap<String, Instant>,
+    /// Latencies of the most recent submissions, in seconds.
+    samples: RollingStats,
+}
+
+impl SubmissionLatency {
+    pub fn new(capacity: usize, pending_timeout: Duration) -> Self {
+        Self {
+            pending_timeout,
+            pending: HashMap::new(),
+            samples: RollingStats::new(capacity),
+        }
+    }
+
+    /// Note that a proof for `task_id` was generated. A repeat keeps the
+    /// first generation time.
+    pub fn generated(&mut self, task_id: &str, now: Instant) {
+        self.expire(now);
+        self.pending.entry(task_id.to_string()).or_insert(now);
+    }
+
+    /// Match a submission to its generation, returning the latency. `None`
+    /// if the generation wasn't seen (or has expired).
+    pub fn submitted(&mut self, task_id: &str, now: Instant) -> Option<Duration> {
+        let latency = now.saturating_duration_since(self.pending.remove(task_id)?);
+        self.samples.record(now, latency.as_secs_f64());
+        Some(latency)
+    }
+
+    /// Drop generations that have waited past the timeout.
+    fn expire(&mut self, now: Instant) {
+        let timeout = self.pending_timeout;
+        self.pending
+            .retain(|_, generated_at| now.saturating_duration_since(*generated_at) < timeout);
+    }
+
+    /// Number of generated proofs awaiting submission.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Statistics of the retained submissions' latencies, in seconds.
+    pub fn stats(&self) -> &RollingStats {
+        &self.samples
+    }
+
+    /// Average latency of the retained submissions.
+    pub fn average(&self) -> Option<Duration> {
+        self.samples.mean().map(Duration::from_secs_f64)
+    }
+
+    /// Longest latency of the retained submissions.
+    pub fn max(&self) -> Option<Duration> {
+        self.samples.max().map(Duration::from_secs_f64)
+    }
+}
+
+/// Retries each task needed before it was submitted.
+///
+/// A task's retries are the failed fetches since the previous task was
+/// fetched, plus its extra submission attempts. Tasks still in flight are
+/// tracked by ID (the most recent `capacity` of them); a task that fails
+/// outright is dropped, since it never succeeded.
+#[derive(Debug, Clone)]
+pub struct RetryStats {
+    /// Maximum number of tasks tracked, and of submissions averaged over.
+    capacity: usize,
+    /// Failed fetches not yet attributed to a fetched task.
+    fetch_failures: u32,
+    /// Retries so far of each task in flight, oldest first.
+    in_flight: VecDeque<(String, u32)>,
+    /// Retry counts of the most recent submissions.
+    samples: RollingStats,
+}
+
+impl RetryStats {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            fetch_failures: 0,
+            in_flight: VecDeque::new(),
+            samples: RollingStats::new(capacity),
+        }
+    }
+
+    /// Note a failed fetch; it counts against whichever task is fetched next.
+    pub fn fetch_failed(&mut self) {
+        self.fetch_failures += 1;
+    }
+
+    /// Start tracking `task_id` with the fetch failures that preceded it.
+    pub fn fetched(&mut self, task_id: &str) {
+        let retries = std::mem::take(&mut self.fetch_failures);
+        self.remove(task_id);
+        push_bounded(
+            &mut self.in_flight,
+            self.capacity,
+            (task_id.to_string(), retries),
+        );
+    }
+
+    /// Record a successful submission at `at` taking `attempts` (when known),
+    /// returning how many retries the task needed in total.
+    pub fn submitted(&mut self, task_id: &str, attempts: Option<u32>, at: Instant) -> u32 {
+        let fetch_retries = self.remove(task_id).unwrap_or(0);
+        let retries = fetch_retries + attempts.map_or(0, |attempts| attempts.saturating_sub(1));
+        self.samples.record(at, f64::from(retries));
+        retries
+    }
+
+    /// Stop tracking a task that failed.
+    pub fn failed(&mut self, task_id: &str) {
+        self.remove(task_id);
+    }
+
+    fn remove(&mut self, task_id: &str) -> Option<u32> {
+        let index = self.in_flight.iter().position(|(id, _)| id == task_id)?;
+        self.in_flight.remove(index).map(|(_, retries)| retries)
+    }
+
+    /// Retries so far of a task in flight.
+    pub fn retries(&self, task_id: &str) -> Option<u32> {
+        self.in_flight
+            .iter()
+            .find(|(id, _)| id == task_id)
+            .map(|&(_, retries)| retries)
+    }
+
+    /// Statistics of the retained submissions' retry counts.
+    pub fn stats(&self) -> &RollingStats {
+        &self.samples
+    }
+
+    /// Average retries before success over the retained submissions.
+    pub fn average(&self) -> Option<f64> {
+        self.samples.mean()
+    }
+}
+
+/// Progress of a single prover, keyed by its index in `Worker::Prover`.
+#[derive(Debug, Clone)]
+pub struct ProverMetrics {
+    /// Task currently being proved, if any.
+    pub current_task: Option<String>,
+    /// Status of this prover's last task.
+    pub last_status: TaskStatus,
+    /// Total proving time in seconds.
+    pub runtime_secs: u64,
+    /// Number of proofs generated.
+    pub tasks_proved: usize,
+    /// When the current proof started, if its start was observed.
+    pub proving_since: Option<Instant>,
+    /// Last task this prover generated a proof for.
+    pub last_proved: Option<String>,
+    /// Highest process RAM sampled during the current proof.
+    pub proving_peak_ram_bytes: u64,
+}
+
+impl Default for ProverMetrics {
+    fn default() -> Self {
+        Self {
+            current_task: None,
+            last_status: TaskStatus::Idle,
+            runtime_secs: 0,
+            tasks_proved: 0,
+            proving_since: None,
+            last_proved: None,
+            proving_peak_ram_bytes: 0,
+        }
+    }
+}
+
+/// Submissions over a sliding time window, for recent throughput.
+///
+/// Submissions age out of the window on their own, so the rate recovers
+/// (or drops to zero) without any reset.
+#[derive(Debug, Clone)]
+pub struct ThroughputWindow {
+    /// Length of the sliding window.
+    window: Duration,
+    /// When tracking started; early rates are averaged over this shorter span.
+    started_at: Instant,
+    /// Submission times still inside the window, oldest first, with the
+    /// points credited for each.
+    submissions: VecDeque<(Instant, u64)>,
+}
+
+impl ThroughputWindow {
+    /// Shortest span a rate is averaged over, so the first proof doesn't read as a huge rate.
+    const MIN_SPAN: Duration = Duration::from_secs(60);
+    /// How long to track before extrapolating earnings.
+    const ESTIMATE_WARMUP: Duration = Duration::from_secs(10 * 60);
+
+    pub fn new(window: Duration, started_at: Instant) -> Self {
+        Self {
+            window,
+            started_at,
+            submissions: VecDeque::new(),
+        }
+    }
+
+    /// Record a submission and the points it was credited.
+    pub fn record(&mut self, at: Instant, points: u64) {
+        self.submissions.push_back((at, points));
+        self.prune(at);
+    }
+
+    /// Drop submissions that have aged out of the window.
+    pub fn prune(&mut self, now: Instant) {
+        while self
+            .submissions
+            .front()
+            .is_some_and(|&(at, _)| now.saturating_duration_since(at) >= self.window)
+        {
+            self.submissions.pop_front();
+        }
+    }
+
+    /// Submissions per hour over the window (or since tracking started, if sooner).
+    pub fn tasks_per_hour(&self, now: Instant) -> f64 {
+        self.recent(now).count() as f64 * 3600.0 / self.span(now).as_secs_f64()
+    }
+
+    /// Submissions still inside the window at `now`.
+    fn recent(&self, now: Instant) -> impl Iterator<Item = &(Instant, u64)> {
+        self.submissions
+            .iter()
+            .filter(move |&&(at, _)| now.saturating_duration_since(at) < self.window)
+    }
+
+    /// Span the window's rates are averaged over.
+    fn span(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.started_at)
+            .clamp(Self::MIN_SPAN, self.window.max(Self::MIN_SPAN))
+    }
+
+    /// Submissions per minute over the same window.
+    pub fn tasks_per_minute(&self, now: Instant) -> f64 {
+        self.tasks_per_hour(now) / 60.0
+    }
+
+    /// Submissions per hour over a shorter window than the one retained.
+    /// Windows longer than the retained one are capped to it.
+    pub fn tasks_per_hour_over(&self, now: Instant, window: Duration) -> f64 {
+        let window = window.min(self.window);
+        let recent = self
+            .submissions
+            .iter()
+            .filter(|&&(at, _)| now.saturating_duration_since(at) < window)
+            .count();
+        let span = now
+            .saturating_duration_since(self.started_at)
+            .clamp(Self::MIN_SPAN.min(window), window.max(Self::MIN_SPAN));
+        recent as f64 * 3600.0 / span.as_secs_f64()
+    }
+
+    /// Submissions per hour at the pace of the latest submission: the gap
+    /// since the one before it, or since it if that has grown longer.
+    pub fn instant_tasks_per_hour(&self, now: Instant) -> f64 {
+        let mut latest = self.submissions.iter().rev();
+        let Some(&(last, _)) = latest.next() else {
+            return 0.0;
+        };
+        let previous = latest.next().map_or(self.started_at, |&(at, _)| at);
+        let gap = last
+            .saturating_duration_since(previous)
+            .max(now.saturating_duration_since(last))
+            .max(Duration::from_secs(1));
+        3600.0 / gap.as_secs_f64()
+    }
+
+    /// Estimated points per hour from the points credited over the window.
+    /// `None` until tracking has run long enough for the rate to mean something.
+    pub fn points_per_hour(&self, now: Instant) -> Option<f64> {
+        (now.saturating_duration_since(self.started_at) >= Self::ESTIMATE_WARMUP).then(|| {
+            let points: u64 = self.recent(now).map(|&(_, points)| points).sum();
+            points as f64 * 3600.0 / self.span(now).as_secs_f64()
+        })
+    }
+}
+
+/// Window the throughput and proving-time figures are averaged over
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AverageWindow {
+    /// The full throughput window and the session's average proving time
+    #[default]
+    Overall,
+    /// The latest submission and proof only
+    Instant,
+    /// The last minute
+    OneMinute,
+    /// The last five minutes
+    FiveMinutes,
+}
+
+impl AverageWindow {
+    /// The next window in the cycle
+    pub fn next(self) -> Self {
+        match self {
+            Self::Overall => Self::Instant,
+            Self::Instant => Self::OneMinute,
+            Self::OneMinute => Self::FiveMinutes,
+            Self::FiveMinutes => Self::Overall,
+        }
+    }
+
+    /// Short label for the panel, or `None` for the overall figures
+    pub fn label(self) -> Option<&'static str> {
+        match self {
+            Self::Overall => None,
+            Self::Instant => Some("instant"),
+            Self::OneMinute => Some("1m avg"),
+            Self::FiveMinutes => Some("5m avg"),
+        }
+    }
+
+    /// Span averaged over, for the windows with a fixed length
+    pub fn duration(self) -> Option<Duration> {
+        match self {
+            Self::OneMinute => Some(Duration::from_secs(60)),
+            Self::FiveMinutes => Some(Duration::from_secs(5 * 60)),
+            Self::Overall | Self::Instant => None,
+        }
+    }
+}
+
+/// How today's submissions compare with the daily target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetPace {
+    /// The target is on course to be beaten comfortably (or already met)
+    Ahead,
+    /// The target is on course to be met, with little to spare
+    OnPace,
+    /// At the recent rate the target won't be met today
+    Behind,
+}
+
+/// Tasks submitted today, against a daily target. "Today" is the local
+/// calendar day; the count starts over at local midnight.
+#[derive(Debug, Clone)]
+pub struct DailyTarget {
+    /// Tasks to submit per day.
+    pub target: u64,
+    /// The local day being counted.
+    day: NaiveDate,
+    /// Tasks submitted so far on `day`.
+    pub submitted_today: u64,
+}
+
+impl DailyTarget {
+    /// Projections within this fraction above the target count as on pace.
+    const PACE_MARGIN: f64 = 0.1;
+
+    pub fn new(target: u64, today: NaiveDate) -> Self {
+        Self {
+            target,
+            day: today,
+            submitted_today: 0,
+        }
+    }
+
+    /// Start a new count if the local day has changed since the last call.
+    pub fn roll_over(&mut self, today: NaiveDate) {
+        if today != self.day {
+            self.day = today;
+            self.submitted_today = 0;
+        }
+    }
+
+    /// Record a submission made today.
+    pub fn record(&mut self, now: NaiveDateTime) {
+        self.roll_over(now.date());
+        self.submitted_today += 1;
+    }
+
+    /// Fraction of the target met so far, at most 1.
+    pub fn progress(&self) -> f64 {
+        if self.target == 0 {
+            return 1.0;
+        }
+        (self.submitted_today as f64 / self.target as f64).min(1.0)
+    }
+
+    /// Compare the target with what today's count would reach by midnight if
+    /// the recent rate held for the rest of the day.
+    pub fn pace(&self, now: NaiveDateTime, tasks_per_hour: f64) -> TargetPace {
+        let hours_left = (24 * 3600 - now.time().num_seconds_from_midnight()) as f64 / 3600.0;
+        let projected = self.submitted_today as f64 + tasks_per_hour * hours_left;
+        let target = self.target as f64;
+        if projected < target {
+            TargetPace::Behind
+        } else if self.submitted_today >= self.target
+            || projected > target * (1.0 + Self::PACE_MARGIN)
+        {
+            TargetPace::Ahead
         } else {
-            format!("{}s", seconds)
+            TargetPace::OnPace
         }
     }
 }
@@ -280,3 +1246,488 @@ impl Default for TaskFetchInfo {
         }
     }
 }
+
+impl TaskFetchInfo {
+    /// Whether a backoff is being waited out
+    pub fn is_backing_off(&self) -> bool {
+        !self.can_fetch_now && self.backoff_duration_secs > 0
+    }
+
+    /// Seconds left in the backoff, never below zero
+    pub fn remaining_secs(&self) -> u64 {
+        self.backoff_duration_secs
+            .saturating_sub(self.time_since_last_fetch_secs)
+    }
+
+    /// How far through the backoff we are (0 to 100); 100 when there is none
+    pub fn progress_pct(&self) -> u16 {
+        if !self.is_backing_off() {
+            return 100;
+        }
+        let elapsed = self
+            .time_since_last_fetch_secs
+            .min(self.backoff_duration_secs);
+        (elapsed * 100 / self.backoff_duration_secs) as u16
+    }
+}
+
+/// Time and events since the last successful submission, as a quick health read.
+#[derive(Debug, Clone, Default)]
+pub struct SinceSuccess {
+    /// When the last submission succeeded, if one has this session.
+    last_success_at: Option<Instant>,
+    /// Worker events processed after it (or since startup, before one).
+    events: usize,
+}
+
+impl SinceSuccess {
+    /// Count an event. Call before `succeeded`, so a success isn't counted towards itself.
+    pub fn record_event(&mut self) {
+        self.events += 1;
+    }
+
+    /// Restart from a successful submission at `at`.
+    pub fn succeeded(&mut self, at: Instant) {
+        self.last_success_at = Some(at);
+        self.events = 0;
+    }
+
+    /// Events since the last success.
+    pub fn events(&self) -> usize {
+        self.events
+    }
+
+    /// Time since the last success, if there has been one.
+    pub fn elapsed(&self, now: Instant) -> Option<Duration> {
+        self.last_success_at
+            .map(|at| now.saturating_duration_since(at))
+    }
+
+    /// E.g. "4m ago (37 events since)", or "none yet" before the first success.
+    pub fn describe(&self, now: Instant) -> String {
+        let Some(at) = self.last_success_at else {
+            return "none yet".to_string();
+        };
+        let secs = now.saturating_duration_since(at).as_secs();
+        let ago = if secs < 60 {
+            format!("{}s", secs)
+        } else if secs < 3600 {
+            format!("{}m", secs / 60)
+        } else {
+            format!("{}h {}m", secs / 3600, (secs % 3600) / 60)
+        };
+        let noun = if self.events == 1 { "event" } else { "events" };
+        format!("{} ago ({} {} since)", ago, self.events, noun)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    fn minutes(n: u64) -> Duration {
+        Duration::from_secs(n * 60)
+    }
+
+    #[test]
+    fn test_since_success_describes_the_wait() {
+        let t0 = Instant::now();
+        let mut since = SinceSuccess::default();
+        since.record_event();
+        assert_eq!(since.describe(t0), "none yet");
+
+        since.succeeded(t0);
+        since.record_event();
+        assert_eq!(
+            since.describe(t0 + Duration::from_secs(5)),
+            "5s ago (1 event since)"
+        );
+        for _ in 0..36 {
+            since.record_event();
+        }
+        assert_eq!(since.describe(t0 + minutes(4)), "4m ago (37 events since)");
+        assert_eq!(
+            since.describe(t0 + minutes(125)),
+            "2h 5m ago (37 events since)"
+        );
+    }
+
+    #[test]
+    fn test_throughput_at_a_steady_cadence() {
+        let t0 = Instant::now();
+        let mut window = ThroughputWindow::new(HOUR, t0);
+
+        // One submission every 5 minutes for two hours
+        for i in 1..=24 {
+            window.record(t0 + minutes(5 * i), 100);
+        }
+
+        let now = t0 + minutes(120);
+        assert_eq!(window.tasks_per_hour(now), 12.0);
+        assert_eq!(window.tasks_per_minute(now), 0.2);
+    }
+
+    #[test]
+    fn test_throughput_uses_elapsed_time_before_a_full_window() {
+        let t0 = Instant::now();
+        let mut window = ThroughputWindow::new(HOUR, t0);
+
+        // Five submissions in the first ten minutes
+        for i in 1..=5 {
+            window.record(t0 + minutes(2 * i), 100);
+        }
+        assert_eq!(window.tasks_per_hour(t0 + minutes(10)), 30.0);
+    }
+
+    #[test]
+    fn test_throughput_decays_as_submissions_age_out() {
+        let t0 = Instant::now();
+        let mut window = ThroughputWindow::new(HOUR, t0);
+        for i in 1..=12 {
+            window.record(t0 + minutes(5 * i), 100);
+        }
+
+        let later = t0 + minutes(60) + minutes(90);
+        window.prune(later);
+        assert_eq!(window.tasks_per_hour(later), 0.0);
+        assert!(window.submissions.is_empty());
+    }
+
+    #[test]
+    fn test_throughput_recomputes_over_shorter_windows() {
+        let t0 = Instant::now();
+        let mut window = ThroughputWindow::new(HOUR, t0);
+        assert_eq!(window.instant_tasks_per_hour(t0), 0.0);
+
+        // One every 5 minutes for half an hour, then one a minute for five minutes
+        for i in 1..=6 {
+            window.record(t0 + minutes(5 * i), 100);
+        }
+        for i in 1..=5 {
+            window.record(t0 + minutes(30 + i), 100);
+        }
+
+        let now = t0 + minutes(35) + Duration::from_secs(30);
+        assert_eq!(window.tasks_per_hour_over(now, minutes(5)), 60.0);
+        assert_eq!(window.tasks_per_hour_over(now, minutes(1)), 60.0);
+        assert_eq!(window.instant_tasks_per_hour(now), 60.0);
+        // Longer than retained is the full window, averaged since tracking started
+        assert_eq!(
+            window.tasks_per_hour_over(now, 2 * HOUR),
+            window.tasks_per_hour(now)
+        );
+
+        // The instant rate decays once the next submission is overdue
+        assert_eq!(window.instant_tasks_per_hour(t0 + minutes(37)), 30.0);
+    }
+
+    #[test]
+    fn test_average_window_cycles_back_to_overall() {
+        let mut window = AverageWindow::default();
+        let mut labels = Vec::new();
+        for _ in 0..4 {
+            labels.push(window.label());
+            window = window.next();
+        }
+        assert_eq!(window, AverageWindow::Overall);
+        assert_eq!(
+            labels,
+            [None, Some("instant"), Some("1m avg"), Some("5m avg")]
+        );
+    }
+
+    #[test]
+    fn test_ram_usage_is_clamped_and_colored_by_threshold() {
+        assert_eq!(usage_pct(0, 0), 0.0);
+        assert_eq!(usage_pct(256, 1024), 25.0);
+        // Subprocess memory briefly exceeding the total stays at 100%
+        assert_eq!(usage_pct(2048, 1024), 100.0);
+
+        let metrics = SystemMetrics {
+            ram_usage_pct: 70.0,
+            ..SystemMetrics::default()
+        };
+        let theme = Theme::dark();
+        assert_eq!(
+            metrics.ram_color(RamThresholds::default(), &theme),
+            theme.warning
+        );
+        let strict = RamThresholds {
+            warn_pct: 50.0,
+            critical_pct: 65.0,
+        };
+        assert_eq!(metrics.ram_color(strict, &theme), theme.error);
+    }
+
+    #[test]
+    fn test_cpu_temperature_uses_the_hottest_cpu_sensor() {
+        assert_eq!(cpu_temperature([]), None);
+        // No CPU sensor (or no reading) means no temperature, not zero
+        assert_eq!(cpu_temperature([("nvme Composite", Some(45.0))]), None);
+        assert_eq!(cpu_temperature([("coretemp Package id 0", None)]), None);
+
+        let sensors = [
+            ("coretemp Package id 0", Some(61.0)),
+            ("coretemp Core 1", Some(67.5)),
+            ("amdgpu edge", Some(90.0)),
+            ("CPU", Some(f32::NAN)),
+        ];
+        assert_eq!(cpu_temperature(sensors), Some(67.5));
+
+        let theme = Theme::dark();
+        let hot = SystemMetrics {
+            cpu_temp_celsius: Some(67.5),
+            ..SystemMetrics::default()
+        };
+        assert_eq!(hot.cpu_temp_color(65.0, &theme), theme.error);
+        assert_eq!(hot.cpu_temp_color(80.0, &theme), theme.label);
+    }
+
+    #[test]
+    fn test_network_rate_starts_at_zero_and_tracks_deltas() {
+        // First sample: no baseline, so no spike from the cumulative counter
+        assert_eq!(bytes_per_sec(0, 5_000_000, None), 0.0);
+        assert_eq!(
+            bytes_per_sec(1_000, 3_048, Some(Duration::from_secs(2))),
+            1024.0
+        );
+        // Counters that reset don't produce a negative or huge rate
+        assert_eq!(bytes_per_sec(3_048, 100, Some(Duration::from_secs(1))), 0.0);
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(10 * 1024 * 1024), "10.0 MB");
+    }
+
+    #[test]
+    fn test_daily_target_starts_over_at_midnight() {
+        let at = |day: u32, hour: u32, minute: u32| {
+            NaiveDate::from_ymd_opt(2025, 3, day)
+                .unwrap()
+                .and_hms_opt(hour, minute, 0)
+                .unwrap()
+        };
+        let mut target = DailyTarget::new(100, at(1, 0, 0).date());
+
+        for _ in 0..40 {
+            target.record(at(1, 12, 0));
+        }
+        assert_eq!(target.progress(), 0.4);
+        // 40 done with 12h left: 4/h falls short, 5/h just makes it, 6/h has room to spare
+        assert_eq!(target.pace(at(1, 12, 0), 4.0), TargetPace::Behind);
+        assert_eq!(target.pace(at(1, 12, 0), 5.0), TargetPace::OnPace);
+        assert_eq!(target.pace(at(1, 12, 0), 6.0), TargetPace::Ahead);
+
+        target.record(at(1, 23, 59));
+        target.roll_over(at(1, 23, 59).date());
+        assert_eq!(target.submitted_today, 41);
+
+        // The first tick after midnight starts a new day
+        target.roll_over(at(2, 0, 0).date());
+        assert_eq!(target.submitted_today, 0);
+        target.record(at(2, 0, 1));
+        assert_eq!(target.submitted_today, 1);
+    }
+
+    #[test]
+    fn test_backoff_countdown_clamps_and_idles_as_ready() {
+        let backoff = |elapsed: u64| TaskFetchInfo {
+            backoff_duration_secs: 40,
+            time_since_last_fetch_secs: elapsed,
+            can_fetch_now: false,
+        };
+        assert_eq!(backoff(10).progress_pct(), 25);
+        assert_eq!(backoff(10).remaining_secs(), 30);
+        // Overrunning the backoff doesn't wrap
+        assert_eq!(backoff(55).progress_pct(), 100);
+        assert_eq!(backoff(55).remaining_secs(), 0);
+
+        let idle = TaskFetchInfo::default();
+        assert!(!idle.is_backing_off());
+        assert_eq!(idle.progress_pct(), 100);
+    }
+
+    #[test]
+    fn test_smoothing_converges_to_a_constant_input() {
+        let mut average = smooth(None, 80.0, Some(0.3));
+        // The first sample is taken as is
+        assert_eq!(average, 80.0);
+
+        for _ in 0..50 {
+            average = smooth(Some(average), 20.0, Some(0.3));
+        }
+        assert!((average - 20.0).abs() < 1e-3, "{}", average);
+
+        // One spike only moves the average part of the way
+        let spiked = smooth(Some(average), 100.0, Some(0.3));
+        assert!(spiked < 50.0);
+        // Raw readings pass through
+        assert_eq!(smooth(Some(average), 100.0, None), 100.0);
+    }
+
+    #[test]
+    fn test_points_estimate_waits_for_warmup_and_rides_out_gaps() {
+        let t0 = Instant::now();
+        let mut window = ThroughputWindow::new(HOUR, t0);
+        window.record(t0 + minutes(2), 100);
+        assert_eq!(window.points_per_hour(t0 + minutes(5)), None);
+        assert_eq!(format_points_estimate(None), "—");
+
+        // One submission every 5 minutes, then a 20 minute gap. The rate is
+        // raised halfway, which only counts for the later submissions.
+        for i in 1..=8 {
+            let points = if i <= 4 { 100 } else { 200 };
+            window.record(t0 + minutes(2 + 5 * i), points);
+        }
+        let now = t0 + minutes(62);
+        // Eight of the submissions are still in the hour window
+        assert_eq!(window.points_per_hour(now), Some(1200.0));
+        assert_eq!(format_points_estimate(Some(1200.0 * 24.0)), "28,800");
+    }
+
+    #[test]
+    fn test_submission_latency_matches_generation_to_submission() {
+        let start = Instant::now();
+        let mut latency = SubmissionLatency::new(2, Duration::from_secs(60));
+        latency.generated("a", start);
+        // A repeated Step 3 keeps the original generation time
+        latency.generated("a", start + Duration::from_secs(5));
+        latency.generated("b", start);
+
+        assert_eq!(
+            latency.submitted("a", start + Duration::from_secs(10)),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(
+            latency.submitted("b", start + Duration::from_secs(30)),
+            Some(Duration::from_secs(30))
+        );
+        // Submissions without an observed generation aren't timed
+        assert_eq!(latency.submitted("c", start), None);
+        assert_eq!(latency.average(), Some(Duration::from_secs(20)));
+        assert_eq!(latency.max(), Some(Duration::from_secs(30)));
+
+        // Never-submitted proofs time out of the pending map
+        latency.generated("d", start);
+        latency.generated("e", start + Duration::from_secs(61));
+        assert_eq!(latency.pending(), 1);
+        assert_eq!(
+            latency.submitted("d", start + Duration::from_secs(62)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rolling_stats_are_empty_until_the_first_sample() {
+        let stats = RollingStats::new(3);
+        assert!(stats.is_empty());
+        assert_eq!(stats.min(), None);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.max(), None);
+        assert_eq!(stats.latest(), None);
+    }
+
+    #[test]
+    fn test_rolling_stats_of_a_single_sample() {
+        let t0 = Instant::now();
+        let mut stats = RollingStats::new(3);
+        stats.record(t0, 4.5);
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats.min(), Some(4.5));
+        assert_eq!(stats.mean(), Some(4.5));
+        assert_eq!(stats.max(), Some(4.5));
+        assert_eq!(stats.latest(), Some(4.5));
+    }
+
+    #[test]
+    fn test_rolling_stats_of_many_samples() {
+        let t0 = Instant::now();
+        let mut stats = RollingStats::new(10);
+        for value in [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0] {
+            stats.record(t0, value);
+        }
+        assert_eq!(stats.len(), 8);
+        assert_eq!(stats.min(), Some(1.0));
+        assert_eq!(stats.mean(), Some(31.0 / 8.0));
+        assert_eq!(stats.max(), Some(9.0));
+        assert_eq!(stats.latest(), Some(6.0));
+    }
+
+    #[test]
+    fn test_rolling_stats_evict_the_oldest_samples() {
+        let t0 = Instant::now();
+        let mut stats = RollingStats::new(3);
+        for value in [100.0, 1.0, 2.0, 3.0] {
+            stats.record(t0, value);
+        }
+        // The 100 is gone from the extremes and the running mean alike
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats.max(), Some(3.0));
+        assert_eq!(stats.mean(), Some(2.0));
+
+        stats.record(t0, 0.5);
+        assert_eq!(stats.min(), Some(0.5));
+        assert_eq!(stats.mean(), Some(5.5 / 3.0));
+
+        // A zero capacity still keeps the latest sample
+        let mut stats = RollingStats::new(0);
+        stats.record(t0, 1.0);
+        stats.record(t0, 2.0);
+        assert_eq!((stats.len(), stats.mean()), (1, Some(2.0)));
+    }
+
+    #[test]
+    fn test_proving_times_track_recent_extremes() {
+        let mut times = ProvingTimes::new(3);
+        let t0 = Instant::now();
+        assert_eq!(times.min(), None);
+
+        for secs in [90, 30, 60] {
+            times.record(t0, secs);
+        }
+        assert_eq!((times.min(), times.max()), (Some(30), Some(90)));
+
+        // The oldest (90s) is evicted
+        times.record(t0, 45);
+        assert_eq!((times.min(), times.max()), (Some(30), Some(60)));
+    }
+
+    #[test]
+    fn test_proving_times_average_over_recent_proofs() {
+        let mut times = ProvingTimes::new(10);
+        let t0 = Instant::now();
+        times.record(t0, 120);
+        times.record(t0 + minutes(4), 60);
+        times.record(t0 + minutes(6), 30);
+
+        let now = t0 + minutes(6);
+        assert_eq!(times.latest(), Some(30));
+        assert_eq!(times.average_over(now, minutes(1)), Some(30.0));
+        assert_eq!(times.average_over(now, minutes(5)), Some(45.0));
+        assert_eq!(times.average_over(now + minutes(10), minutes(5)), None);
+    }
+
+    #[test]
+    fn test_task_status_text_and_color() {
+        let theme = Theme::dark();
+        assert_eq!(ZkVMMetrics::default().last_task_status, TaskStatus::None);
+        assert_eq!(ProverMetrics::default().last_status, TaskStatus::Idle);
+        assert_eq!(TaskStatus::ProofFailed.to_string(), "Proof Failed");
+        assert_eq!(TaskStatus::SubmitFailed.to_string(), "Submit Failed");
+        assert_eq!(TaskStatus::Success.color(&theme), theme.success);
+        assert_eq!(TaskStatus::Dropped.color(&theme), theme.error);
+        assert_eq!(TaskStatus::Idle.color(&theme), theme.label);
+    }
+
+    #[test]
+    fn test_avg_proving_secs_skips_untimed_proofs() {
+        let mut metrics = ZkVMMetrics::default();
+        assert_eq!(metrics.avg_proving_secs(), None);
+
+        metrics.tasks_proved = 4;
+        metrics.untimed_proofs = 1;
+        metrics.zkvm_runtime_secs = 270;
+        assert_eq!(metrics.avg_proving_secs(), Some(90.0));
+    }
+}
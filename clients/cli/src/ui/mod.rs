@@ -2,7 +2,8 @@
 mod app;
 pub mod dashboard;
 mod login;
-mod metrics;
+pub mod metrics;
 pub mod splash;
+pub mod theme;
 // Re-exports for external use
 pub use app::{App, UIConfig, run};
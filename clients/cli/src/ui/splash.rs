@@ -1,8 +1,9 @@
 //! Splash screen rendering module.
 
+use crate::ui::theme::Theme;
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 
@@ -15,7 +16,7 @@ pub const LOGO_NAME: &str = r#"
   ╚═╝  ╚═══╝  ╚══════╝  ╚═╝  ╚═╝   ╚═════╝   ╚══════╝
 "#;
 
-pub fn render_splash(f: &mut Frame) {
+pub fn render_splash(f: &mut Frame, theme: &Theme) {
     // Convert LOGO_NAME into styled Lines
     let mut lines: Vec<Line> = LOGO_NAME
         .trim_matches('\n')
@@ -24,7 +25,7 @@ pub fn render_splash(f: &mut Frame) {
             Span::styled(
                 line.to_string(),
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme.border)
                     .add_modifier(Modifier::BOLD),
             )
             .into()
@@ -39,7 +40,7 @@ pub fn render_splash(f: &mut Frame) {
         Span::styled(
             format!("Version {}", env!("CARGO_PKG_VERSION")),
             Style::default()
-                .fg(Color::LightBlue)
+                .fg(theme.info)
                 .add_modifier(Modifier::ITALIC),
         )
         .into(),
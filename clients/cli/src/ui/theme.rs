@@ -0,0 +1,220 @@
+//! Color themes
+//!
+//! Every color the TUI draws with comes from a `Theme`, so the dashboard can
+//! be made readable on light terminals, for colorblind users, or without
+//! color at all. Panels pick colors by role (border, label, error, ...)
+//! rather than by hue.
+
+use ratatui::prelude::Color;
+use std::str::FromStr;
+
+/// Themes selectable with `--theme`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ThemeName {
+    /// The original scheme, for dark terminals
+    #[default]
+    Dark,
+    /// Darker hues that stay readable on a light background
+    Light,
+    /// Bright, colorblind-safe hues that never rely on red versus green
+    HighContrast,
+}
+
+impl FromStr for ThemeName {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "dark" => Ok(ThemeName::Dark),
+            "light" => Ok(ThemeName::Light),
+            "high-contrast" => Ok(ThemeName::HighContrast),
+            other => Err(format!(
+                "invalid theme '{}', expected 'dark', 'light' or 'high-contrast'",
+                other
+            )),
+        }
+    }
+}
+
+/// Colors by role
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Theme {
+    /// Fill behind the dashboard, when the background is enabled
+    pub background: Color,
+    /// Panel borders, the title and the footer
+    pub border: Color,
+    /// Field labels ("Points: ", ...) and secondary detail
+    pub label: Color,
+    /// Timestamps and separators
+    pub muted: Color,
+    /// Plain values such as counts and task IDs
+    pub text: Color,
+    /// Measured values such as durations and speeds
+    pub value: Color,
+    /// Points and other headline numbers
+    pub highlight: Color,
+    /// Node identity, peak RAM and the waiting state
+    pub info: Color,
+    /// Memory and network
+    pub secondary: Color,
+    /// Schedule, GPU and the comparison panel
+    pub accent: Color,
+    /// Work in progress going well: uptime, proving, the first proof
+    pub active: Color,
+    /// Healthy levels and successful outcomes
+    pub success: Color,
+    /// Degraded levels
+    pub warning: Color,
+    /// Failures and critical levels
+    pub error: Color,
+    /// Activity of the task fetcher
+    pub fetcher: Color,
+    /// Activity of the provers
+    pub prover: Color,
+    /// Activity of the proof submitter
+    pub submitter: Color,
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            background: Color::Rgb(16, 20, 24),
+            border: Color::Cyan,
+            label: Color::Gray,
+            muted: Color::DarkGray,
+            text: Color::White,
+            value: Color::Cyan,
+            highlight: Color::LightYellow,
+            info: Color::LightBlue,
+            secondary: Color::LightCyan,
+            accent: Color::LightMagenta,
+            active: Color::LightGreen,
+            success: Color::Green,
+            warning: Color::Yellow,
+            error: Color::Red,
+            fetcher: Color::Cyan,
+            prover: Color::Yellow,
+            submitter: Color::Green,
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            background: Color::Rgb(250, 250, 250),
+            border: Color::Blue,
+            label: Color::DarkGray,
+            muted: Color::Gray,
+            text: Color::Black,
+            value: Color::Blue,
+            highlight: Color::Rgb(150, 90, 0),
+            info: Color::Blue,
+            secondary: Color::Rgb(0, 105, 125),
+            accent: Color::Magenta,
+            active: Color::Rgb(0, 120, 0),
+            success: Color::Rgb(0, 120, 0),
+            warning: Color::Rgb(170, 100, 0),
+            error: Color::Rgb(190, 0, 0),
+            fetcher: Color::Blue,
+            prover: Color::Rgb(150, 90, 0),
+            submitter: Color::Rgb(0, 120, 0),
+        }
+    }
+
+    /// Okabe-Ito hues: success is blue and errors orange, so the two stay
+    /// distinct with red-green color blindness
+    pub fn high_contrast() -> Self {
+        Self {
+            background: Color::Black,
+            border: Color::White,
+            label: Color::White,
+            muted: Color::Gray,
+            text: Color::White,
+            value: Color::Rgb(86, 180, 233),
+            highlight: Color::Rgb(240, 228, 66),
+            info: Color::Rgb(86, 180, 233),
+            secondary: Color::Rgb(86, 180, 233),
+            accent: Color::Rgb(204, 121, 167),
+            active: Color::Rgb(86, 180, 233),
+            success: Color::Rgb(0, 114, 178),
+            warning: Color::Rgb(240, 228, 66),
+            error: Color::Rgb(230, 159, 0),
+            fetcher: Color::Rgb(86, 180, 233),
+            prover: Color::Rgb(240, 228, 66),
+            submitter: Color::White,
+        }
+    }
+
+    /// The terminal's own colors throughout, for `NO_COLOR`
+    pub fn monochrome() -> Self {
+        Self {
+            background: Color::Reset,
+            border: Color::Reset,
+            label: Color::Reset,
+            muted: Color::Reset,
+            text: Color::Reset,
+            value: Color::Reset,
+            highlight: Color::Reset,
+            info: Color::Reset,
+            secondary: Color::Reset,
+            accent: Color::Reset,
+            active: Color::Reset,
+            success: Color::Reset,
+            warning: Color::Reset,
+            error: Color::Reset,
+            fetcher: Color::Reset,
+            prover: Color::Reset,
+            submitter: Color::Reset,
+        }
+    }
+
+    pub fn named(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Dark => Self::dark(),
+            ThemeName::Light => Self::light(),
+            ThemeName::HighContrast => Self::high_contrast(),
+        }
+    }
+
+    /// Whether this theme draws without colors
+    pub fn is_monochrome(&self) -> bool {
+        *self == Self::monochrome()
+    }
+
+    /// The theme to use: an explicitly chosen one, else monochrome when
+    /// `NO_COLOR` is set, else dark.
+    pub fn resolve(name: Option<ThemeName>, no_color: bool) -> Self {
+        match name {
+            Some(name) => Self::named(name),
+            None if no_color => Self::monochrome(),
+            None => Self::dark(),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// Whether `NO_COLOR` asks for no color (set and non-empty, per no-color.org)
+pub fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explicit_theme_wins_over_no_color() {
+        assert_eq!(Theme::resolve(None, false), Theme::dark());
+        assert_eq!(Theme::resolve(None, true), Theme::monochrome());
+        assert_eq!(Theme::resolve(Some(ThemeName::Light), true), Theme::light());
+        assert_eq!(
+            "High-Contrast".parse::<ThemeName>(),
+            Ok(ThemeName::HighContrast)
+        );
+        assert!("solarized".parse::<ThemeName>().is_err());
+    }
+}
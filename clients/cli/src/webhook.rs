@@ -0,0 +1,160 @@
+//! Submission webhook
+//!
+//! POSTs a JSON record to a user-supplied URL for every accepted proof.
+//! Delivery runs on its own task with a timeout and bounded retries, so a slow
+//! or unreachable endpoint never stalls the dashboard.
+
+use crate::consts::cli_consts::webhook::{MAX_RETRIES, QUEUE_SIZE, initial_backoff, timeout};
+use reqwest::Client;
+use serde::Serialize;
+use tokio::sync::broadcast::error::TryRecvError;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{broadcast, mpsc};
+
+/// Body of each webhook request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SubmissionRecord {
+    /// Task the accepted proof was for
+    pub task_id: String,
+    /// When the submission was recorded
+    pub timestamp: String,
+    /// Cumulative points after this submission
+    pub total_points: u64,
+}
+
+/// Handle to the background delivery task
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    records: mpsc::Sender<SubmissionRecord>,
+    failures: broadcast::Sender<String>,
+}
+
+impl Webhook {
+    /// Start delivering submission records to `url` in the background.
+    pub fn spawn(url: String) -> Result<Self, reqwest::Error> {
+        let client = Client::builder().timeout(timeout()).build()?;
+        let (webhook, mut records) = Self::channel(QUEUE_SIZE);
+        let failures = webhook.failures.clone();
+        tokio::spawn(async move {
+            while let Some(record) = records.recv().await {
+                if let Err(e) = deliver(&client, &url, &record).await {
+                    let _ = failures.send(format!(
+                        "Webhook delivery failed for task {}: {}",
+                        record.task_id, e
+                    ));
+                }
+            }
+        });
+        Ok(webhook)
+    }
+
+    /// A handle whose records arrive on the returned receiver
+    pub(crate) fn channel(capacity: usize) -> (Self, mpsc::Receiver<SubmissionRecord>) {
+        let (records, receiver) = mpsc::channel(capacity);
+        let (failures, _) = broadcast::channel(capacity);
+        (Self { records, failures }, receiver)
+    }
+
+    /// Connect a dashboard to the delivery task
+    pub fn subscribe(&self) -> WebhookClient {
+        WebhookClient {
+            records: self.records.clone(),
+            failures: self.failures.subscribe(),
+            dropped: Vec::new(),
+        }
+    }
+}
+
+/// A dashboard's connection to the delivery task
+#[derive(Debug)]
+pub struct WebhookClient {
+    records: mpsc::Sender<SubmissionRecord>,
+    failures: broadcast::Receiver<String>,
+    /// Records that never reached the delivery task
+    dropped: Vec<String>,
+}
+
+impl WebhookClient {
+    /// Queue a record for delivery without waiting on the endpoint.
+    pub fn send(&mut self, record: SubmissionRecord) {
+        match self.records.try_send(record) {
+            Ok(()) => {}
+            Err(TrySendError::Full(record)) => self.dropped.push(format!(
+                "Webhook queue full, dropped submission for task {}",
+                record.task_id
+            )),
+            Err(TrySendError::Closed(record)) => self.dropped.push(format!(
+                "Webhook stopped, dropped submission for task {}",
+                record.task_id
+            )),
+        }
+    }
+
+    /// Delivery failures reported since the last call
+    pub fn failures(&mut self) -> Vec<String> {
+        let mut failures = std::mem::take(&mut self.dropped);
+        loop {
+            match self.failures.try_recv() {
+                Ok(failure) => failures.push(failure),
+                Err(TryRecvError::Lagged(_)) => continue,
+                Err(_) => return failures,
+            }
+        }
+    }
+}
+
+/// POST one record, retrying with exponential backoff
+async fn deliver(
+    client: &Client,
+    url: &str,
+    record: &SubmissionRecord,
+) -> Result<(), reqwest::Error> {
+    let mut backoff = initial_backoff();
+    let mut attempt = 0;
+    loop {
+        let result = client
+            .post(url)
+            .json(record)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        match result {
+            Ok(_) => return Ok(()),
+            Err(e) if attempt >= MAX_RETRIES => return Err(e),
+            Err(_) => {
+                attempt += 1;
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(task_id: &str) -> SubmissionRecord {
+        SubmissionRecord {
+            task_id: task_id.to_string(),
+            timestamp: "2025-01-01 12:00:00".to_string(),
+            total_points: 300,
+        }
+    }
+
+    #[test]
+    fn test_full_queue_reports_dropped_records() {
+        let (webhook, mut records) = Webhook::channel(1);
+        let mut client = webhook.subscribe();
+
+        client.send(record("a"));
+        client.send(record("b"));
+
+        assert_eq!(records.try_recv().unwrap(), record("a"));
+        assert_eq!(
+            client.failures(),
+            ["Webhook queue full, dropped submission for task b"]
+        );
+        assert!(client.failures().is_empty());
+    }
+}
@@ -1,39 +1,51 @@
-//! Single authenticated worker that orchestrates fetch→prove→submit
+//! Authenticated worker that orchestrates fetch→prove→submit
 
 use super::core::{EventSender, WorkerConfig};
 use super::fetcher::TaskFetcher;
 use super::prover::TaskProver;
 use super::submitter::ProofSubmitter;
-use crate::events::{Event, ProverState};
+use crate::events::{Event, ProofPhase, ProverState, Worker};
 use crate::orchestrator::OrchestratorClient;
+use crate::schedule::{Schedule, format_minute_of_day};
 
 use ed25519_dalek::SigningKey;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::time::Duration;
 use tokio::sync::{broadcast, mpsc};
 use tokio::task::JoinHandle;
 
-/// Single authenticated worker that handles the complete task lifecycle
+/// Authenticated worker that handles the complete task lifecycle, one task at a time
 pub struct AuthenticatedWorker {
+    /// Prover index, which the schedule's worker count is checked against
+    index: usize,
     fetcher: TaskFetcher,
     prover: TaskProver,
     submitter: ProofSubmitter,
     event_sender: EventSender,
     max_tasks: Option<u32>,
-    tasks_completed: u32,
+    /// Tasks completed by all of the session's workers, counted towards `max_tasks`
+    tasks_completed: Arc<AtomicU32>,
     shutdown_sender: broadcast::Sender<()>,
+    schedule: Option<Schedule>,
+    /// Whether the schedule is currently holding the worker back
+    schedule_paused: bool,
 }
 
 impl AuthenticatedWorker {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
+        index: usize,
         node_id: u64,
         signing_key: SigningKey,
         orchestrator: OrchestratorClient,
         config: WorkerConfig,
         event_sender: mpsc::Sender<Event>,
         max_tasks: Option<u32>,
+        tasks_completed: Arc<AtomicU32>,
         shutdown_sender: broadcast::Sender<()>,
     ) -> Self {
-        let event_sender_helper = EventSender::new(event_sender);
+        let event_sender_helper = EventSender::new(event_sender).with_node_id(node_id);
 
         // Create the 3 specialized components
         let fetcher = TaskFetcher::new(
@@ -44,7 +56,8 @@ impl AuthenticatedWorker {
             &config,
         );
 
-        let prover = TaskProver::new(event_sender_helper.clone(), config.clone());
+        let schedule = config.schedule.clone();
+        let prover = TaskProver::new(index, event_sender_helper.clone(), config.clone());
 
         let submitter = ProofSubmitter::new(
             signing_key,
@@ -54,13 +67,16 @@ impl AuthenticatedWorker {
         );
 
         Self {
+            index,
             fetcher,
             prover,
             submitter,
             event_sender: event_sender_helper,
             max_tasks,
-            tasks_completed: 0,
+            tasks_completed,
             shutdown_sender,
+            schedule,
+            schedule_paused: false,
         }
     }
 
@@ -99,6 +115,11 @@ impl AuthenticatedWorker {
     /// Complete work cycle: fetch→prove→submit
     /// Returns true if the worker should exit (max tasks reached)
     async fn work_cycle(&mut self) -> bool {
+        // Hold off fetching while the schedule pauses or caps this worker
+        if self.wait_for_schedule().await {
+            return false;
+        }
+
         // Step 1: Fetch task
         let task = match self.fetcher.fetch_task().await {
             Ok(task) => task,
@@ -115,10 +136,16 @@ impl AuthenticatedWorker {
         // Step 2: Prove task
         // Send state change to Proving
         self.event_sender
-            .send_event(Event::state_change(
-                ProverState::Proving,
-                format!("Step 2 of 4: Proving task {}", task.task_id),
-            ))
+            .send_event(
+                Event::state_change(
+                    ProverState::Proving,
+                    format!("Step 2 of 4: Proving task {}", task.task_id),
+                )
+                .with_phase(ProofPhase::Proving {
+                    task_id: task.task_id.clone(),
+                })
+                .with_worker(Worker::Prover(self.index)),
+            )
             .await;
 
         let proof_result = match self.prover.prove_task(&task).await {
@@ -140,7 +167,7 @@ impl AuthenticatedWorker {
 
         // Only increment task counter on successful submission
         if submission_result.is_ok() {
-            self.tasks_completed += 1;
+            let tasks_completed = self.tasks_completed.fetch_add(1, Ordering::SeqCst) + 1;
 
             // Update success tracking for difficulty promotion
             let duration_secs = start_time.elapsed().as_secs();
@@ -164,7 +191,7 @@ impl AuthenticatedWorker {
                 .await;
             // Check if we've reached the maximum number of tasks
             if let Some(max) = self.max_tasks {
-                if self.tasks_completed >= max {
+                if tasks_completed >= max {
                     // Give a brief moment for the "Step 4 of 4" message to be processed
                     // before triggering shutdown
                     tokio::time::sleep(std::time::Duration::from_millis(100)).await;
@@ -172,7 +199,7 @@ impl AuthenticatedWorker {
                     self.event_sender
                         .send_event(Event::state_change(
                             ProverState::Waiting,
-                            format!("Completed {} tasks, shutting down", self.tasks_completed),
+                            format!("Completed {} tasks, shutting down", tasks_completed),
                         ))
                         .await;
 
@@ -193,4 +220,50 @@ impl AuthenticatedWorker {
 
         false // Continue with more tasks
     }
+
+    /// Check the proving schedule, announcing pause and resume transitions.
+    /// Returns true (after sleeping) if the worker should skip this cycle.
+    ///
+    /// A worker count holds back the workers with an index at or past it, so
+    /// `=2` leaves provers 0 and 1 running. A task already in progress is
+    /// finished first, since the schedule is only checked between tasks.
+    async fn wait_for_schedule(&mut self) -> bool {
+        let Some(schedule) = &self.schedule else {
+            return false;
+        };
+
+        let setting = schedule.current_setting();
+        let paused = setting.is_some_and(|setting| !setting.allows_worker(self.index));
+        if paused != self.schedule_paused {
+            let msg = if paused {
+                let reason = match setting {
+                    Some(setting) if !setting.is_paused() => format!(" ({})", setting),
+                    _ => String::new(),
+                };
+                match schedule.next_change() {
+                    Some((minute, _)) => format!(
+                        "Prover {} paused by schedule{} until {}",
+                        self.index,
+                        reason,
+                        format_minute_of_day(minute)
+                    ),
+                    None => format!("Prover {} paused by schedule{}", self.index, reason),
+                }
+            } else {
+                format!("Schedule resumed prover {}", self.index)
+            };
+            self.schedule_paused = paused;
+            self.event_sender
+                .send_event(Event::state_change(ProverState::Waiting, msg))
+                .await;
+        }
+
+        if paused {
+            tokio::time::sleep(Duration::from_secs(
+                crate::consts::cli_consts::SCHEDULE_CHECK_INTERVAL_SECS,
+            ))
+            .await;
+        }
+        paused
+    }
 }
@@ -8,30 +8,28 @@ use tokio::sync::mpsc;
 #[derive(Clone)]
 pub struct EventSender {
     sender: mpsc::Sender<Event>,
+    /// Node every event is attributed to, if any
+    node_id: Option<u64>,
 }
 
 impl EventSender {
     pub fn new(sender: mpsc::Sender<Event>) -> Self {
-        Self { sender }
+        Self {
+            sender,
+            node_id: None,
+        }
     }
 
-    /// Send a generic event
-    pub async fn send_event(&self, event: Event) {
-        let _ = self.sender.send(event).await;
+    /// Attribute every event sent to this node (unless the event names its own)
+    pub fn with_node_id(mut self, node_id: u64) -> Self {
+        self.node_id = Some(node_id);
+        self
     }
 
-    pub async fn send_task_event(
-        &self,
-        message: String,
-        event_type: EventType,
-        log_level: LogLevel,
-    ) {
-        let _ = self
-            .sender
-            .send(Event::task_fetcher_with_level(
-                message, event_type, log_level,
-            ))
-            .await;
+    /// Send a generic event
+    pub async fn send_event(&self, mut event: Event) {
+        event.node_id = event.node_id.or(self.node_id);
+        let _ = self.sender.send(event).await;
     }
 
     pub async fn send_proof_event(
@@ -40,27 +38,10 @@ impl EventSender {
         event_type: EventType,
         log_level: LogLevel,
     ) {
-        let _ = self
-            .sender
-            .send(Event::proof_submitter_with_level(
-                message, event_type, log_level,
-            ))
-            .await;
-    }
-
-    pub async fn send_prover_event(
-        &self,
-        thread_id: usize,
-        message: String,
-        event_type: EventType,
-        log_level: LogLevel,
-    ) {
-        let _ = self
-            .sender
-            .send(Event::prover_with_level(
-                thread_id, message, event_type, log_level,
-            ))
-            .await;
+        self.send_event(Event::proof_submitter_with_level(
+            message, event_type, log_level,
+        ))
+        .await;
     }
 }
 
@@ -70,6 +51,7 @@ pub struct WorkerConfig {
     pub environment: crate::environment::Environment,
     pub client_id: String,
     pub max_difficulty: Option<crate::nexus_orchestrator::TaskDifficulty>,
+    pub schedule: Option<crate::schedule::Schedule>,
 }
 
 impl WorkerConfig {
@@ -78,6 +60,7 @@ impl WorkerConfig {
             environment,
             client_id,
             max_difficulty: None,
+            schedule: None,
         }
     }
 }
@@ -3,7 +3,7 @@
 use super::core::{EventSender, WorkerConfig};
 use crate::analytics::track_got_task;
 use crate::consts::cli_consts::{difficulty, rate_limiting, task_fetching};
-use crate::events::EventType;
+use crate::events::{Event, EventType, ProofPhase};
 use crate::logging::LogLevel;
 use crate::network::{NetworkClient, RequestTimer, RequestTimerConfig};
 use crate::orchestrator::Orchestrator;
@@ -72,10 +72,13 @@ impl TaskFetcher {
 
         if can_proceed_immediately {
             self.event_sender
-                .send_task_event(
-                    "Step 1 of 4: Fetching task...".to_string(),
-                    EventType::Refresh,
-                    LogLevel::Info,
+                .send_event(
+                    Event::task_fetcher_with_level(
+                        "Step 1 of 4: Fetching task...".to_string(),
+                        EventType::Refresh,
+                        LogLevel::Info,
+                    )
+                    .with_phase(ProofPhase::Requesting),
                 )
                 .await;
         }
@@ -86,13 +89,18 @@ impl TaskFetcher {
             if wait_time > Duration::ZERO {
                 // Log the accurate wait time here
                 self.event_sender
-                    .send_task_event(
-                        format!(
-                            "Step 1 of 4: Waiting - ready for next task ({}) seconds",
-                            wait_time.as_secs()
-                        ),
-                        EventType::Waiting,
-                        LogLevel::Info,
+                    .send_event(
+                        Event::task_fetcher_with_level(
+                            format!(
+                                "Step 1 of 4: Waiting - ready for next task ({}) seconds",
+                                wait_time.as_secs()
+                            ),
+                            EventType::Waiting,
+                            LogLevel::Info,
+                        )
+                        .with_phase(ProofPhase::Backoff {
+                            wait_secs: wait_time.as_secs(),
+                        }),
                     )
                     .await;
                 sleep(wait_time).await;
@@ -163,10 +171,15 @@ impl TaskFetcher {
             Ok(task) => {
                 // Log successful fetch
                 self.event_sender
-                    .send_task_event(
-                        format!("Step 1 of 4: Got task {}", task.task_id),
-                        EventType::Success,
-                        LogLevel::Info,
+                    .send_event(
+                        Event::task_fetcher_with_level(
+                            format!("Step 1 of 4: Got task {}", task.task_id),
+                            EventType::Success,
+                            LogLevel::Info,
+                        )
+                        .with_phase(ProofPhase::Fetched {
+                            task_id: task.task_id.clone(),
+                        }),
                     )
                     .await;
 
@@ -183,13 +196,27 @@ impl TaskFetcher {
                 // Log fetch failure with appropriate level
                 let log_level = self.network_client.classify_error(&e);
                 self.event_sender
-                    .send_task_event(
+                    .send_event(Event::task_fetcher_with_level(
                         format!("Failed to fetch task: {}", e),
                         EventType::Error,
                         log_level,
-                    )
+                    ))
                     .await;
 
+                // The server asked for a wait we can't read, so the default backoff applies
+                if let Some(value) = e.unparsed_retry_after() {
+                    self.event_sender
+                        .send_event(Event::task_fetcher_with_level(
+                            format!(
+                                "Couldn't parse Retry-After value {:?}; using the default backoff",
+                                value
+                            ),
+                            EventType::Refresh,
+                            LogLevel::Debug,
+                        ))
+                        .await;
+                }
+
                 Err(FetchError::Network(e))
             }
         }
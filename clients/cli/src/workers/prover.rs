@@ -2,7 +2,7 @@
 
 use super::core::{EventSender, WorkerConfig};
 use crate::analytics::track_authenticated_proof_analytics;
-use crate::events::EventType;
+use crate::events::{Event, EventType, ProofPhase};
 use crate::logging::LogLevel;
 use crate::prover::{ProverError, ProverResult, authenticated_proving};
 use crate::task::Task;
@@ -16,13 +16,16 @@ pub enum ProveError {
 
 /// Task prover that generates proofs using the existing prover module
 pub struct TaskProver {
+    /// Prover index its events are reported under
+    index: usize,
     event_sender: EventSender,
     config: WorkerConfig,
 }
 
 impl TaskProver {
-    pub fn new(event_sender: EventSender, config: WorkerConfig) -> Self {
+    pub fn new(index: usize, event_sender: EventSender, config: WorkerConfig) -> Self {
         Self {
+            index,
             event_sender,
             config,
         }
@@ -33,13 +36,26 @@ impl TaskProver {
         // Use existing prover module for proof generation
         match authenticated_proving(task, &self.config.environment, &self.config.client_id).await {
             Ok((proofs, combined_hash, individual_proof_hashes)) => {
+                // Size as submitted; only reported, so a failure just leaves it out
+                let proof_bytes = proofs
+                    .iter()
+                    .map(|proof| postcard::to_allocvec(proof).map(|bytes| bytes.len() as u64))
+                    .sum::<Result<u64, _>>()
+                    .ok();
+
                 // Log successful proof generation
                 self.event_sender
-                    .send_prover_event(
-                        0, // Single-threaded prover for now
-                        format!("Step 3 of 4: Proof generated for task {}", task.task_id),
-                        EventType::Success,
-                        LogLevel::Info,
+                    .send_event(
+                        Event::prover_with_level(
+                            self.index,
+                            format!("Step 3 of 4: Proof generated for task {}", task.task_id),
+                            EventType::Success,
+                            LogLevel::Info,
+                        )
+                        .with_phase(ProofPhase::ProofGenerated {
+                            task_id: task.task_id.clone(),
+                            proof_bytes,
+                        }),
                     )
                     .await;
 
@@ -58,11 +74,16 @@ impl TaskProver {
             Err(e) => {
                 // Log proof generation failure
                 self.event_sender
-                    .send_prover_event(
-                        0, // Single-threaded prover for now
-                        format!("Proof generation failed for task {}: {}", task.task_id, e),
-                        EventType::Error,
-                        LogLevel::Error,
+                    .send_event(
+                        Event::prover_with_level(
+                            self.index,
+                            format!("Proof generation failed for task {}: {}", task.task_id, e),
+                            EventType::Error,
+                            LogLevel::Error,
+                        )
+                        .with_phase(ProofPhase::ProofFailed {
+                            task_id: task.task_id.clone(),
+                        }),
                     )
                     .await;
 
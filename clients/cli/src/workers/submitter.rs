@@ -5,7 +5,7 @@ use crate::analytics::{
     track_proof_accepted, track_proof_submission_error, track_proof_submission_success,
 };
 use crate::consts::cli_consts::{proof_submission, rate_limiting};
-use crate::events::EventType;
+use crate::events::{Event, EventType, ProofPhase};
 use crate::logging::LogLevel;
 use crate::network::{NetworkClient, ProofSubmission, RequestTimer, RequestTimerConfig};
 use crate::orchestrator::Orchestrator;
@@ -120,13 +120,19 @@ impl ProofSubmitter {
                 };
 
                 self.event_sender
-                    .send_proof_event(
-                        format!(
-                            "Step 4 of 4: Proof submitted successfully for task {}{}\n",
-                            task.task_id, attempt_text
-                        ),
-                        EventType::Success,
-                        LogLevel::Info,
+                    .send_event(
+                        Event::proof_submitter_with_level(
+                            format!(
+                                "Step 4 of 4: Proof submitted successfully for task {}{}\n",
+                                task.task_id, attempt_text
+                            ),
+                            EventType::Success,
+                            LogLevel::Info,
+                        )
+                        .with_phase(ProofPhase::Submitted {
+                            task_id: task.task_id.clone(),
+                            attempts: Some(attempts),
+                        }),
                     )
                     .await;
 
@@ -141,13 +147,18 @@ impl ProofSubmitter {
                 // Log submission failure with attempt count and appropriate level
                 let log_level = self.network_client.classify_error(&e);
                 self.event_sender
-                    .send_proof_event(
-                        format!(
-                            "Failed to submit proof for task {} after {} attempts: {}",
-                            task.task_id, attempts, e
-                        ),
-                        EventType::Error,
-                        log_level,
+                    .send_event(
+                        Event::proof_submitter_with_level(
+                            format!(
+                                "Failed to submit proof for task {} after {} attempts: {}",
+                                task.task_id, attempts, e
+                            ),
+                            EventType::Error,
+                            log_level,
+                        )
+                        .with_phase(ProofPhase::SubmitFailed {
+                            task_id: task.task_id.clone(),
+                        }),
                     )
                     .await;
 